@@ -0,0 +1,264 @@
+use tree_sitter::Node;
+
+/// Conditionals whose `else` branch may chain into another conditional.
+const IF_KINDS: &[&str] = &["if_statement", "if_expression", "elif_clause"];
+
+/// Loops, switches, catches and ternaries: each breaks the linear flow and
+/// nests what it contains.
+const NESTING_KINDS: &[&str] = &[
+    "for_statement",
+    "for_in_statement",
+    "enhanced_for_statement",
+    "for_range_loop",
+    "for_expression",
+    "while_statement",
+    "while_expression",
+    "do_statement",
+    "loop_expression",
+    "switch_statement",
+    "switch_expression",
+    "match_statement",
+    "match_expression",
+    "catch_clause",
+    "except_clause",
+    "except_group_clause",
+    "conditional_expression",
+    "ternary_expression",
+];
+
+/// Functions, methods, lambdas and closures nested in a function body.
+const FUNCTION_KINDS: &[&str] = &[
+    "function_item",
+    "closure_expression",
+    "function_definition",
+    "lambda",
+    "function_declaration",
+    "function_expression",
+    "generator_function",
+    "generator_function_declaration",
+    "arrow_function",
+    "method_definition",
+    "method_declaration",
+    "constructor_declaration",
+    "lambda_expression",
+];
+
+/// Fields holding the condition or scrutinee of a structure, which are not
+/// nested inside it.
+const HEADER_FIELDS: &[&str] = &["condition", "value", "subject", "right"];
+
+/// Prefixes under which a function calls itself.
+const SELF_PREFIXES: &[&str] = &["self.", "this.", "this->", "Self::"];
+
+/// Analyzer for SonarSource-style cognitive complexity.
+///
+/// Each break in the linear flow (conditionals, loops, switches, catches,
+/// ternaries, `goto` and labeled jumps) adds 1, plus its nesting depth for
+/// structures that nest. Every sequence of like boolean operators adds 1, as
+/// does calling the function itself.
+pub struct CognitiveComplexityAnalyzer;
+
+impl CognitiveComplexityAnalyzer {
+    /// Create a new cognitive complexity analyzer.
+    pub fn new() -> Self {
+        CognitiveComplexityAnalyzer
+    }
+
+    /// Compute the cognitive complexity of a function body. Calls to
+    /// `function_name` count as recursion.
+    pub fn analyze(&self, body: Node, source: &str, function_name: &str) -> u32 {
+        let mut walker = ComplexityWalker {
+            source,
+            function_name,
+            score: 0,
+            recursive: false,
+        };
+        walker.visit(body, 0);
+        walker.score + u32::from(walker.recursive)
+    }
+}
+
+impl Default for CognitiveComplexityAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Walks a function body, accumulating increments.
+struct ComplexityWalker<'s> {
+    source: &'s str,
+    function_name: &'s str,
+    score: u32,
+    recursive: bool,
+}
+
+impl ComplexityWalker<'_> {
+    fn visit(&mut self, node: Node, nesting: u32) {
+        let kind = node.kind();
+        if IF_KINDS.contains(&kind) {
+            self.visit_if(node, nesting, false);
+        } else if logical_operator(node).is_some() {
+            self.visit_logical(node, nesting);
+        } else if NESTING_KINDS.contains(&kind) {
+            self.score += 1 + nesting;
+            self.visit_nested(node, nesting);
+        } else if FUNCTION_KINDS.contains(&kind) {
+            self.visit_children(node, nesting + 1);
+        } else {
+            if is_labeled_jump(node) {
+                self.score += 1;
+            }
+            if self.is_recursive_call(node) {
+                self.recursive = true;
+            }
+            self.visit_children(node, nesting);
+        }
+    }
+
+    fn visit_children(&mut self, node: Node, nesting: u32) {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            self.visit(child, nesting);
+        }
+    }
+
+    /// Visit a structure's children, nesting all but its header.
+    fn visit_nested(&mut self, node: Node, nesting: u32) {
+        for i in 0..node.child_count() {
+            let Some(child) = node.child(i) else {
+                continue;
+            };
+            match node.field_name_for_child(i as u32) {
+                Some(field) if HEADER_FIELDS.contains(&field) => self.visit(child, nesting),
+                _ => self.visit(child, nesting + 1),
+            }
+        }
+    }
+
+    /// Visit a conditional. An `else if` adds 1 regardless of nesting, as it
+    /// continues the chain of the conditional it belongs to.
+    fn visit_if(&mut self, node: Node, nesting: u32, else_if: bool) {
+        self.score += if else_if { 1 } else { 1 + nesting };
+        for i in 0..node.child_count() {
+            let Some(child) = node.child(i) else {
+                continue;
+            };
+            match node.field_name_for_child(i as u32) {
+                Some("condition") => self.visit(child, nesting),
+                Some("alternative") => self.visit_else(child, nesting),
+                _ => self.visit(child, nesting + 1),
+            }
+        }
+    }
+
+    /// Visit the alternative of a conditional: `else`, `else if` or `elif`.
+    fn visit_else(&mut self, node: Node, nesting: u32) {
+        if IF_KINDS.contains(&node.kind()) {
+            self.visit_if(node, nesting, true);
+            return;
+        }
+
+        if node.kind() == "else_clause" {
+            let mut cursor = node.walk();
+            let inner: Vec<Node> = node
+                .named_children(&mut cursor)
+                .filter(|child| child.kind() != "comment")
+                .collect();
+            if let [chained] = inner[..] {
+                if IF_KINDS.contains(&chained.kind()) {
+                    self.visit_if(chained, nesting, true);
+                    return;
+                }
+            }
+        }
+
+        self.score += 1;
+        self.visit(node, nesting + 1);
+    }
+
+    /// Visit a boolean expression, adding 1 per sequence of like operators:
+    /// `a && b && c` adds 1 and `a && b || c` adds 2.
+    fn visit_logical(&mut self, node: Node, nesting: u32) {
+        let mut operators = Vec::new();
+        let mut operands = Vec::new();
+        flatten_logical(node, &mut operators, &mut operands);
+        operators.dedup();
+        self.score += operators.len() as u32;
+        for operand in operands {
+            self.visit(operand, nesting);
+        }
+    }
+
+    /// Whether a node calls the function being analyzed, directly or through
+    /// `self`/`this`.
+    fn is_recursive_call(&self, node: Node) -> bool {
+        let callee = match node.kind() {
+            "call_expression" | "call" => node.child_by_field_name("function"),
+            "method_invocation" => match node.child_by_field_name("object") {
+                Some(object) if object.kind() != "this" => None,
+                _ => node.child_by_field_name("name"),
+            },
+            _ => None,
+        };
+        let Some(callee) = callee else {
+            return false;
+        };
+
+        let text = &self.source[callee.start_byte()..callee.end_byte()];
+        let name = SELF_PREFIXES
+            .iter()
+            .find_map(|prefix| text.strip_prefix(prefix))
+            .unwrap_or(text);
+        name == self.function_name
+    }
+}
+
+/// The operator of a short-circuit boolean expression, `and` and `or`
+/// written as `&&` and `||`.
+fn logical_operator(node: Node) -> Option<&'static str> {
+    if !matches!(node.kind(), "binary_expression" | "boolean_operator") {
+        return None;
+    }
+    match node.child_by_field_name("operator")?.kind() {
+        "&&" | "and" => Some("&&"),
+        "||" | "or" => Some("||"),
+        _ => None,
+    }
+}
+
+/// Flatten nested boolean expressions into their operators and operands, in
+/// source order. Parentheses do not break a sequence.
+fn flatten_logical<'a>(
+    node: Node<'a>,
+    operators: &mut Vec<&'static str>,
+    operands: &mut Vec<Node<'a>>,
+) {
+    if let Some(operator) = logical_operator(node) {
+        if let Some(left) = node.child_by_field_name("left") {
+            flatten_logical(left, operators, operands);
+        }
+        operators.push(operator);
+        if let Some(right) = node.child_by_field_name("right") {
+            flatten_logical(right, operators, operands);
+        }
+    } else if node.kind() == "parenthesized_expression" && node.named_child_count() == 1 {
+        match node.named_child(0) {
+            Some(inner) => flatten_logical(inner, operators, operands),
+            None => operands.push(node),
+        }
+    } else {
+        operands.push(node);
+    }
+}
+
+/// Whether a node is a `goto` or a `break`/`continue` to a label.
+fn is_labeled_jump(node: Node) -> bool {
+    let label_kinds: &[&str] = match node.kind() {
+        "goto_statement" => return true,
+        "break_statement" | "continue_statement" => &["identifier", "statement_identifier"],
+        "break_expression" | "continue_expression" => &["label", "lifetime", "loop_label"],
+        _ => return false,
+    };
+    node.named_children(&mut node.walk())
+        .any(|child| label_kinds.contains(&child.kind()))
+}
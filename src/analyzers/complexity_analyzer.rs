@@ -1,3 +1,4 @@
+use crate::analyzers::loop_analyzer::LoopAnalyzer;
 use crate::models::ir::FunctionCFGIR;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
@@ -9,8 +10,19 @@ pub struct ComplexityResult {
     pub function: String,
     /// Cyclomatic complexity (E - N + 2)
     pub cyclomatic: u32,
+    /// Cognitive complexity, computed from the syntax tree rather than the
+    /// CFG; 0 when only the CFG was analyzed
+    #[serde(default)]
+    pub cognitive: u32,
     /// List of unreachable node IDs
     pub unreachable: Vec<String>,
+    /// Deepest loop nesting, 0 without loops
+    #[serde(default)]
+    pub max_loop_nesting: u32,
+    /// Whether a loop can be entered other than through its header, as with
+    /// a `goto` into a loop body
+    #[serde(default)]
+    pub irreducible: bool,
 }
 
 /// Analyzer for computing cyclomatic complexity and detecting unreachable nodes.
@@ -31,7 +43,10 @@ impl ComplexityAnalyzer {
             return Ok(ComplexityResult {
                 function: cfg.function_name.clone(),
                 cyclomatic: 1,
+                cognitive: 0,
                 unreachable: Vec::new(),
+                max_loop_nesting: 0,
+                irreducible: false,
             });
         }
 
@@ -45,10 +60,15 @@ impl ComplexityAnalyzer {
         // Find unreachable nodes using DFS from ENTRY
         let unreachable = self.find_unreachable_nodes(cfg)?;
 
+        let loops = LoopAnalyzer::new().analyze(cfg)?;
+
         Ok(ComplexityResult {
             function: cfg.function_name.clone(),
             cyclomatic,
+            cognitive: 0,
             unreachable,
+            max_loop_nesting: loops.max_depth(),
+            irreducible: !loops.is_reducible(),
         })
     }
 
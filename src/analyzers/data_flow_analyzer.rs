@@ -50,11 +50,16 @@ impl DataFlowAnalyzer {
     /// Build data flow nodes from CFG nodes.
     fn build_data_flow_nodes(&mut self, cfg: &ControlFlowGraph) -> Result<(), NTreeError> {
         for cfg_node in &cfg.nodes {
+            // ENTRY and EXIT have no source span
+            let (span, line) = match cfg_node.span {
+                Some(span) => (span.format(), span.start_line as u32),
+                None => (String::new(), 0),
+            };
             let mut data_node = DataFlowNode::new(
                 cfg_node.cfg_node.to_string(),
                 cfg_node.label.clone(),
-                format!("{}:{}-{}:{}", 1, 1, 1, 1), // Default span since CFG doesn't have spans
-                1,                                  // Default line number
+                span,
+                line,
             );
 
             // Extract variable definitions and uses from the statement
@@ -119,7 +124,7 @@ impl DataFlowAnalyzer {
                         let var_def = VariableDefinition {
                             variable: def_var.clone(),
                             definition_site: node_id_str.clone(),
-                            span: format!("{}:{}-{}:{}", 1, 1, 1, 1), // Default span
+                            span: data_node.span.clone(),
                             is_initial: node.cfg_node == entry_node.cfg_node,
                         };
                         new_definitions.insert(var_def);
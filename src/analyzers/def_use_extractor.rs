@@ -0,0 +1,538 @@
+use crate::models::ir::NodeDefUse;
+
+/// Extracts the variables a CFG node defines and uses from its label.
+///
+/// Labels are either source statements, such as `let y = x + 1;`, or
+/// normalized control flow labels, such as `while_loop(cond: i < n)`, whose
+/// fields hold source expressions. Extraction is syntactic and shared by all
+/// languages: member and element stores, calls and paths are not variables.
+pub struct DefUseExtractor;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Ident(&'a str),
+    Punct(&'a str),
+    Literal,
+}
+
+/// Keywords, literals and built-in types of the supported languages.
+const KEYWORDS: &[&str] = &[
+    "and",
+    "as",
+    "assert",
+    "async",
+    "auto",
+    "await",
+    "bool",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "const",
+    "continue",
+    "def",
+    "default",
+    "del",
+    "delete",
+    "do",
+    "double",
+    "dyn",
+    "elif",
+    "else",
+    "enum",
+    "except",
+    "extern",
+    "false",
+    "False",
+    "final",
+    "finally",
+    "float",
+    "fn",
+    "for",
+    "from",
+    "function",
+    "global",
+    "goto",
+    "if",
+    "impl",
+    "import",
+    "in",
+    "instanceof",
+    "int",
+    "is",
+    "lambda",
+    "let",
+    "long",
+    "loop",
+    "match",
+    "move",
+    "mut",
+    "new",
+    "nil",
+    "None",
+    "nonlocal",
+    "not",
+    "null",
+    "or",
+    "pass",
+    "raise",
+    "ref",
+    "register",
+    "return",
+    "short",
+    "signed",
+    "sizeof",
+    "static",
+    "struct",
+    "switch",
+    "throw",
+    "true",
+    "True",
+    "try",
+    "typeof",
+    "undefined",
+    "union",
+    "unsafe",
+    "unsigned",
+    "var",
+    "void",
+    "volatile",
+    "where",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Operators assigning their left operand.
+const ASSIGNMENTS: &[&str] = &[
+    "=", ":=", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=", "<<=", ">>=", "**=", "//=", "??=",
+    "&&=", "||=",
+];
+
+/// Multi-character operators, longest first.
+const OPERATORS: &[&str] = &[
+    "<<=", ">>=", "**=", "//=", "??=", "&&=", "||=", "::", "->", "=>", "==", "!=", "<=", ">=",
+    "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=", ":=", "++", "--", "&&", "||", "?.", "**", "//",
+    "<<", ">>",
+];
+
+/// Normalized labels of nodes that evaluate nothing.
+const STRUCTURAL_LABELS: &[&str] = &[
+    "ENTRY",
+    "EXIT",
+    "join",
+    "yield",
+    "loop_expr",
+    "break_stmt",
+    "continue_stmt",
+    "try_stmt",
+    "try_else",
+    "finally_block",
+];
+
+impl DefUseExtractor {
+    /// Extract the variables a node label defines and uses.
+    pub fn extract(label: &str) -> NodeDefUse {
+        let label = label.trim();
+        if Self::is_structural(label) {
+            return NodeDefUse::default();
+        }
+        if let Some(def_use) = Self::extract_normalized(label) {
+            return def_use;
+        }
+        Self::extract_statement(label)
+    }
+
+    fn is_structural(label: &str) -> bool {
+        STRUCTURAL_LABELS.contains(&label)
+            || label.ends_with("_join")
+            || label.ends_with("_body")
+            || label.ends_with("_else")
+            || label.starts_with("after_")
+    }
+
+    /// Extract from a normalized `kind(field: value, ...)` label, or return
+    /// `None` for a source statement.
+    fn extract_normalized(label: &str) -> Option<NodeDefUse> {
+        let (kind, args) = label.strip_suffix(')')?.split_once('(')?;
+        let mut def_use = NodeDefUse::default();
+        match kind {
+            // Jump targets and handlers, whose fields are names and types
+            "label" | "labeled_block" | "catch_clause" => {}
+            "condition" => Self::add_uses(&mut def_use, args),
+            "for_loop"
+            | "comprehension"
+            | "let_else"
+            | "match_arm"
+            | "while_loop"
+            | "do_while_loop"
+            | "match_expr"
+            | "switch_stmt"
+            | "case"
+            | "case_guard"
+            | "comprehension_filter"
+            | "await"
+            | "yield"
+            | "yield_from"
+            | "async_for_next" => Self::add_fields(&mut def_use, args),
+            _ if kind.starts_with("ternary_")
+                || kind.starts_with("with_")
+                || kind.starts_with("async_with_") =>
+            {
+                Self::add_fields(&mut def_use, args)
+            }
+            _ => return None,
+        }
+        Some(def_use)
+    }
+
+    /// Add the definitions and uses of a normalized label's fields. A
+    /// counter loop's header holds its initialization and update, so it
+    /// defines the loop variable.
+    fn add_fields(def_use: &mut NodeDefUse, args: &str) {
+        for (key, value) in Self::split_fields(args) {
+            match key {
+                "pattern" => {
+                    let tokens = Self::tokenize(value);
+                    for name in Self::pattern_defs(&tokens) {
+                        Self::push_unique(&mut def_use.defs, name);
+                    }
+                }
+                "init" | "update" => {
+                    let statement = Self::extract_statement(value);
+                    for name in statement.uses {
+                        Self::push_unique(&mut def_use.uses, &name);
+                    }
+                    for name in statement.defs {
+                        Self::push_unique(&mut def_use.defs, &name);
+                    }
+                }
+                "cond" | "value" | "iter" => Self::add_uses(def_use, value),
+                _ => {}
+            }
+        }
+    }
+
+    /// Split `key: value, key: value` fields, whose values may hold commas.
+    fn split_fields(args: &str) -> Vec<(&str, &str)> {
+        let keys = ["init", "cond", "update", "pattern", "value", "iter"];
+        let mut starts = Vec::new();
+        let mut depth = 0i32;
+        for (i, c) in args.char_indices() {
+            match c {
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ => {}
+            }
+            let at_field = i == 0 || (depth == 0 && args[..i].ends_with(", "));
+            if at_field {
+                if let Some(key) = keys
+                    .iter()
+                    .find(|key| args[i..].starts_with(&format!("{}: ", key)))
+                {
+                    starts.push((i, *key));
+                }
+            }
+        }
+
+        let mut fields = Vec::new();
+        for (n, &(start, key)) in starts.iter().enumerate() {
+            let end = starts.get(n + 1).map_or(args.len(), |&(next, _)| next - 2);
+            fields.push((key, args[start + key.len() + 2..end].trim()));
+        }
+        fields
+    }
+
+    /// Extract from a source statement or expression.
+    fn extract_statement(statement: &str) -> NodeDefUse {
+        let statement = statement.trim().trim_end_matches(';');
+        let tokens = Self::tokenize(statement);
+        let mut def_use = NodeDefUse::default();
+
+        let mut depth = 0i32;
+        let mut assignment = None;
+        for (i, token) in tokens.iter().enumerate() {
+            match token {
+                Token::Punct("(" | "[" | "{") => depth += 1,
+                Token::Punct(")" | "]" | "}") => depth -= 1,
+                Token::Punct(op) if depth == 0 && ASSIGNMENTS.contains(op) => {
+                    assignment = Some((i, *op));
+                    break;
+                }
+                _ => {}
+            }
+        }
+
+        match assignment {
+            Some((i, op)) => {
+                let (targets, uses) = Self::split_targets(&tokens[..i]);
+                // Compound assignments read their target first
+                if op != "=" && op != ":=" {
+                    for name in &targets {
+                        Self::push_unique(&mut def_use.uses, name);
+                    }
+                }
+                for name in uses.into_iter().chain(Self::uses(&tokens[i + 1..])) {
+                    Self::push_unique(&mut def_use.uses, name);
+                }
+                for name in targets {
+                    Self::push_unique(&mut def_use.defs, name);
+                }
+            }
+            None => match Self::declaration(&tokens) {
+                Some(name) => Self::push_unique(&mut def_use.defs, name),
+                None => {
+                    for name in Self::uses(&tokens) {
+                        Self::push_unique(&mut def_use.uses, name);
+                    }
+                    for name in Self::increments(&tokens) {
+                        Self::push_unique(&mut def_use.defs, name);
+                    }
+                }
+            },
+        }
+        def_use
+    }
+
+    /// Find the variables an assignment's left side defines, and those it
+    /// reads to store into an element or member.
+    fn split_targets<'a>(tokens: &[Token<'a>]) -> (Vec<&'a str>, Vec<&'a str>) {
+        let mut defs = Vec::new();
+        let mut uses = Vec::new();
+        let is_var_declaration = tokens.first() == Some(&Token::Ident("var"));
+
+        // Drop type annotations, as in `let x: i32` or `x: int`
+        let tokens = match Self::split_top_level(tokens, ":").first() {
+            Some(untyped) => *untyped,
+            None => tokens,
+        };
+        for part in Self::split_top_level(tokens, ",") {
+            // Skip `let`, `mut`, `const` and other declaration keywords
+            let start = part
+                .iter()
+                .position(|token| !matches!(token, Token::Ident(word) if KEYWORDS.contains(word)))
+                .unwrap_or(part.len());
+            let target = &part[start..];
+            let is_store = start == 0
+                && matches!(
+                    target,
+                    [Token::Ident(_), Token::Punct("[" | "." | "->" | "?."), ..]
+                        | [Token::Punct("*"), ..]
+                );
+
+            match target {
+                _ if is_store => uses.extend(Self::uses(target)),
+                _ if target
+                    .iter()
+                    .any(|token| matches!(token, Token::Punct("(" | "[" | "{"))) =>
+                {
+                    defs.extend(Self::pattern_defs(target))
+                }
+                // Go's `var x T` names the variable first, C's `T x` last
+                _ => {
+                    let mut names = target.iter().filter_map(|token| match token {
+                        Token::Ident(word) => Some(*word),
+                        _ => None,
+                    });
+                    let name = if is_var_declaration {
+                        names.next()
+                    } else {
+                        names.next_back()
+                    };
+                    defs.extend(name);
+                }
+            }
+        }
+        (defs, uses)
+    }
+
+    /// Find the variables bound by a pattern, such as `(a, b)`, `Some(x)` or
+    /// `{ left, right }`.
+    fn pattern_defs<'a>(tokens: &[Token<'a>]) -> Vec<&'a str> {
+        let mut defs = Vec::new();
+        for (i, token) in tokens.iter().enumerate() {
+            let name = match token {
+                Token::Ident(name) => *name,
+                _ => continue,
+            };
+            let next = tokens.get(i + 1);
+            let is_constructor = matches!(next, Some(Token::Punct("(" | "::" | "{")));
+            let is_path = i > 0 && matches!(tokens[i - 1], Token::Punct("::" | "."));
+            let is_binding = name
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_lowercase() || c == '_');
+            if !KEYWORDS.contains(&name) && !is_constructor && !is_path && is_binding {
+                defs.push(name);
+            }
+        }
+        defs
+    }
+
+    /// Find the variables incremented or decremented by `++`/`--`.
+    fn increments<'a>(tokens: &[Token<'a>]) -> Vec<&'a str> {
+        let mut defs = Vec::new();
+        for (i, token) in tokens.iter().enumerate() {
+            if !matches!(token, Token::Punct("++" | "--")) {
+                continue;
+            }
+            let operand = match (i.checked_sub(1).map(|j| tokens[j]), tokens.get(i + 1)) {
+                (Some(Token::Ident(name)), _) => Some(name),
+                (_, Some(Token::Ident(name))) => Some(*name),
+                _ => None,
+            };
+            defs.extend(operand.filter(|name| !KEYWORDS.contains(name)));
+        }
+        defs
+    }
+
+    /// Find the variable declared without a value, as in `int x` or `let x`.
+    fn declaration<'a>(tokens: &[Token<'a>]) -> Option<&'a str> {
+        let first = match tokens.first() {
+            Some(Token::Ident(word)) => *word,
+            _ => return None,
+        };
+        let has_keyword =
+            matches!(first, "let" | "var" | "const" | "auto" | "static") || Self::is_type(first);
+        let mut names = Vec::new();
+        for token in tokens {
+            match token {
+                Token::Ident(word) => names.push(*word),
+                Token::Punct("*" | "&") if has_keyword => {}
+                _ => return None,
+            }
+        }
+        // Without a keyword, only `Type name` declares
+        let declares = has_keyword || (names.len() == 2 && !KEYWORDS.contains(&first));
+        let name = *names.last()?;
+        (declares && names.len() >= 2 && !KEYWORDS.contains(&name)).then_some(name)
+    }
+
+    fn is_type(word: &str) -> bool {
+        matches!(
+            word,
+            "int"
+                | "long"
+                | "short"
+                | "char"
+                | "float"
+                | "double"
+                | "bool"
+                | "boolean"
+                | "byte"
+                | "unsigned"
+                | "signed"
+                | "void"
+                | "struct"
+                | "enum"
+                | "union"
+                | "register"
+                | "volatile"
+                | "final"
+                | "mut"
+        )
+    }
+
+    fn add_uses(def_use: &mut NodeDefUse, expression: &str) {
+        for name in Self::uses(&Self::tokenize(expression)) {
+            Self::push_unique(&mut def_use.uses, name);
+        }
+    }
+
+    /// Find the variables an expression reads, skipping keywords, callees,
+    /// members, path segments and keyword arguments.
+    fn uses<'a>(tokens: &[Token<'a>]) -> Vec<&'a str> {
+        let mut uses = Vec::new();
+        for (i, token) in tokens.iter().enumerate() {
+            let name = match token {
+                Token::Ident(name) => *name,
+                _ => continue,
+            };
+            let previous = i.checked_sub(1).map(|j| tokens[j]);
+            let next = tokens.get(i + 1).copied();
+            let is_member = matches!(previous, Some(Token::Punct("." | "->" | "::" | "?.")));
+            let is_callee = matches!(next, Some(Token::Punct("(" | "::" | "=")))
+                || (next == Some(Token::Punct("!"))
+                    && matches!(tokens.get(i + 2), Some(Token::Punct("(" | "[" | "{"))));
+            if !KEYWORDS.contains(&name) && !is_member && !is_callee {
+                uses.push(name);
+            }
+        }
+        uses
+    }
+
+    /// Split tokens on a separator outside brackets.
+    fn split_top_level<'t, 'a>(tokens: &'t [Token<'a>], separator: &str) -> Vec<&'t [Token<'a>]> {
+        let mut parts = Vec::new();
+        let mut depth = 0i32;
+        let mut start = 0;
+        for (i, token) in tokens.iter().enumerate() {
+            match token {
+                Token::Punct("(" | "[" | "{") => depth += 1,
+                Token::Punct(")" | "]" | "}") => depth -= 1,
+                Token::Punct(punct) if depth == 0 && *punct == separator => {
+                    parts.push(&tokens[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(&tokens[start..]);
+        parts
+    }
+
+    /// Split source text into identifiers, punctuation and literals.
+    fn tokenize(text: &str) -> Vec<Token<'_>> {
+        let mut tokens = Vec::new();
+        let bytes = text.as_bytes();
+        let mut i = 0;
+        while i < text.len() {
+            let c = text[i..].chars().next().unwrap_or(' ');
+            if c.is_whitespace() {
+                i += c.len_utf8();
+            } else if c == '"' || c == '`' || (c == '\'' && text[i + 1..].contains('\'')) {
+                // String or character literal
+                let mut end = i + 1;
+                while end < text.len() && bytes[end] != bytes[i] {
+                    end += if bytes[end] == b'\\' { 2 } else { 1 };
+                }
+                tokens.push(Token::Literal);
+                i = (end + 1).min(text.len());
+            } else if c.is_alphabetic() || c == '_' || c == '$' || c == '\'' {
+                // A lone quote starts a Rust lifetime or loop label
+                let start = if c == '\'' { i + 1 } else { i };
+                let end = text[start..]
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+                    .map_or(text.len(), |offset| start + offset);
+                let word = &text[start..end];
+                let is_string_prefix = matches!(bytes.get(end), Some(b'"' | b'\''))
+                    && word.len() <= 2
+                    && word.chars().all(|c| "fFrRbBuU".contains(c));
+                if c != '\'' && !is_string_prefix && !word.is_empty() {
+                    tokens.push(Token::Ident(word));
+                }
+                i = end.max(i + 1);
+            } else if c.is_ascii_digit() {
+                let end = text[i..]
+                    .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.'))
+                    .map_or(text.len(), |offset| i + offset);
+                tokens.push(Token::Literal);
+                i = end;
+            } else {
+                let operator = OPERATORS.iter().find(|op| text[i..].starts_with(**op));
+                let len = operator.map_or(c.len_utf8(), |op| op.len());
+                tokens.push(Token::Punct(&text[i..i + len]));
+                i += len;
+            }
+        }
+        tokens
+    }
+
+    fn push_unique(names: &mut Vec<String>, name: &str) {
+        if !names.iter().any(|existing| existing == name) {
+            names.push(name.to_string());
+        }
+    }
+}
@@ -0,0 +1,443 @@
+use crate::models::ir::FunctionCFGIR;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// An edge of a function's dominator or post-dominator tree.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DominatorTreeEdge {
+    /// "Dominator" or "PostDominator"
+    #[serde(rename = "type")]
+    pub edge_type: String,
+    /// Function this edge belongs to
+    pub func: String,
+    /// Immediate (post-)dominator of `node`
+    pub parent: String,
+    /// Node identifier
+    pub node: String,
+}
+
+/// The nodes where a node's dominance ends, the first ones it does not
+/// strictly dominate along some path.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DominanceFrontier {
+    #[serde(rename = "type")]
+    pub frontier_type: String,
+    /// Function this frontier belongs to
+    pub func: String,
+    /// Node identifier
+    pub node: String,
+    /// Node identifiers in the frontier, in CFG order
+    pub frontier: Vec<String>,
+}
+
+/// An edge of the control dependence graph: whether `to` runs is decided by
+/// the edge taken out of `from`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ControlDependence {
+    #[serde(rename = "type")]
+    pub dependence_type: String,
+    /// Function this dependence belongs to
+    pub func: String,
+    /// Branching node, such as a condition
+    pub from: String,
+    /// Node that runs only for some outcomes of `from`
+    pub to: String,
+    /// Kind of the CFG edge out of `from` that leads to `to` (e.g., "true")
+    pub kind: String,
+}
+
+/// Dominance and control dependence analysis result for a function.
+///
+/// Nodes unreachable from ENTRY have no dominator, and nodes that cannot
+/// reach EXIT, such as those of an infinite loop, have no post-dominator
+/// nor control dependences.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DominanceResult {
+    /// Function name being analyzed
+    pub function: String,
+    /// Qualified name of the function, e.g. `Parser::parse`
+    #[serde(default)]
+    pub qualname: String,
+    /// Dominator tree, rooted at ENTRY
+    pub dominators: Vec<DominatorTreeEdge>,
+    /// Post-dominator tree, rooted at EXIT
+    pub post_dominators: Vec<DominatorTreeEdge>,
+    /// Non-empty dominance frontiers
+    pub frontiers: Vec<DominanceFrontier>,
+    /// Control dependence graph
+    pub control_dependences: Vec<ControlDependence>,
+}
+
+impl DominanceResult {
+    /// Get the immediate dominator of a node.
+    pub fn immediate_dominator(&self, node: &str) -> Option<&str> {
+        Self::parent(&self.dominators, node)
+    }
+
+    /// Get the immediate post-dominator of a node.
+    pub fn immediate_post_dominator(&self, node: &str) -> Option<&str> {
+        Self::parent(&self.post_dominators, node)
+    }
+
+    /// Check whether every path from ENTRY to `node` goes through `dominator`.
+    /// A reachable node dominates itself.
+    pub fn dominates(&self, dominator: &str, node: &str) -> bool {
+        Self::is_ancestor(&self.dominators, dominator, node)
+    }
+
+    /// Check whether every path from `node` to EXIT goes through `post_dominator`.
+    pub fn post_dominates(&self, post_dominator: &str, node: &str) -> bool {
+        Self::is_ancestor(&self.post_dominators, post_dominator, node)
+    }
+
+    /// Get the dominance frontier of a node.
+    pub fn dominance_frontier(&self, node: &str) -> &[String] {
+        self.frontiers
+            .iter()
+            .find(|frontier| frontier.node == node)
+            .map(|frontier| frontier.frontier.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Get the branches that decide whether a node runs, such as the
+    /// conditions guarding a statement.
+    pub fn controlling_branches(&self, node: &str) -> Vec<&ControlDependence> {
+        self.control_dependences
+            .iter()
+            .filter(|dependence| dependence.to == node)
+            .collect()
+    }
+
+    /// Get the nodes whose execution a branch decides.
+    pub fn dependents(&self, branch: &str) -> Vec<&ControlDependence> {
+        self.control_dependences
+            .iter()
+            .filter(|dependence| dependence.from == branch)
+            .collect()
+    }
+
+    /// Convert to JSONL format, one tree edge, frontier or dependence per line.
+    pub fn to_jsonl(&self) -> String {
+        let mut jsonl = String::new();
+        let lines = self
+            .dominators
+            .iter()
+            .chain(&self.post_dominators)
+            .map(serde_json::to_string)
+            .chain(self.frontiers.iter().map(serde_json::to_string))
+            .chain(self.control_dependences.iter().map(serde_json::to_string));
+        for line in lines {
+            match line {
+                Ok(json) => {
+                    jsonl.push_str(&json);
+                    jsonl.push('\n');
+                }
+                Err(_) => continue,
+            }
+        }
+        jsonl
+    }
+
+    fn parent<'a>(tree: &'a [DominatorTreeEdge], node: &str) -> Option<&'a str> {
+        tree.iter()
+            .find(|edge| edge.node == node)
+            .map(|edge| edge.parent.as_str())
+    }
+
+    fn is_ancestor(tree: &[DominatorTreeEdge], ancestor: &str, node: &str) -> bool {
+        let mut current = node;
+        let mut visited = HashSet::new();
+        while visited.insert(current) {
+            if current == ancestor {
+                return true;
+            }
+            match Self::parent(tree, current) {
+                Some(parent) => current = parent,
+                None => break,
+            }
+        }
+        false
+    }
+}
+
+/// A function's CFG with its nodes indexed in CFG order, so results follow it.
+pub(crate) struct IndexedCfg<'a> {
+    ir: &'a FunctionCFGIR,
+    /// Successors `(node, edge kind)` of each node
+    pub successors: Vec<Vec<(usize, &'a str)>>,
+    pub predecessors: Vec<Vec<usize>>,
+    /// ENTRY, or the first node without one
+    pub entry: usize,
+    pub exit: Option<usize>,
+}
+
+impl<'a> IndexedCfg<'a> {
+    pub fn new(ir: &'a FunctionCFGIR) -> Self {
+        let index: HashMap<&str, usize> = ir
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), i))
+            .collect();
+        let mut successors = vec![Vec::new(); ir.nodes.len()];
+        let mut predecessors = vec![Vec::new(); ir.nodes.len()];
+        for edge in &ir.edges {
+            if let (Some(&from), Some(&to)) =
+                (index.get(edge.from.as_str()), index.get(edge.to.as_str()))
+            {
+                successors[from].push((to, edge.kind.as_str()));
+                predecessors[to].push(from);
+            }
+        }
+
+        IndexedCfg {
+            ir,
+            successors,
+            predecessors,
+            entry: ir
+                .nodes
+                .iter()
+                .position(|node| node.label == "ENTRY")
+                .unwrap_or(0),
+            exit: ir.nodes.iter().position(|node| node.label == "EXIT"),
+        }
+    }
+
+    /// Successor nodes of each node, without edge kinds.
+    pub fn targets(&self) -> Vec<Vec<usize>> {
+        self.successors
+            .iter()
+            .map(|edges| edges.iter().map(|&(to, _)| to).collect())
+            .collect()
+    }
+
+    /// Identifier of a node.
+    pub fn id(&self, node: usize) -> String {
+        self.ir.nodes[node].id.clone()
+    }
+}
+
+/// Analyzer for computing dominator trees, dominance frontiers and control
+/// dependences of a function's CFG.
+pub struct DominanceAnalyzer;
+
+impl DominanceAnalyzer {
+    /// Create a new dominance analyzer.
+    pub fn new() -> Self {
+        DominanceAnalyzer
+    }
+
+    /// Analyze a function's CFG for dominance and control dependence.
+    pub fn analyze(&self, cfg: &FunctionCFGIR) -> Result<DominanceResult, String> {
+        let func = cfg.function_name.clone();
+        let mut result = DominanceResult {
+            function: func.clone(),
+            qualname: cfg.qualname.clone(),
+            dominators: Vec::new(),
+            post_dominators: Vec::new(),
+            frontiers: Vec::new(),
+            control_dependences: Vec::new(),
+        };
+        if cfg.nodes.is_empty() {
+            return Ok(result);
+        }
+
+        let graph = IndexedCfg::new(cfg);
+        let (successors, predecessors) = (&graph.successors, &graph.predecessors);
+        let forward = graph.targets();
+        let id = |i: usize| graph.id(i);
+
+        // Dominators and dominance frontiers, from ENTRY
+        let entry = graph.entry;
+        let idom = Self::immediate_dominators(&forward, predecessors, entry);
+        result.dominators = Self::tree_edges(&idom, "Dominator", &func, &id);
+
+        let frontiers = Self::dominance_frontiers(predecessors, &idom);
+        for (node, frontier) in frontiers.into_iter().enumerate() {
+            if frontier.is_empty() {
+                continue;
+            }
+            result.frontiers.push(DominanceFrontier {
+                frontier_type: "DominanceFrontier".to_string(),
+                func: func.clone(),
+                node: id(node),
+                frontier: frontier.into_iter().map(id).collect(),
+            });
+        }
+
+        // Post-dominators and control dependences, from EXIT on the reverse CFG
+        let exit = match graph.exit {
+            Some(exit) => exit,
+            None => return Ok(result), // No EXIT, nothing post-dominates
+        };
+        let ipdom = Self::immediate_dominators(predecessors, &forward, exit);
+        result.post_dominators = Self::tree_edges(&ipdom, "PostDominator", &func, &id);
+
+        let mut seen = HashSet::new();
+        for (from, edges) in successors.iter().enumerate() {
+            if ipdom[from].is_none() {
+                continue;
+            }
+            for &(to, kind) in edges {
+                // The nodes from `to` up to the branch's post-dominator run
+                // only when this edge is taken
+                let mut runner = to;
+                while Some(runner) != ipdom[from] && ipdom[runner].is_some() {
+                    if seen.insert((from, runner, kind)) {
+                        result.control_dependences.push(ControlDependence {
+                            dependence_type: "ControlDependence".to_string(),
+                            func: func.clone(),
+                            from: id(from),
+                            to: id(runner),
+                            kind: kind.to_string(),
+                        });
+                    }
+                    if runner == exit {
+                        break;
+                    }
+                    runner = ipdom[runner].unwrap_or(exit);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Compute immediate dominators with the iterative algorithm of Cooper,
+    /// Harvey and Kennedy. The root is its own dominator, and nodes
+    /// unreachable from it have none.
+    pub(crate) fn immediate_dominators(
+        successors: &[Vec<usize>],
+        predecessors: &[Vec<usize>],
+        root: usize,
+    ) -> Vec<Option<usize>> {
+        let order = Self::reverse_postorder(successors, root);
+        let mut rank = vec![usize::MAX; successors.len()];
+        for (position, &node) in order.iter().enumerate() {
+            rank[node] = position;
+        }
+
+        let mut idom = vec![None; successors.len()];
+        idom[root] = Some(root);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &node in order.iter().skip(1) {
+                let mut new_idom = None;
+                for &pred in &predecessors[node] {
+                    if idom[pred].is_none() {
+                        continue; // Not processed yet, or unreachable
+                    }
+                    new_idom = Some(match new_idom {
+                        None => pred,
+                        Some(current) => Self::intersect(&idom, &rank, pred, current),
+                    });
+                }
+                if new_idom.is_some() && idom[node] != new_idom {
+                    idom[node] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+        idom
+    }
+
+    /// Compute the dominance frontier of each node, in CFG order.
+    pub(crate) fn dominance_frontiers(
+        predecessors: &[Vec<usize>],
+        idom: &[Option<usize>],
+    ) -> Vec<Vec<usize>> {
+        let mut frontiers: Vec<Vec<usize>> = vec![Vec::new(); idom.len()];
+        for node in 0..idom.len() {
+            let preds: Vec<usize> = predecessors[node]
+                .iter()
+                .copied()
+                .filter(|&pred| idom[pred].is_some())
+                .collect();
+            if preds.len() < 2 || idom[node].is_none() {
+                continue;
+            }
+            for pred in preds {
+                // Walk up from each predecessor until the join's dominator
+                let mut runner = pred;
+                while Some(runner) != idom[node] {
+                    if !frontiers[runner].contains(&node) {
+                        frontiers[runner].push(node);
+                    }
+                    match idom[runner] {
+                        Some(parent) if parent != runner => runner = parent,
+                        _ => break,
+                    }
+                }
+            }
+        }
+        for frontier in &mut frontiers {
+            frontier.sort_unstable();
+        }
+        frontiers
+    }
+
+    /// Find the nearest common dominator of two nodes.
+    fn intersect(idom: &[Option<usize>], rank: &[usize], mut a: usize, mut b: usize) -> usize {
+        while a != b {
+            while rank[a] > rank[b] {
+                a = idom[a].unwrap_or(a);
+            }
+            while rank[b] > rank[a] {
+                b = idom[b].unwrap_or(b);
+            }
+        }
+        a
+    }
+
+    /// Order the nodes reachable from `root` so each comes before its
+    /// successors, back edges aside.
+    pub(crate) fn reverse_postorder(successors: &[Vec<usize>], root: usize) -> Vec<usize> {
+        let mut visited = vec![false; successors.len()];
+        let mut postorder = Vec::new();
+        let mut stack = vec![(root, 0)];
+        visited[root] = true;
+
+        while let Some((node, next)) = stack.pop() {
+            match successors[node].get(next) {
+                Some(&child) => {
+                    stack.push((node, next + 1));
+                    if !visited[child] {
+                        visited[child] = true;
+                        stack.push((child, 0));
+                    }
+                }
+                None => postorder.push(node),
+            }
+        }
+
+        postorder.reverse();
+        postorder
+    }
+
+    fn tree_edges(
+        idom: &[Option<usize>],
+        edge_type: &str,
+        func: &str,
+        id: &dyn Fn(usize) -> String,
+    ) -> Vec<DominatorTreeEdge> {
+        idom.iter()
+            .enumerate()
+            .filter_map(|(node, parent)| match parent {
+                Some(parent) if *parent != node => Some(DominatorTreeEdge {
+                    edge_type: edge_type.to_string(),
+                    func: func.to_string(),
+                    parent: id(*parent),
+                    node: id(node),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl Default for DominanceAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -24,18 +24,59 @@ impl ForLoopNormalizer {
         }
     }
 
-    /// Analyze C-style for loops: for(init; condition; update)
-    /// Will be implemented when we add support for C/Java/JS.
-    fn analyze_c_style_for(_for_node: Node, _source: &str, _loop_id: String) -> Option<ForLoopIR> {
-        // TODO: Implement for C/Java/JavaScript support
-        None
+    /// Analyze C-style for loops: for(init; condition; update), and the
+    /// iterator loops of the same languages: JavaScript `for (x of xs)`,
+    /// Java `for (T x : xs)` and C++ `for (auto x : xs)`.
+    fn analyze_c_style_for(for_node: Node, source: &str, loop_id: String) -> Option<ForLoopIR> {
+        match for_node.kind() {
+            "for_in_statement" => {
+                Self::analyze_iterator_for(for_node, source, loop_id, "left", "right")
+            }
+            "enhanced_for_statement" => {
+                Self::analyze_iterator_for(for_node, source, loop_id, "name", "value")
+            }
+            "for_range_loop" => {
+                Self::analyze_iterator_for(for_node, source, loop_id, "declarator", "right")
+            }
+            _ => {
+                // Field names differ: C `initializer`/`update`, Java `init`/`update`,
+                // JavaScript `initializer`/`increment`; any part may be omitted
+                let part = |fields: &[&str]| {
+                    fields
+                        .iter()
+                        .find_map(|field| for_node.child_by_field_name(field))
+                        .map(|node| Self::extract_text(node, source))
+                };
+                let mut for_ir =
+                    ForLoopIR::new_counter(loop_id, String::new(), String::new(), String::new());
+                for_ir.init = part(&["initializer", "init"]);
+                for_ir.condition = part(&["condition"]);
+                for_ir.update = part(&["update", "increment"]);
+                Some(for_ir)
+            }
+        }
     }
 
     /// Analyze Python-style for loops: for x in xs:
-    /// Will be implemented when we add Python support.
-    fn analyze_python_for(_for_node: Node, _source: &str, _loop_id: String) -> Option<ForLoopIR> {
-        // TODO: Implement for Python support
-        None
+    fn analyze_python_for(for_node: Node, source: &str, loop_id: String) -> Option<ForLoopIR> {
+        Self::analyze_iterator_for(for_node, source, loop_id, "left", "right")
+    }
+
+    /// Build iterator loop IR from the fields holding the pattern and the iterable.
+    fn analyze_iterator_for(
+        for_node: Node,
+        source: &str,
+        loop_id: String,
+        pattern_field: &str,
+        iter_field: &str,
+    ) -> Option<ForLoopIR> {
+        let pattern = for_node.child_by_field_name(pattern_field)?;
+        let iter_expr = for_node.child_by_field_name(iter_field)?;
+        Some(ForLoopIR::new_iterator(
+            loop_id,
+            Self::extract_text(pattern, source),
+            Self::extract_text(iter_expr, source),
+        ))
     }
 
     /// Auto-detect language from node types (fallback).
@@ -44,8 +85,22 @@ impl ForLoopNormalizer {
         source: &str,
         loop_id: String,
     ) -> Option<ForLoopIR> {
-        // For now, assume Rust since that's what we're working with
-        // Later we can add more sophisticated language detection
-        Self::normalize(for_node, source, "rust", loop_id)
+        let language = match for_node.kind() {
+            "for_statement" if for_node.child_by_field_name("left").is_some() => "python",
+            "for_statement" => "c",
+            "for_in_statement" => "javascript",
+            "enhanced_for_statement" => "java",
+            "for_range_loop" => "cpp",
+            _ => "rust",
+        };
+        Self::normalize(for_node, source, language, loop_id)
+    }
+
+    /// Extract node text without a declaration's trailing semicolon.
+    fn extract_text(node: Node, source: &str) -> String {
+        source[node.start_byte()..node.end_byte()]
+            .trim()
+            .trim_end_matches(';')
+            .to_string()
     }
 }
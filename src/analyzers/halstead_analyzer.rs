@@ -0,0 +1,190 @@
+use crate::models::HalsteadResult;
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+/// Closing brackets, counted with their opening bracket as one operator.
+const CLOSING_TOKENS: &[&str] = &[")", "]", "}"];
+
+/// Analyzer for Halstead metrics from a function's tokens.
+///
+/// Operands are identifiers, values and literals, each literal counting as a
+/// single token. Operators are the remaining tokens: keywords, punctuation
+/// and operators, with a bracket pair counted once. Comments are skipped.
+pub struct HalsteadAnalyzer;
+
+impl HalsteadAnalyzer {
+    /// Create a new Halstead analyzer.
+    pub fn new() -> Self {
+        HalsteadAnalyzer
+    }
+
+    /// Analyze a function node. `cyclomatic` is the function's cyclomatic
+    /// complexity, which the Maintainability Index accounts for.
+    pub fn analyze(
+        &self,
+        function_name: &str,
+        function: Node,
+        source: &str,
+        cyclomatic: u32,
+    ) -> HalsteadResult {
+        let mut counts = TokenCounts::default();
+        counts.count(function, source);
+
+        let distinct_operators = counts.operators.len() as u32;
+        let distinct_operands = counts.operands.len() as u32;
+        let vocabulary = distinct_operators + distinct_operands;
+        let length = counts.total_operators + counts.total_operands;
+
+        let volume = if vocabulary > 0 {
+            f64::from(length) * f64::from(vocabulary).log2()
+        } else {
+            0.0
+        };
+        let difficulty = if distinct_operands > 0 {
+            f64::from(distinct_operators) / 2.0 * f64::from(counts.total_operands)
+                / f64::from(distinct_operands)
+        } else {
+            0.0
+        };
+        let loc = (function.end_position().row - function.start_position().row + 1) as u32;
+
+        HalsteadResult {
+            function: function_name.to_string(),
+            qualname: function_name.to_string(),
+            distinct_operators,
+            distinct_operands,
+            total_operators: counts.total_operators,
+            total_operands: counts.total_operands,
+            vocabulary,
+            length,
+            volume,
+            difficulty,
+            effort: difficulty * volume,
+            loc,
+            cyclomatic,
+            maintainability_index: Self::maintainability_index(volume, cyclomatic, loc),
+        }
+    }
+
+    /// Maintainability Index, `171 - 5.2 ln(V) - 0.23 G - 16.2 ln(LOC)`,
+    /// rescaled from 0-171 to 0-100 and clamped at 0.
+    pub fn maintainability_index(volume: f64, cyclomatic: u32, loc: u32) -> f64 {
+        let index = 171.0
+            - 5.2 * volume.max(1.0).ln()
+            - 0.23 * f64::from(cyclomatic)
+            - 16.2 * f64::from(loc.max(1)).ln();
+        (index * 100.0 / 171.0).max(0.0)
+    }
+}
+
+impl Default for HalsteadAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Distinct and total operators and operands seen so far.
+#[derive(Default)]
+struct TokenCounts {
+    operators: HashSet<String>,
+    operands: HashSet<String>,
+    total_operators: u32,
+    total_operands: u32,
+}
+
+impl TokenCounts {
+    fn count(&mut self, node: Node, source: &str) {
+        let kind = node.kind();
+        if kind.contains("comment") {
+            return;
+        }
+
+        if is_literal(kind) && has_interpolation(node) {
+            // An interpolated string is one operator; its text fragments are
+            // operands and the interpolated expressions count as usual
+            self.add_operator(kind);
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                if is_interpolation(child.kind()) {
+                    self.count(child, source);
+                } else if is_string_fragment(child.kind()) {
+                    self.add_operand(&source[child.start_byte()..child.end_byte()]);
+                }
+            }
+        } else if is_literal(kind) || (node.child_count() == 0 && is_operand(kind)) {
+            self.add_operand(&source[node.start_byte()..node.end_byte()]);
+        } else if node.child_count() == 0 {
+            // Keyword-like named leaves such as `mut` count by their text
+            if node.is_named() {
+                self.add_operator(&source[node.start_byte()..node.end_byte()]);
+            } else if !CLOSING_TOKENS.contains(&kind) {
+                self.add_operator(kind);
+            }
+        } else {
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                self.count(child, source);
+            }
+        }
+    }
+
+    fn add_operator(&mut self, token: &str) {
+        self.operators.insert(token.to_string());
+        self.total_operators += 1;
+    }
+
+    fn add_operand(&mut self, token: &str) {
+        self.operands.insert(token.to_string());
+        self.total_operands += 1;
+    }
+}
+
+/// Named leaves that are values rather than keywords.
+const VALUE_KINDS: &[&str] = &[
+    "integer",
+    "float",
+    "number",
+    "character",
+    "true",
+    "false",
+    "none",
+    "null",
+    "undefined",
+    "self",
+    "this",
+    "metavariable",
+    "jsx_text",
+];
+
+/// Whether a node is a literal whose pieces (quotes, escapes, content) form
+/// one operand.
+fn is_literal(kind: &str) -> bool {
+    kind.ends_with("_literal") || kind.contains("string") || kind == "regex"
+}
+
+/// Whether a leaf node is an operand: an identifier or a value.
+fn is_operand(kind: &str) -> bool {
+    kind.contains("identifier") || VALUE_KINDS.contains(&kind)
+}
+
+/// Whether a node is an expression interpolated into a string, such as
+/// Python's `{x}` or JavaScript's `${x}`.
+fn is_interpolation(kind: &str) -> bool {
+    matches!(
+        kind,
+        "interpolation" | "template_substitution" | "string_interpolation"
+    )
+}
+
+/// Whether a node is the literal text of a string between interpolations.
+fn is_string_fragment(kind: &str) -> bool {
+    matches!(
+        kind,
+        "string_content" | "string_fragment" | "escape_sequence"
+    )
+}
+
+fn has_interpolation(node: Node) -> bool {
+    node.named_children(&mut node.walk())
+        .any(|child| is_interpolation(child.kind()))
+}
@@ -0,0 +1,71 @@
+use crate::core::format_node_span;
+use tree_sitter::Node;
+
+/// Utilities for working with C AST nodes.
+pub struct CAstUtils;
+
+impl CAstUtils {
+    /// Extract span information from a node.
+    pub fn extract_span(node: Node) -> String {
+        format_node_span(node)
+    }
+
+    /// Extract text content from node.
+    pub fn extract_text(node: Node, source: &str) -> String {
+        let start = node.start_byte();
+        let end = node.end_byte();
+        source.get(start..end).unwrap_or("").trim().to_string()
+    }
+
+    /// Find the identifier a declarator introduces.
+    ///
+    /// Unwraps pointer, array, init, and parenthesized declarators. The flag is
+    /// `true` when the declarator declares a function, and `false` for plain
+    /// variables and function pointers such as `void (*handler)(int)`.
+    pub fn declared_name(declarator: Node) -> Option<(Node, bool)> {
+        match declarator.kind() {
+            "identifier" | "type_identifier" | "field_identifier" => Some((declarator, false)),
+            "function_declarator" => {
+                let inner = declarator.child_by_field_name("declarator")?;
+                let (name, inner_is_function) = Self::declared_name(inner)?;
+                Some((
+                    name,
+                    inner_is_function || inner.kind() != "parenthesized_declarator",
+                ))
+            }
+            "parenthesized_declarator" => {
+                let mut cursor = declarator.walk();
+                let inner = declarator.named_children(&mut cursor).next()?;
+                Self::declared_name(inner)
+            }
+            _ => Self::declared_name(declarator.child_by_field_name("declarator")?),
+        }
+    }
+
+    /// Check if a declaration carries the given storage class (`static`, `extern`).
+    pub fn has_storage_class(declaration: Node, source: &str, storage_class: &str) -> bool {
+        declaration.children(&mut declaration.walk()).any(|child| {
+            child.kind() == "storage_class_specifier"
+                && Self::extract_text(child, source) == storage_class
+        })
+    }
+
+    /// Linkage recorded on functions and file-scope variables.
+    pub fn linkage(declaration: Node, source: &str) -> &'static str {
+        if Self::has_storage_class(declaration, source, "static") {
+            "internal"
+        } else {
+            "external"
+        }
+    }
+
+    /// Map a tagged type specifier to its symbol kind.
+    pub fn tag_kind(node_kind: &str) -> Option<&'static str> {
+        match node_kind {
+            "struct_specifier" => Some("struct"),
+            "union_specifier" => Some("union"),
+            "enum_specifier" => Some("enum"),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use super::ast_utils::CAstUtils;
+use crate::core::NTreeError;
+use crate::storage::{CallEdge, CallType, SymbolId};
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+/// C-specific call site extractor.
+pub struct CCallExtractor;
+
+impl CCallExtractor {
+    /// Extract call sites from C function body.
+    ///
+    /// Calls through function pointers cannot be resolved statically and are
+    /// marked `Dynamic`: calling a parameter or local variable, `(*fp)(x)`,
+    /// `s->handler(x)`, or `table[i](x)`.
+    pub fn extract_call_sites(
+        function_body: Node,
+        source: &str,
+        caller_sym: &SymbolId,
+    ) -> Result<Vec<CallEdge>, NTreeError> {
+        let mut variables = HashSet::new();
+        if let Some(function) = function_body.parent() {
+            Self::collect_parameters(function, source, &mut variables);
+        }
+        Self::collect_locals(function_body, source, &mut variables);
+
+        let mut call_edges = Vec::new();
+        Self::visit_node_for_calls(
+            function_body,
+            source,
+            caller_sym,
+            &variables,
+            &mut call_edges,
+        );
+        Ok(call_edges)
+    }
+
+    /// Recursively visit AST nodes looking for call expressions.
+    fn visit_node_for_calls(
+        node: Node,
+        source: &str,
+        caller_sym: &SymbolId,
+        variables: &HashSet<String>,
+        call_edges: &mut Vec<CallEdge>,
+    ) {
+        if node.kind() == "call_expression" {
+            if let Some(function) = node.child_by_field_name("function") {
+                let callee = CAstUtils::extract_text(function, source);
+                let call_type = match function.kind() {
+                    "identifier" if !variables.contains(&callee) => CallType::FreeFunction,
+                    _ => CallType::Dynamic,
+                };
+
+                let mut edge = CallEdge::new(
+                    caller_sym.clone(),
+                    CAstUtils::extract_span(node),
+                    CAstUtils::extract_text(node, source),
+                );
+                edge.call_type = call_type;
+                edge.module_hints = vec![callee];
+                call_edges.push(edge);
+            }
+        }
+
+        // Recursively visit children, including call arguments
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::visit_node_for_calls(child, source, caller_sym, variables, call_edges);
+        }
+    }
+
+    /// Collect the names of a function definition's parameters.
+    fn collect_parameters(function: Node, source: &str, variables: &mut HashSet<String>) {
+        let parameters = function
+            .child_by_field_name("declarator")
+            .and_then(Self::function_declarator)
+            .and_then(|declarator| declarator.child_by_field_name("parameters"));
+
+        if let Some(parameters) = parameters {
+            let mut cursor = parameters.walk();
+            for parameter in parameters.named_children(&mut cursor) {
+                if let Some(declarator) = parameter.child_by_field_name("declarator") {
+                    Self::add_variable(declarator, source, variables);
+                }
+            }
+        }
+    }
+
+    /// Collect variables declared anywhere in a function body.
+    fn collect_locals(node: Node, source: &str, variables: &mut HashSet<String>) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.kind() == "declaration" {
+                let mut declarators = child.walk();
+                for declarator in child.children_by_field_name("declarator", &mut declarators) {
+                    Self::add_variable(declarator, source, variables);
+                }
+            } else {
+                Self::collect_locals(child, source, variables);
+            }
+        }
+    }
+
+    /// Record the name a declarator introduces, unless it declares a function.
+    fn add_variable(declarator: Node, source: &str, variables: &mut HashSet<String>) {
+        if let Some((name, false)) = CAstUtils::declared_name(declarator) {
+            variables.insert(CAstUtils::extract_text(name, source));
+        }
+    }
+
+    /// Find the declarator holding a function definition's parameter list.
+    fn function_declarator(declarator: Node) -> Option<Node> {
+        match declarator.kind() {
+            "function_declarator" => Some(declarator),
+            _ => Self::function_declarator(declarator.child_by_field_name("declarator")?),
+        }
+    }
+}
@@ -1,6 +1,7 @@
+use super::ast_utils::CAstUtils;
 use crate::core::NTreeError;
-use crate::storage::{ExportEdge, ImportEdge};
-use std::path::PathBuf;
+use crate::storage::{ExportEdge, ImportEdge, ImportType};
+use std::path::{Path, PathBuf};
 use tree_sitter::Node;
 
 /// C-specific import/export extractor.
@@ -8,12 +9,61 @@ pub struct CImportExtractor;
 
 impl CImportExtractor {
     /// Extract C import/export relationships.
+    ///
+    /// Every `#include` becomes a module import whose target keeps its
+    /// delimiters, so `<stdio.h>` stays a system header and `"util.h"` a local
+    /// one. Includes inside conditional blocks are recorded as well. C has no
+    /// export syntax, so no exports are produced.
     pub fn extract_dependencies(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<(Vec<ImportEdge>, Vec<ExportEdge>), NTreeError> {
-        // Not implemented yet
-        Ok((Vec::new(), Vec::new()))
+        let mut imports = Vec::new();
+        Self::extract_includes(root, source, file_path, &mut imports);
+        Ok((imports, Vec::new()))
+    }
+
+    /// Collect `#include` directives from a translation unit or preprocessor block.
+    fn extract_includes(node: Node, source: &str, file_path: &Path, imports: &mut Vec<ImportEdge>) {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "preproc_include" => {
+                    if let Some(import) = Self::extract_include(child, source, file_path) {
+                        imports.push(import);
+                    }
+                }
+                "preproc_if"
+                | "preproc_ifdef"
+                | "preproc_else"
+                | "preproc_elif"
+                | "preproc_elifdef"
+                | "linkage_specification"
+                | "namespace_definition"
+                | "declaration_list" => Self::extract_includes(child, source, file_path, imports),
+                _ => {}
+            }
+        }
+    }
+
+    /// Extract a single `#include <...>` or `#include "..."` directive.
+    ///
+    /// Computed includes such as `#include HEADER` cannot be resolved without
+    /// running the preprocessor and are skipped.
+    fn extract_include(include_node: Node, source: &str, file_path: &Path) -> Option<ImportEdge> {
+        let path = include_node.child_by_field_name("path")?;
+        if !matches!(path.kind(), "system_lib_string" | "string_literal") {
+            return None;
+        }
+
+        Some(ImportEdge::new(
+            file_path.to_path_buf(),
+            CAstUtils::extract_text(path, source),
+            None,
+            ImportType::Module,
+            CAstUtils::extract_span(include_node),
+            CAstUtils::extract_text(include_node, source),
+        ))
     }
 }
@@ -1,6 +1,10 @@
-pub mod import_extractor;
 /// C-specific analyzers and extractors.
+pub mod ast_utils;
+pub mod call_extractor;
+pub mod import_extractor;
 pub mod symbol_extractor;
 
+pub use ast_utils::CAstUtils;
+pub use call_extractor::CCallExtractor;
 pub use import_extractor::CImportExtractor;
 pub use symbol_extractor::CSymbolExtractor;
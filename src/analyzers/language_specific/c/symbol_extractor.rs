@@ -1,19 +1,189 @@
+use super::ast_utils::CAstUtils;
 use crate::core::NTreeError;
-use crate::storage::TopLevelSymbol;
-use std::path::PathBuf;
+use crate::storage::{SymbolId, TopLevelSymbol};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use tree_sitter::Node;
 
-/// C-specific symbol extractor.
+/// Extracted symbol plus whether it is a definition or only a declaration.
+struct CSymbolEntry {
+    symbol: TopLevelSymbol,
+    is_definition: bool,
+}
+
+/// C-specific symbol extractor for functions, tagged types, typedefs, and globals.
 pub struct CSymbolExtractor;
 
 impl CSymbolExtractor {
-    /// Extract symbols from C AST.
+    /// Extract file-scope symbols from C AST.
+    ///
+    /// Prototypes and `extern` declarations are only reported when the file does
+    /// not also define the symbol, so headers list their full interface while
+    /// source files list each function once.
     pub fn extract_symbols(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<Vec<TopLevelSymbol>, NTreeError> {
-        // Not implemented yet
-        Ok(Vec::new())
+        let mut entries = Vec::new();
+        Self::extract_from_scope(root, source, file_path, &mut entries);
+
+        let defined: HashSet<SymbolId> = entries
+            .iter()
+            .filter(|entry| entry.is_definition)
+            .map(|entry| entry.symbol.id.clone())
+            .collect();
+        let mut seen = HashSet::new();
+
+        Ok(entries
+            .into_iter()
+            .filter(|entry| entry.is_definition || !defined.contains(&entry.symbol.id))
+            .filter(|entry| seen.insert(entry.symbol.id.clone()))
+            .map(|entry| entry.symbol)
+            .collect())
+    }
+
+    /// Extract symbols from the translation unit or a preprocessor conditional block.
+    fn extract_from_scope(
+        scope: Node,
+        source: &str,
+        file_path: &PathBuf,
+        entries: &mut Vec<CSymbolEntry>,
+    ) {
+        let mut cursor = scope.walk();
+
+        for child in scope.children(&mut cursor) {
+            match child.kind() {
+                "function_definition" => {
+                    if let Some(declarator) = child.child_by_field_name("declarator") {
+                        if let Some((name, _)) = CAstUtils::declared_name(declarator) {
+                            entries.push(CSymbolEntry {
+                                symbol: Self::create_symbol(
+                                    child,
+                                    &CAstUtils::extract_text(name, source),
+                                    "function",
+                                    file_path,
+                                )
+                                .with_linkage(CAstUtils::linkage(child, source)),
+                                is_definition: true,
+                            });
+                        }
+                    }
+                }
+                "declaration" => {
+                    Self::extract_declaration(child, source, file_path, entries);
+                }
+                "type_definition" => {
+                    Self::extract_type_definition(child, source, file_path, entries);
+                }
+                "struct_specifier" | "union_specifier" | "enum_specifier" => {
+                    Self::extract_tagged_type(child, source, file_path, entries);
+                }
+                // Header guards and platform conditionals wrap most declarations
+                "preproc_ifdef" | "preproc_if" | "preproc_else" | "preproc_elif"
+                | "preproc_elifdef" => {
+                    Self::extract_from_scope(child, source, file_path, entries);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Extract prototypes and file-scope variables from a declaration.
+    fn extract_declaration(
+        declaration: Node,
+        source: &str,
+        file_path: &PathBuf,
+        entries: &mut Vec<CSymbolEntry>,
+    ) {
+        if let Some(type_node) = declaration.child_by_field_name("type") {
+            Self::extract_tagged_type(type_node, source, file_path, entries);
+        }
+
+        let linkage = CAstUtils::linkage(declaration, source);
+        let is_extern = CAstUtils::has_storage_class(declaration, source, "extern");
+        let mut cursor = declaration.walk();
+
+        for declarator in declaration.children_by_field_name("declarator", &mut cursor) {
+            if let Some((name, is_function)) = CAstUtils::declared_name(declarator) {
+                let kind = if is_function { "prototype" } else { "variable" };
+                entries.push(CSymbolEntry {
+                    symbol: Self::create_symbol(
+                        declarator,
+                        &CAstUtils::extract_text(name, source),
+                        kind,
+                        file_path,
+                    )
+                    .with_linkage(linkage),
+                    is_definition: !is_function && !is_extern,
+                });
+            }
+        }
+    }
+
+    /// Extract typedef names and any tagged type defined inline.
+    fn extract_type_definition(
+        type_definition: Node,
+        source: &str,
+        file_path: &PathBuf,
+        entries: &mut Vec<CSymbolEntry>,
+    ) {
+        if let Some(type_node) = type_definition.child_by_field_name("type") {
+            Self::extract_tagged_type(type_node, source, file_path, entries);
+        }
+
+        let mut cursor = type_definition.walk();
+        for declarator in type_definition.children_by_field_name("declarator", &mut cursor) {
+            if let Some((name, _)) = CAstUtils::declared_name(declarator) {
+                entries.push(CSymbolEntry {
+                    symbol: Self::create_symbol(
+                        type_definition,
+                        &CAstUtils::extract_text(name, source),
+                        "typedef",
+                        file_path,
+                    ),
+                    is_definition: true,
+                });
+            }
+        }
+    }
+
+    /// Extract a named struct, union, or enum that has a body.
+    ///
+    /// Tags live in their own namespace in C, so the symbol ID is prefixed with
+    /// the tag keyword (`struct Node`) to coexist with `typedef ... Node`.
+    fn extract_tagged_type(
+        type_node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        entries: &mut Vec<CSymbolEntry>,
+    ) {
+        let kind = match CAstUtils::tag_kind(type_node.kind()) {
+            Some(kind) => kind,
+            None => return,
+        };
+        if type_node.child_by_field_name("body").is_none() {
+            return; // Forward declaration or use of an existing tag
+        }
+
+        if let Some(name_node) = type_node.child_by_field_name("name") {
+            let name = CAstUtils::extract_text(name_node, source);
+            entries.push(CSymbolEntry {
+                symbol: Self::create_symbol(type_node, &name, kind, file_path)
+                    .with_id(SymbolId::new(file_path, &format!("{} {}", kind, name))),
+                is_definition: true,
+            });
+        }
+    }
+
+    /// Create symbol for a file-scope C name.
+    fn create_symbol(node: Node, name: &str, kind: &str, file_path: &Path) -> TopLevelSymbol {
+        TopLevelSymbol::new(
+            file_path.to_path_buf(),
+            name.to_string(),
+            kind.to_string(),
+            name.to_string(),
+            CAstUtils::extract_span(node),
+        )
     }
 }
@@ -0,0 +1,208 @@
+use crate::core::format_node_span;
+use tree_sitter::Node;
+
+/// Utilities for working with C++ AST nodes.
+pub struct CppAstUtils;
+
+impl CppAstUtils {
+    /// Extract span information from a node.
+    pub fn extract_span(node: Node) -> String {
+        format_node_span(node)
+    }
+
+    /// Extract text content from node.
+    pub fn extract_text(node: Node, source: &str) -> String {
+        let start = node.start_byte();
+        let end = node.end_byte();
+        source.get(start..end).unwrap_or("").trim().to_string()
+    }
+
+    /// Find the (possibly qualified) name a declarator introduces.
+    ///
+    /// Returns the name split into `::` components, so `void geo::Shape::area()`
+    /// yields `["geo", "Shape", "area"]`. Template arguments are dropped from
+    /// scopes and names. The flag is `true` when the declarator declares a
+    /// function rather than a variable, field, or function pointer.
+    pub fn declared_name(declarator: Node, source: &str) -> Option<(Vec<String>, bool)> {
+        match declarator.kind() {
+            "identifier"
+            | "field_identifier"
+            | "type_identifier"
+            | "namespace_identifier"
+            | "destructor_name"
+            | "operator_name" => Some((vec![Self::normalize(declarator, source)], false)),
+            "qualified_identifier" => {
+                let mut path = match declarator.child_by_field_name("scope") {
+                    Some(scope) => Self::name_path(scope, source)?,
+                    None => Vec::new(), // `::name` refers to the global namespace
+                };
+                let (name, is_function) =
+                    Self::declared_name(declarator.child_by_field_name("name")?, source)?;
+                path.extend(name);
+                Some((path, is_function))
+            }
+            "template_function" | "template_type" => {
+                Self::declared_name(declarator.child_by_field_name("name")?, source)
+            }
+            "operator_cast" => Some((
+                vec![Self::normalize(declarator, source)
+                    .split('(')
+                    .next()?
+                    .to_string()],
+                true,
+            )),
+            "function_declarator" => {
+                let inner = declarator.child_by_field_name("declarator")?;
+                let (name, inner_is_function) = Self::declared_name(inner, source)?;
+                Some((
+                    name,
+                    inner_is_function || inner.kind() != "parenthesized_declarator",
+                ))
+            }
+            _ => Self::declared_name(Self::inner_declarator(declarator)?, source),
+        }
+    }
+
+    /// Split a scope or type name such as `geo::Shape<T>` into `::` components.
+    pub fn name_path(node: Node, source: &str) -> Option<Vec<String>> {
+        match node.kind() {
+            "nested_namespace_specifier" => {
+                let mut cursor = node.walk();
+                let path = node
+                    .named_children(&mut cursor)
+                    .map(|part| Self::extract_text(part, source))
+                    .collect();
+                Some(path)
+            }
+            _ => Self::declared_name(node, source).map(|(path, _)| path),
+        }
+    }
+
+    /// Find the function declarator (which holds the parameter list) inside a declarator.
+    pub fn function_declarator(declarator: Node) -> Option<Node> {
+        match declarator.kind() {
+            "function_declarator" | "abstract_function_declarator" => Some(declarator),
+            "operator_cast" => {
+                Self::function_declarator(declarator.child_by_field_name("declarator")?)
+            }
+            _ => Self::function_declarator(Self::inner_declarator(declarator)?),
+        }
+    }
+
+    /// Build a parameter type signature such as `(int,const Shape&)const`.
+    ///
+    /// Parameter names and default values are dropped so a declaration and its
+    /// out-of-line definition produce the same signature.
+    pub fn parameter_signature(function_declarator: Node, source: &str) -> String {
+        let mut types = Vec::new();
+
+        if let Some(parameters) = function_declarator.child_by_field_name("parameters") {
+            let mut cursor = parameters.walk();
+            for parameter in parameters.named_children(&mut cursor) {
+                let end = parameter
+                    .child_by_field_name("default_value")
+                    .and_then(|value| value.prev_sibling())
+                    .map(|equals| equals.start_byte())
+                    .unwrap_or_else(|| parameter.end_byte());
+                let mut text = source
+                    .get(parameter.start_byte()..end)
+                    .unwrap_or("")
+                    .to_string();
+
+                let name = parameter
+                    .child_by_field_name("declarator")
+                    .and_then(|declarator| Self::parameter_name(declarator));
+                if let Some(name) = name {
+                    let start = name.start_byte() - parameter.start_byte();
+                    let end = name.end_byte() - parameter.start_byte();
+                    if end <= text.len() {
+                        text.replace_range(start..end, "");
+                    }
+                }
+
+                types.push(Self::normalize_text(&text));
+            }
+        }
+
+        let mut cursor = function_declarator.walk();
+        let is_const = function_declarator.children(&mut cursor).any(|child| {
+            child.kind() == "type_qualifier" && Self::extract_text(child, source) == "const"
+        });
+
+        format!(
+            "({}){}",
+            types.join(","),
+            if is_const { "const" } else { "" }
+        )
+    }
+
+    /// Check if a declaration carries the given storage class (`static`, `extern`).
+    pub fn has_storage_class(declaration: Node, source: &str, storage_class: &str) -> bool {
+        declaration.children(&mut declaration.walk()).any(|child| {
+            child.kind() == "storage_class_specifier"
+                && Self::extract_text(child, source) == storage_class
+        })
+    }
+
+    /// Map a class, struct, union, or enum specifier to its symbol kind.
+    pub fn type_kind(node_kind: &str) -> Option<&'static str> {
+        match node_kind {
+            "class_specifier" => Some("class"),
+            "struct_specifier" => Some("struct"),
+            "union_specifier" => Some("union"),
+            "enum_specifier" => Some("enum"),
+            _ => None,
+        }
+    }
+
+    /// Identifier naming a parameter, if the parameter is named.
+    fn parameter_name(declarator: Node) -> Option<Node> {
+        match declarator.kind() {
+            "identifier" => Some(declarator),
+            // Function pointer parameters keep their parameter list in the type
+            "function_declarator" => {
+                Self::parameter_name(declarator.child_by_field_name("declarator")?)
+            }
+            _ => Self::parameter_name(Self::inner_declarator(declarator)?),
+        }
+    }
+
+    /// Unwrap one level of pointer, reference, array, init, or parenthesized declarator.
+    fn inner_declarator(declarator: Node) -> Option<Node> {
+        if let Some(inner) = declarator.child_by_field_name("declarator") {
+            return Some(inner);
+        }
+        // Reference and parenthesized declarators hold their inner declarator unnamed
+        let mut cursor = declarator.walk();
+        let inner = declarator
+            .named_children(&mut cursor)
+            .find(|child| !matches!(child.kind(), "type_qualifier" | "ms_pointer_modifier"));
+        inner
+    }
+
+    /// Node text with insignificant whitespace removed (`operator ==` becomes `operator==`).
+    fn normalize(node: Node, source: &str) -> String {
+        Self::normalize_text(&Self::extract_text(node, source))
+    }
+
+    /// Collapse whitespace, keeping a single space only between two word characters.
+    fn normalize_text(text: &str) -> String {
+        let mut normalized = String::new();
+        let mut pending_space = false;
+
+        for c in text.trim().chars() {
+            if c.is_whitespace() {
+                pending_space = true;
+                continue;
+            }
+            let is_word = |c: char| c.is_alphanumeric() || c == '_';
+            if pending_space && normalized.chars().last().is_some_and(is_word) && is_word(c) {
+                normalized.push(' ');
+            }
+            pending_space = false;
+            normalized.push(c);
+        }
+
+        normalized
+    }
+}
@@ -0,0 +1,167 @@
+use super::ast_utils::CppAstUtils;
+use crate::core::NTreeError;
+use crate::storage::{CallEdge, CallType, SymbolId};
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+/// C++-specific call site extractor.
+pub struct CppCallExtractor;
+
+impl CppCallExtractor {
+    /// Extract call sites from C++ function body.
+    ///
+    /// Member calls (`obj.m()`, `ptr->m()`, `this->m()`), qualified calls
+    /// (`geo::area()`, `Shape::make()`), and `new` expressions are classified
+    /// by syntax. Calling a parameter or local variable (a function pointer,
+    /// functor, or lambda) is `Dynamic`.
+    pub fn extract_call_sites(
+        function_body: Node,
+        source: &str,
+        caller_sym: &SymbolId,
+    ) -> Result<Vec<CallEdge>, NTreeError> {
+        let mut variables = HashSet::new();
+        if let Some(function) = function_body.parent() {
+            Self::collect_parameters(function, source, &mut variables);
+        }
+        Self::collect_locals(function_body, source, &mut variables);
+
+        let mut call_edges = Vec::new();
+        Self::visit_node_for_calls(
+            function_body,
+            source,
+            caller_sym,
+            &variables,
+            &mut call_edges,
+        );
+        Ok(call_edges)
+    }
+
+    /// Recursively visit AST nodes looking for call and `new` expressions.
+    fn visit_node_for_calls(
+        node: Node,
+        source: &str,
+        caller_sym: &SymbolId,
+        variables: &HashSet<String>,
+        call_edges: &mut Vec<CallEdge>,
+    ) {
+        let call = match node.kind() {
+            "call_expression" => node
+                .child_by_field_name("function")
+                .map(|function| Self::classify_callee(function, source, variables)),
+            "new_expression" => node.child_by_field_name("type").map(|type_node| {
+                (
+                    Self::qualified_name(type_node, source),
+                    CallType::Constructor,
+                )
+            }),
+            _ => None,
+        };
+        if let Some((callee, call_type)) = call {
+            let mut edge = CallEdge::new(
+                caller_sym.clone(),
+                CppAstUtils::extract_span(node),
+                CppAstUtils::extract_text(node, source),
+            );
+            edge.call_type = call_type;
+            edge.module_hints = vec![callee];
+            call_edges.push(edge);
+        }
+
+        // Recursively visit children, including call arguments and receivers
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::visit_node_for_calls(child, source, caller_sym, variables, call_edges);
+        }
+    }
+
+    /// Classify a call by its callee expression and build the callee name.
+    fn classify_callee(
+        function: Node,
+        source: &str,
+        variables: &HashSet<String>,
+    ) -> (String, CallType) {
+        match function.kind() {
+            "identifier" | "template_function" => {
+                let name = Self::qualified_name(function, source);
+                if variables.contains(&name) {
+                    (name, CallType::Dynamic)
+                } else {
+                    (name, CallType::FreeFunction)
+                }
+            }
+            "qualified_identifier" => (
+                Self::qualified_name(function, source),
+                CallType::StaticMethod,
+            ),
+            "field_expression" => {
+                let receiver = function
+                    .child_by_field_name("argument")
+                    .map(|argument| CppAstUtils::extract_text(argument, source).replace("->", "."))
+                    .unwrap_or_default();
+                let member = function
+                    .child_by_field_name("field")
+                    .map(|field| Self::qualified_name(field, source))
+                    .unwrap_or_default();
+                (format!("{}.{}", receiver, member), CallType::InstanceMethod)
+            }
+            // Dereferenced pointers, subscripts, call results, and lambdas
+            _ => (
+                CppAstUtils::extract_text(function, source),
+                CallType::Dynamic,
+            ),
+        }
+    }
+
+    /// Name with template arguments dropped (`geo::Shape<int>` is `geo::Shape`).
+    fn qualified_name(node: Node, source: &str) -> String {
+        let path = match node.kind() {
+            "template_method" => node
+                .child_by_field_name("name")
+                .and_then(|name| CppAstUtils::name_path(name, source)),
+            _ => CppAstUtils::name_path(node, source),
+        };
+        match path {
+            Some(path) => path.join("::"),
+            None => CppAstUtils::extract_text(node, source),
+        }
+    }
+
+    /// Collect the names of a function definition's parameters.
+    fn collect_parameters(function: Node, source: &str, variables: &mut HashSet<String>) {
+        let parameters = function
+            .child_by_field_name("declarator")
+            .and_then(CppAstUtils::function_declarator)
+            .and_then(|declarator| declarator.child_by_field_name("parameters"));
+
+        if let Some(parameters) = parameters {
+            let mut cursor = parameters.walk();
+            for parameter in parameters.named_children(&mut cursor) {
+                if let Some(declarator) = parameter.child_by_field_name("declarator") {
+                    Self::add_variable(declarator, source, variables);
+                }
+            }
+        }
+    }
+
+    /// Collect variables declared anywhere in a function body.
+    fn collect_locals(node: Node, source: &str, variables: &mut HashSet<String>) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.kind() == "declaration" {
+                let mut declarators = child.walk();
+                for declarator in child.children_by_field_name("declarator", &mut declarators) {
+                    Self::add_variable(declarator, source, variables);
+                }
+            } else {
+                Self::collect_locals(child, source, variables);
+            }
+        }
+    }
+
+    /// Record the name a declarator introduces, unless it declares a function.
+    fn add_variable(declarator: Node, source: &str, variables: &mut HashSet<String>) {
+        if let Some((path, false)) = CppAstUtils::declared_name(declarator, source) {
+            variables.extend(path.last().cloned());
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::analyzers::language_specific::c::CImportExtractor;
 use crate::core::NTreeError;
 use crate::storage::{ExportEdge, ImportEdge};
 use std::path::PathBuf;
@@ -8,12 +9,14 @@ pub struct CppImportExtractor;
 
 impl CppImportExtractor {
     /// Extract C++ import/export relationships.
+    ///
+    /// C++ shares the C preprocessor, so includes are extracted the same way;
+    /// includes nested in `extern "C"` blocks and namespaces are recorded too.
     pub fn extract_dependencies(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<(Vec<ImportEdge>, Vec<ExportEdge>), NTreeError> {
-        // Not implemented yet
-        Ok((Vec::new(), Vec::new()))
+        CImportExtractor::extract_dependencies(root, source, file_path)
     }
 }
@@ -1,6 +1,10 @@
-pub mod import_extractor;
 /// C++-specific analyzers and extractors.
+pub mod ast_utils;
+pub mod call_extractor;
+pub mod import_extractor;
 pub mod symbol_extractor;
 
+pub use ast_utils::CppAstUtils;
+pub use call_extractor::CppCallExtractor;
 pub use import_extractor::CppImportExtractor;
 pub use symbol_extractor::CppSymbolExtractor;
@@ -1,19 +1,464 @@
+use super::ast_utils::CppAstUtils;
 use crate::core::NTreeError;
-use crate::storage::TopLevelSymbol;
+use crate::storage::{SymbolId, TopLevelSymbol};
+use std::collections::HashSet;
 use std::path::PathBuf;
 use tree_sitter::Node;
 
-/// C++-specific symbol extractor.
+/// The file being extracted and the namespaces it opens, which tell a
+/// namespace-qualified definition (`geo::area`) from an out-of-class method
+/// definition (`Shape::area`).
+struct TranslationUnit<'a> {
+    source: &'a str,
+    file_path: &'a PathBuf,
+    /// Qualified names of every namespace opened in the file
+    namespaces: HashSet<String>,
+}
+
+/// Namespace or class enclosing the declarations being extracted.
+#[derive(Clone, Copy)]
+struct Scope<'s> {
+    path: &'s [String],
+    in_class: bool,
+    /// Inside an anonymous namespace, where everything has internal linkage
+    internal: bool,
+}
+
+/// Extracted symbol plus whether it is a definition or only a declaration.
+struct CppSymbolEntry {
+    symbol: TopLevelSymbol,
+    is_definition: bool,
+}
+
+impl CppSymbolEntry {
+    fn definition(symbol: TopLevelSymbol) -> Self {
+        Self {
+            symbol,
+            is_definition: true,
+        }
+    }
+}
+
+/// C++-specific symbol extractor for namespaces, classes, functions, and templates.
+///
+/// Names are qualified with `::` (`geo::Shape::area`). Out-of-line member
+/// definitions such as `double Shape::area() {}` are attributed to their class,
+/// and declarations directly under `template <...>` get a `template_` kind prefix.
 pub struct CppSymbolExtractor;
 
 impl CppSymbolExtractor {
     /// Extract symbols from C++ AST.
+    ///
+    /// As in C, a declaration is only reported when the file does not also
+    /// define the same symbol, so a member declared in its class and defined
+    /// out-of-line appears once, at the definition.
     pub fn extract_symbols(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<Vec<TopLevelSymbol>, NTreeError> {
-        // Not implemented yet
-        Ok(Vec::new())
+        let mut namespaces = HashSet::new();
+        Self::collect_namespaces(root, source, &[], &mut namespaces);
+
+        let unit = TranslationUnit {
+            source,
+            file_path,
+            namespaces,
+        };
+        let scope = Scope {
+            path: &[],
+            in_class: false,
+            internal: false,
+        };
+        let mut entries = Vec::new();
+        Self::extract_from_scope(root, &unit, scope, &mut entries);
+
+        let defined: HashSet<SymbolId> = entries
+            .iter()
+            .filter(|entry| entry.is_definition)
+            .map(|entry| entry.symbol.id.clone())
+            .collect();
+        let mut seen = HashSet::new();
+
+        Ok(entries
+            .into_iter()
+            .filter(|entry| entry.is_definition || !defined.contains(&entry.symbol.id))
+            .filter(|entry| seen.insert(entry.symbol.id.clone()))
+            .map(|entry| entry.symbol)
+            .collect())
+    }
+
+    /// Record namespace names up front so qualified definitions can tell
+    /// `ns::free_function` apart from `Class::method`.
+    fn collect_namespaces(
+        node: Node,
+        source: &str,
+        prefix: &[String],
+        namespaces: &mut HashSet<String>,
+    ) {
+        let mut cursor = node.walk();
+
+        for child in node.named_children(&mut cursor) {
+            match child.kind() {
+                "namespace_definition" => {
+                    let mut path = prefix.to_vec();
+                    if let Some(name) = child.child_by_field_name("name") {
+                        for part in CppAstUtils::name_path(name, source).unwrap_or_default() {
+                            path.push(part);
+                            namespaces.insert(path.join("::"));
+                        }
+                    }
+                    if let Some(body) = child.child_by_field_name("body") {
+                        Self::collect_namespaces(body, source, &path, namespaces);
+                    }
+                }
+                "linkage_specification"
+                | "preproc_ifdef"
+                | "preproc_if"
+                | "preproc_else"
+                | "preproc_elif"
+                | "preproc_elifdef"
+                | "declaration_list" => {
+                    Self::collect_namespaces(child, source, prefix, namespaces);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Extract symbols from a translation unit, namespace body, or class body.
+    fn extract_from_scope(
+        container: Node,
+        unit: &TranslationUnit,
+        scope: Scope,
+        entries: &mut Vec<CppSymbolEntry>,
+    ) {
+        let mut cursor = container.walk();
+
+        for child in container.named_children(&mut cursor) {
+            Self::extract_item(child, unit, scope, false, entries);
+        }
+    }
+
+    /// Extract symbols from a single declaration.
+    fn extract_item(
+        node: Node,
+        unit: &TranslationUnit,
+        scope: Scope,
+        template: bool,
+        entries: &mut Vec<CppSymbolEntry>,
+    ) {
+        match node.kind() {
+            "namespace_definition" => {
+                Self::extract_namespace(node, unit, scope, entries);
+            }
+            "function_definition" => {
+                if let Some(declarator) = node.child_by_field_name("declarator") {
+                    Self::extract_function(node, declarator, true, unit, scope, template, entries);
+                }
+            }
+            "declaration" | "field_declaration" => {
+                Self::extract_declaration(node, unit, scope, template, entries);
+            }
+            "class_specifier" | "struct_specifier" | "union_specifier" | "enum_specifier" => {
+                Self::extract_type(node, unit, scope, template, entries);
+            }
+            "type_definition" => {
+                if let Some(type_node) = node.child_by_field_name("type") {
+                    Self::extract_type(type_node, unit, scope, false, entries);
+                }
+                let mut cursor = node.walk();
+                for declarator in node.children_by_field_name("declarator", &mut cursor) {
+                    if let Some((name, _)) = CppAstUtils::declared_name(declarator, unit.source) {
+                        entries.extend(
+                            Self::create_symbol(node, &name, "typedef", "", unit, scope)
+                                .map(CppSymbolEntry::definition),
+                        );
+                    }
+                }
+            }
+            "alias_declaration" => {
+                if let Some(name) = node.child_by_field_name("name") {
+                    let name = vec![CppAstUtils::extract_text(name, unit.source)];
+                    entries.extend(
+                        Self::create_symbol(node, &name, "type_alias", "", unit, scope)
+                            .map(CppSymbolEntry::definition),
+                    );
+                }
+            }
+            "template_declaration" => {
+                let mut cursor = node.walk();
+                for child in node.named_children(&mut cursor) {
+                    Self::extract_item(child, unit, scope, true, entries);
+                }
+            }
+            "linkage_specification" => {
+                // `extern "C" { ... }` or `extern "C" void f();`
+                if let Some(body) = node.child_by_field_name("body") {
+                    if body.kind() == "declaration_list" {
+                        Self::extract_from_scope(body, unit, scope, entries);
+                    } else {
+                        Self::extract_item(body, unit, scope, template, entries);
+                    }
+                }
+            }
+            // Declarations under `#ifdef`/`#if` stay in the enclosing namespace or class
+            "preproc_ifdef" | "preproc_if" | "preproc_else" | "preproc_elif"
+            | "preproc_elifdef" => {
+                Self::extract_from_scope(node, unit, scope, entries);
+            }
+            _ => {}
+        }
+    }
+
+    /// Extract a namespace, then the declarations in its body.
+    ///
+    /// `namespace a::b {}` produces a symbol for each component; anonymous
+    /// namespaces produce none but give their contents internal linkage.
+    fn extract_namespace(
+        namespace_node: Node,
+        unit: &TranslationUnit,
+        scope: Scope,
+        entries: &mut Vec<CppSymbolEntry>,
+    ) {
+        let mut path = scope.path.to_vec();
+        let mut internal = scope.internal;
+
+        match namespace_node.child_by_field_name("name") {
+            Some(name) => {
+                for part in CppAstUtils::name_path(name, unit.source).unwrap_or_default() {
+                    let namespace_scope = Scope {
+                        path: &path,
+                        in_class: false,
+                        internal,
+                    };
+                    entries.extend(
+                        Self::create_symbol(
+                            namespace_node,
+                            std::slice::from_ref(&part),
+                            "namespace",
+                            "",
+                            unit,
+                            namespace_scope,
+                        )
+                        .map(CppSymbolEntry::definition),
+                    );
+                    path.push(part);
+                }
+            }
+            None => internal = true,
+        }
+
+        if let Some(body) = namespace_node.child_by_field_name("body") {
+            let body_scope = Scope {
+                path: &path,
+                in_class: false,
+                internal,
+            };
+            Self::extract_from_scope(body, unit, body_scope, entries);
+        }
+    }
+
+    /// Extract a class, struct, union, or enum with a body, then its members.
+    fn extract_type(
+        type_node: Node,
+        unit: &TranslationUnit,
+        scope: Scope,
+        template: bool,
+        entries: &mut Vec<CppSymbolEntry>,
+    ) {
+        let kind = match CppAstUtils::type_kind(type_node.kind()) {
+            Some(kind) => kind,
+            None => return,
+        };
+        let body = match type_node.child_by_field_name("body") {
+            Some(body) => body,
+            None => return, // Forward declaration or elaborated type use
+        };
+        let name = match type_node
+            .child_by_field_name("name")
+            .and_then(|name| CppAstUtils::name_path(name, unit.source))
+        {
+            Some(name) => name,
+            None => return,
+        };
+
+        let kind = if template && kind != "enum" {
+            format!("template_{}", kind)
+        } else {
+            kind.to_string()
+        };
+        entries.extend(
+            Self::create_symbol(type_node, &name, &kind, "", unit, scope)
+                .map(CppSymbolEntry::definition),
+        );
+
+        if kind.ends_with("enum") {
+            return;
+        }
+        let mut path = scope.path.to_vec();
+        path.extend(name);
+        let class_scope = Scope {
+            path: &path,
+            in_class: true,
+            internal: scope.internal,
+        };
+        Self::extract_from_scope(body, unit, class_scope, entries);
+    }
+
+    /// Extract functions, variables, and fields from a declaration.
+    fn extract_declaration(
+        declaration: Node,
+        unit: &TranslationUnit,
+        scope: Scope,
+        template: bool,
+        entries: &mut Vec<CppSymbolEntry>,
+    ) {
+        if let Some(type_node) = declaration.child_by_field_name("type") {
+            Self::extract_type(type_node, unit, scope, template, entries);
+        }
+
+        let is_extern = CppAstUtils::has_storage_class(declaration, unit.source, "extern");
+        let mut cursor = declaration.walk();
+
+        for declarator in declaration.children_by_field_name("declarator", &mut cursor) {
+            match CppAstUtils::declared_name(declarator, unit.source) {
+                Some((_, true)) => {
+                    Self::extract_function(
+                        declaration,
+                        declarator,
+                        false,
+                        unit,
+                        scope,
+                        template,
+                        entries,
+                    );
+                }
+                Some((name, false)) if scope.in_class => {
+                    entries.extend(
+                        Self::create_symbol(declarator, &name, "field", "", unit, scope)
+                            .map(CppSymbolEntry::definition),
+                    );
+                }
+                Some((name, false)) => {
+                    let linkage = Self::linkage(declaration, unit, scope);
+                    if let Some(symbol) =
+                        Self::create_symbol(declarator, &name, "variable", "", unit, scope)
+                    {
+                        entries.push(CppSymbolEntry {
+                            symbol: symbol.with_linkage(linkage),
+                            is_definition: !is_extern,
+                        });
+                    }
+                }
+                None => {}
+            }
+        }
+    }
+
+    /// Extract a function, method, constructor, destructor, or operator.
+    fn extract_function(
+        node: Node,
+        declarator: Node,
+        is_definition: bool,
+        unit: &TranslationUnit,
+        scope: Scope,
+        template: bool,
+        entries: &mut Vec<CppSymbolEntry>,
+    ) {
+        let name = match CppAstUtils::declared_name(declarator, unit.source) {
+            Some((name, _)) if !name.is_empty() => name,
+            _ => return,
+        };
+        let signature = CppAstUtils::function_declarator(declarator)
+            .map(|function| CppAstUtils::parameter_signature(function, unit.source))
+            .unwrap_or_default();
+
+        // `Shape::area` names a member unless `Shape` is a namespace of this file
+        let mut owner = scope.path.to_vec();
+        owner.extend(name[..name.len() - 1].iter().cloned());
+        let is_member = if name.len() == 1 {
+            scope.in_class
+        } else {
+            !unit.namespaces.contains(&owner.join("::"))
+        };
+
+        let simple_name = &name[name.len() - 1];
+        let kind = if simple_name.starts_with('~') {
+            "destructor"
+        } else if is_member && owner.last() == Some(simple_name) {
+            "constructor"
+        } else if simple_name.starts_with("operator") {
+            "operator"
+        } else if is_member {
+            "method"
+        } else if is_definition {
+            "function"
+        } else {
+            "prototype"
+        };
+        // Qualified names under `template <...>` belong to an existing template
+        let kind = match kind {
+            "method" if template && name.len() == 1 => "template_method".to_string(),
+            "function" | "prototype" if template && name.len() == 1 => {
+                "template_function".to_string()
+            }
+            kind => kind.to_string(),
+        };
+
+        let symbol = match Self::create_symbol(node, &name, &kind, &signature, unit, scope) {
+            Some(symbol) if is_member => symbol,
+            Some(symbol) => symbol.with_linkage(Self::linkage(node, unit, scope)),
+            None => return,
+        };
+        entries.push(CppSymbolEntry {
+            symbol,
+            is_definition,
+        });
+    }
+
+    /// Linkage of a namespace-scope function or variable.
+    fn linkage(declaration: Node, unit: &TranslationUnit, scope: Scope) -> &'static str {
+        if scope.internal || CppAstUtils::has_storage_class(declaration, unit.source, "static") {
+            "internal"
+        } else {
+            "external"
+        }
+    }
+
+    /// Create symbol for `name` (relative to `scope`) with a `::`-qualified name.
+    ///
+    /// The symbol ID is the qualified name plus the parameter signature, e.g.
+    /// `geo::Shape::Shape(int)`, so overloads remain distinct.
+    fn create_symbol(
+        node: Node,
+        name: &[String],
+        kind: &str,
+        signature: &str,
+        unit: &TranslationUnit,
+        scope: Scope,
+    ) -> Option<TopLevelSymbol> {
+        let simple_name = name.last()?.clone();
+        let qualname = scope
+            .path
+            .iter()
+            .chain(name)
+            .cloned()
+            .collect::<Vec<_>>()
+            .join("::");
+
+        Some(
+            TopLevelSymbol::new(
+                unit.file_path.clone(),
+                simple_name,
+                kind.to_string(),
+                qualname.clone(),
+                CppAstUtils::extract_span(node),
+            )
+            .with_id(SymbolId::new(
+                unit.file_path,
+                &format!("{}{}", qualname, signature),
+            )),
+        )
     }
 }
@@ -0,0 +1,78 @@
+use crate::core::format_node_span;
+use tree_sitter::Node;
+
+/// Utilities for working with Java AST nodes.
+pub struct JavaAstUtils;
+
+impl JavaAstUtils {
+    /// Extract the `name` field of a declaration node.
+    pub fn extract_name(node: Node, source: &str) -> Option<String> {
+        node.child_by_field_name("name")
+            .map(|name| Self::extract_text(name, source))
+    }
+
+    /// Extract span information from a node.
+    pub fn extract_span(node: Node) -> String {
+        format_node_span(node)
+    }
+
+    /// Extract text content from node.
+    pub fn extract_text(node: Node, source: &str) -> String {
+        let start = node.start_byte();
+        let end = node.end_byte();
+        source.get(start..end).unwrap_or("").trim().to_string()
+    }
+
+    /// Extract the package name from the compilation unit, if declared.
+    pub fn extract_package(root: Node, source: &str) -> Option<String> {
+        let mut cursor = root.walk();
+        let package = root
+            .children(&mut cursor)
+            .find(|child| child.kind() == "package_declaration")?;
+
+        let mut package_cursor = package.walk();
+        let name = package
+            .named_children(&mut package_cursor)
+            .find(|child| matches!(child.kind(), "scoped_identifier" | "identifier"))?;
+        Some(Self::extract_text(name, source))
+    }
+
+    /// Build a parameter type signature such as `(int,String...)` for overload identity.
+    pub fn parameter_signature(callable_node: Node, source: &str) -> String {
+        let mut types = Vec::new();
+
+        if let Some(parameters) = callable_node.child_by_field_name("parameters") {
+            let mut cursor = parameters.walk();
+            for parameter in parameters.named_children(&mut cursor) {
+                match parameter.kind() {
+                    "formal_parameter" => {
+                        if let Some(param_type) = parameter.child_by_field_name("type") {
+                            types.push(Self::normalize_type(param_type, source));
+                        }
+                    }
+                    "spread_parameter" => {
+                        let mut param_cursor = parameter.walk();
+                        let param_type =
+                            parameter.named_children(&mut param_cursor).find(|child| {
+                                !matches!(child.kind(), "modifiers" | "variable_declarator")
+                            });
+                        if let Some(param_type) = param_type {
+                            types.push(format!("{}...", Self::normalize_type(param_type, source)));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        format!("({})", types.join(","))
+    }
+
+    /// Type text with whitespace removed (`Map<K, V>` becomes `Map<K,V>`).
+    fn normalize_type(type_node: Node, source: &str) -> String {
+        Self::extract_text(type_node, source)
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect()
+    }
+}
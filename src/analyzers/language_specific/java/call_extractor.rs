@@ -0,0 +1,92 @@
+use super::ast_utils::JavaAstUtils;
+use crate::core::NTreeError;
+use crate::storage::{CallEdge, CallType, SymbolId};
+use tree_sitter::Node;
+
+/// Java-specific call site extractor.
+pub struct JavaCallExtractor;
+
+impl JavaCallExtractor {
+    /// Extract call sites from Java method or constructor body.
+    pub fn extract_call_sites(
+        function_body: Node,
+        source: &str,
+        caller_sym: &SymbolId,
+    ) -> Result<Vec<CallEdge>, NTreeError> {
+        let mut call_edges = Vec::new();
+        Self::visit_node_for_calls(function_body, source, caller_sym, &mut call_edges);
+        Ok(call_edges)
+    }
+
+    /// Recursively visit AST nodes looking for method invocations and `new` expressions.
+    fn visit_node_for_calls(
+        node: Node,
+        source: &str,
+        caller_sym: &SymbolId,
+        call_edges: &mut Vec<CallEdge>,
+    ) {
+        let call = match node.kind() {
+            "method_invocation" => Self::extract_method_invocation(node, source),
+            "object_creation_expression" => node
+                .child_by_field_name("type")
+                .map(|type_node| (Self::type_name(type_node, source), CallType::Constructor)),
+            _ => None,
+        };
+        if let Some((callee, call_type)) = call {
+            let mut edge = CallEdge::new(
+                caller_sym.clone(),
+                JavaAstUtils::extract_span(node),
+                JavaAstUtils::extract_text(node, source),
+            );
+            edge.call_type = call_type;
+            edge.module_hints = vec![callee];
+            call_edges.push(edge);
+        }
+
+        // Recursively visit children, including arguments, receivers, and anonymous classes
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::visit_node_for_calls(child, source, caller_sym, call_edges);
+        }
+    }
+
+    /// Classify a method invocation and build its callee expression.
+    ///
+    /// A receiver naming a type (`Math.max`) is a static call; an unqualified
+    /// call targets the enclosing class.
+    fn extract_method_invocation(invocation: Node, source: &str) -> Option<(String, CallType)> {
+        let name = JavaAstUtils::extract_text(invocation.child_by_field_name("name")?, source);
+
+        match invocation.child_by_field_name("object") {
+            Some(object) => {
+                let receiver = JavaAstUtils::extract_text(object, source);
+                let is_type = object.kind() == "identifier"
+                    && receiver.starts_with(|c: char| c.is_uppercase());
+                let call_type = if is_type {
+                    CallType::StaticMethod
+                } else {
+                    CallType::InstanceMethod
+                };
+                Some((format!("{}.{}", receiver, name), call_type))
+            }
+            None => Some((name, CallType::InstanceMethod)),
+        }
+    }
+
+    /// Instantiated type without generic arguments (`ArrayList<String>` is `ArrayList`).
+    fn type_name(type_node: Node, source: &str) -> String {
+        match type_node.kind() {
+            "generic_type" => {
+                let mut cursor = type_node.walk();
+                let base = type_node
+                    .named_children(&mut cursor)
+                    .find(|child| child.kind() != "type_arguments");
+                match base {
+                    Some(base) => Self::type_name(base, source),
+                    None => JavaAstUtils::extract_text(type_node, source),
+                }
+            }
+            _ => JavaAstUtils::extract_text(type_node, source),
+        }
+    }
+}
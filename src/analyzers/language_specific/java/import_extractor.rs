@@ -1,6 +1,7 @@
+use super::ast_utils::JavaAstUtils;
 use crate::core::NTreeError;
-use crate::storage::{ExportEdge, ImportEdge};
-use std::path::PathBuf;
+use crate::storage::{ExportEdge, ExportType, ImportEdge, ImportType, SymbolId};
+use std::path::{Path, PathBuf};
 use tree_sitter::Node;
 
 /// Java-specific import/export extractor.
@@ -8,12 +9,120 @@ pub struct JavaImportExtractor;
 
 impl JavaImportExtractor {
     /// Extract Java import/export relationships.
+    ///
+    /// Imports target the imported package, so the dependency graph is built
+    /// between packages. Public top-level types are exported.
     pub fn extract_dependencies(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<(Vec<ImportEdge>, Vec<ExportEdge>), NTreeError> {
-        // Not implemented yet
-        Ok((Vec::new(), Vec::new()))
+        let mut imports = Vec::new();
+        let mut exports = Vec::new();
+        let package = JavaAstUtils::extract_package(root, source);
+        let mut cursor = root.walk();
+
+        for child in root.children(&mut cursor) {
+            match child.kind() {
+                "import_declaration" => {
+                    if let Some(import) = Self::extract_import(child, source, file_path) {
+                        imports.push(match &package {
+                            Some(package) => import.with_source_module(package),
+                            None => import,
+                        });
+                    }
+                }
+                "class_declaration"
+                | "interface_declaration"
+                | "enum_declaration"
+                | "record_declaration"
+                | "annotation_type_declaration" => {
+                    if let Some(export) = Self::extract_public_type(child, source, file_path) {
+                        exports.push(export);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok((imports, exports))
+    }
+
+    /// Extract a single-type, on-demand, or static import.
+    ///
+    /// Java syntax does not separate the package from the type, so the package
+    /// is taken to end before the first capitalized segment (`java.util.Map.Entry`
+    /// imports `Map.Entry` from `java.util`). Static imports are split the same
+    /// way, so `import static java.lang.Math.max` imports `Math.max` from `java.lang`.
+    fn extract_import(import_node: Node, source: &str, file_path: &Path) -> Option<ImportEdge> {
+        let mut cursor = import_node.walk();
+        let children: Vec<Node> = import_node.children(&mut cursor).collect();
+
+        let name = children
+            .iter()
+            .find(|child| matches!(child.kind(), "scoped_identifier" | "identifier"))?;
+        let is_wildcard = children.iter().any(|child| child.kind() == "asterisk");
+
+        let segments: Vec<String> = JavaAstUtils::extract_text(*name, source)
+            .split('.')
+            .map(|segment| segment.trim().to_string())
+            .collect();
+        let type_start = segments
+            .iter()
+            .position(|segment| segment.starts_with(|c: char| c.is_uppercase()))
+            .unwrap_or(if is_wildcard {
+                segments.len()
+            } else {
+                segments.len() - 1
+            });
+
+        let package = segments[..type_start].join(".");
+        let imported = segments[type_start..].join(".");
+        let (imported_symbol, import_type) = match (is_wildcard, imported.is_empty()) {
+            // `import java.util.*;`
+            (true, true) => (None, ImportType::Wildcard),
+            // `import static org.junit.Assert.*;` or `import java.util.Map.*;`
+            (true, false) => (Some(imported), ImportType::Wildcard),
+            (false, _) => (Some(imported), ImportType::Symbol),
+        };
+
+        Some(ImportEdge::new(
+            file_path.to_path_buf(),
+            package,
+            imported_symbol,
+            import_type,
+            JavaAstUtils::extract_span(import_node),
+            JavaAstUtils::extract_text(import_node, source),
+        ))
+    }
+
+    /// Export a top-level type declared `public`.
+    fn extract_public_type(
+        type_node: Node,
+        source: &str,
+        file_path: &PathBuf,
+    ) -> Option<ExportEdge> {
+        let mut cursor = type_node.walk();
+        let is_public = type_node
+            .children(&mut cursor)
+            .filter(|child| child.kind() == "modifiers")
+            .any(|modifiers| {
+                JavaAstUtils::extract_text(modifiers, source)
+                    .split_whitespace()
+                    .any(|modifier| modifier == "public")
+            });
+        if !is_public {
+            return None;
+        }
+
+        let name = JavaAstUtils::extract_name(type_node, source)?;
+        Some(ExportEdge::new(
+            file_path.clone(),
+            SymbolId::new(file_path, &name),
+            ExportType::Public,
+            None,
+            JavaAstUtils::extract_span(type_node),
+            "public".to_string(),
+        ))
     }
 }
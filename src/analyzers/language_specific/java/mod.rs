@@ -1,6 +1,10 @@
-pub mod import_extractor;
 /// Java-specific analyzers and extractors.
+pub mod ast_utils;
+pub mod call_extractor;
+pub mod import_extractor;
 pub mod symbol_extractor;
 
+pub use ast_utils::JavaAstUtils;
+pub use call_extractor::JavaCallExtractor;
 pub use import_extractor::JavaImportExtractor;
 pub use symbol_extractor::JavaSymbolExtractor;
@@ -1,19 +1,196 @@
+use super::ast_utils::JavaAstUtils;
 use crate::core::NTreeError;
-use crate::storage::TopLevelSymbol;
+use crate::storage::{SymbolId, TopLevelSymbol};
 use std::path::PathBuf;
 use tree_sitter::Node;
 
-/// Java-specific symbol extractor.
+/// Java-specific symbol extractor for types, methods, constructors, and fields.
 pub struct JavaSymbolExtractor;
 
 impl JavaSymbolExtractor {
-    /// Extract symbols from Java AST.
+    /// Extract symbols from Java AST with package-qualified names.
     pub fn extract_symbols(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<Vec<TopLevelSymbol>, NTreeError> {
-        // Not implemented yet
-        Ok(Vec::new())
+        let mut symbols = Vec::new();
+        let package = JavaAstUtils::extract_package(root, source);
+        let mut cursor = root.walk();
+
+        for child in root.children(&mut cursor) {
+            if Self::type_kind(child.kind()).is_some() {
+                Self::extract_type(
+                    child,
+                    source,
+                    file_path,
+                    package.as_deref(),
+                    None,
+                    &mut symbols,
+                );
+            }
+        }
+
+        Ok(symbols)
+    }
+
+    /// Map a type declaration node to its symbol kind.
+    fn type_kind(node_kind: &str) -> Option<&'static str> {
+        match node_kind {
+            "class_declaration" => Some("class"),
+            "interface_declaration" => Some("interface"),
+            "enum_declaration" => Some("enum"),
+            "record_declaration" => Some("record"),
+            "annotation_type_declaration" => Some("annotation"),
+            _ => None,
+        }
+    }
+
+    /// Extract a type declaration and its members.
+    fn extract_type(
+        type_node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        package: Option<&str>,
+        outer_type: Option<&str>,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        let (kind, name) = match (
+            Self::type_kind(type_node.kind()),
+            JavaAstUtils::extract_name(type_node, source),
+        ) {
+            (Some(kind), Some(name)) => (kind, name),
+            _ => return,
+        };
+        let type_path = match outer_type {
+            Some(outer) => format!("{}.{}", outer, name),
+            None => name.clone(),
+        };
+
+        symbols.push(Self::create_symbol(
+            type_node, file_path, package, outer_type, &name, kind, "",
+        ));
+
+        if let Some(body) = type_node.child_by_field_name("body") {
+            Self::extract_members(body, source, file_path, package, &type_path, symbols);
+        }
+    }
+
+    /// Extract members of a class, interface, enum, or record body.
+    fn extract_members(
+        body: Node,
+        source: &str,
+        file_path: &PathBuf,
+        package: Option<&str>,
+        type_path: &str,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        let mut cursor = body.walk();
+
+        for member in body.named_children(&mut cursor) {
+            match member.kind() {
+                "method_declaration" | "constructor_declaration" => {
+                    let kind = if member.kind() == "method_declaration" {
+                        "method"
+                    } else {
+                        "constructor"
+                    };
+                    if let Some(name) = JavaAstUtils::extract_name(member, source) {
+                        let signature = JavaAstUtils::parameter_signature(member, source);
+                        symbols.push(Self::create_symbol(
+                            member,
+                            file_path,
+                            package,
+                            Some(type_path),
+                            &name,
+                            kind,
+                            &signature,
+                        ));
+                    }
+                }
+                "compact_constructor_declaration" => {
+                    // Compact record constructors take the record components implicitly
+                    let record = body.parent().unwrap_or(body);
+                    if let Some(name) = JavaAstUtils::extract_name(member, source) {
+                        let signature = JavaAstUtils::parameter_signature(record, source);
+                        symbols.push(Self::create_symbol(
+                            member,
+                            file_path,
+                            package,
+                            Some(type_path),
+                            &name,
+                            "constructor",
+                            &signature,
+                        ));
+                    }
+                }
+                "field_declaration" | "constant_declaration" => {
+                    let mut field_cursor = member.walk();
+                    for declarator in member.children_by_field_name("declarator", &mut field_cursor)
+                    {
+                        if let Some(name) = JavaAstUtils::extract_name(declarator, source) {
+                            symbols.push(Self::create_symbol(
+                                declarator,
+                                file_path,
+                                package,
+                                Some(type_path),
+                                &name,
+                                "field",
+                                "",
+                            ));
+                        }
+                    }
+                }
+                "enum_body_declarations" => {
+                    Self::extract_members(member, source, file_path, package, type_path, symbols);
+                }
+                kind if Self::type_kind(kind).is_some() => {
+                    Self::extract_type(
+                        member,
+                        source,
+                        file_path,
+                        package,
+                        Some(type_path),
+                        symbols,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Create symbol with a package-qualified name (`com.acme.Outer.Inner.method`).
+    ///
+    /// The symbol ID uses the in-file path plus the parameter signature, e.g.
+    /// `Outer.run(int,String)`, so overloads remain distinct.
+    fn create_symbol(
+        node: Node,
+        file_path: &PathBuf,
+        package: Option<&str>,
+        outer_type: Option<&str>,
+        name: &str,
+        kind: &str,
+        signature: &str,
+    ) -> TopLevelSymbol {
+        let local_path = match outer_type {
+            Some(outer) => format!("{}.{}", outer, name),
+            None => name.to_string(),
+        };
+        let qualname = match package {
+            Some(package) => format!("{}.{}", package, local_path),
+            None => local_path.clone(),
+        };
+
+        TopLevelSymbol::new(
+            file_path.clone(),
+            name.to_string(),
+            kind.to_string(),
+            qualname,
+            JavaAstUtils::extract_span(node),
+        )
+        .with_id(SymbolId::new(
+            file_path,
+            &format!("{}{}", local_path, signature),
+        ))
     }
 }
@@ -0,0 +1,75 @@
+use crate::core::format_node_span;
+use std::path::Path;
+use tree_sitter::Node;
+
+/// Utilities for working with JavaScript AST nodes.
+pub struct JavaScriptAstUtils;
+
+impl JavaScriptAstUtils {
+    /// Extract the `name` field of a declaration node.
+    pub fn extract_name(node: Node, source: &str) -> Option<String> {
+        node.child_by_field_name("name")
+            .map(|name| Self::extract_text(name, source))
+    }
+
+    /// Extract span information from a node.
+    pub fn extract_span(node: Node) -> String {
+        format_node_span(node)
+    }
+
+    /// Extract text content from node.
+    pub fn extract_text(node: Node, source: &str) -> String {
+        let start = node.start_byte();
+        let end = node.end_byte();
+        source.get(start..end).unwrap_or("").trim().to_string()
+    }
+
+    /// Module name used as the qualname root (file stem).
+    pub fn module_name(file_path: &Path) -> String {
+        file_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("module")
+            .to_string()
+    }
+
+    /// Join an in-file scope path (`Outer.Inner`) with a member name.
+    pub fn join_path(parent: Option<&str>, name: &str) -> String {
+        match parent {
+            Some(parent_path) => format!("{}.{}", parent_path, name),
+            None => name.to_string(),
+        }
+    }
+
+    /// Check if a node is a function value (arrow function or function expression).
+    pub fn is_function_value(node: Node) -> bool {
+        matches!(
+            node.kind(),
+            "arrow_function" | "function_expression" | "function" | "generator_function"
+        )
+    }
+
+    /// Check if a method definition carries the given keyword modifier.
+    pub fn has_modifier(method_node: Node, modifier: &str) -> bool {
+        method_node
+            .children(&mut method_node.walk())
+            .any(|child| !child.is_named() && child.kind() == modifier)
+    }
+
+    /// Get method type based on name and modifiers.
+    pub fn get_method_type(method_node: Node, method_name: &str) -> &'static str {
+        if method_name == "constructor" {
+            "constructor"
+        } else if Self::has_modifier(method_node, "get") {
+            "getter"
+        } else if Self::has_modifier(method_node, "set") {
+            "setter"
+        } else if Self::has_modifier(method_node, "static") {
+            "static_method"
+        } else if method_name.starts_with('#') {
+            "private_method"
+        } else {
+            "method"
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use super::ast_utils::JavaScriptAstUtils;
+use crate::core::NTreeError;
+use crate::storage::{CallEdge, CallType, SymbolId};
+use tree_sitter::Node;
+
+/// JavaScript-specific call site extractor.
+pub struct JavaScriptCallExtractor;
+
+impl JavaScriptCallExtractor {
+    /// Extract call sites from JavaScript function body.
+    ///
+    /// Covers plain and optional-chaining calls, `new` expressions, and tagged
+    /// templates. Dynamic `import()` is a dependency, not a call, and is skipped.
+    pub fn extract_call_sites(
+        function_body: Node,
+        source: &str,
+        caller_sym: &SymbolId,
+    ) -> Result<Vec<CallEdge>, NTreeError> {
+        let mut call_edges = Vec::new();
+        Self::visit_node_for_calls(function_body, source, caller_sym, &mut call_edges);
+        Ok(call_edges)
+    }
+
+    /// Recursively visit AST nodes looking for call and `new` expressions.
+    fn visit_node_for_calls(
+        node: Node,
+        source: &str,
+        caller_sym: &SymbolId,
+        call_edges: &mut Vec<CallEdge>,
+    ) {
+        let call = match node.kind() {
+            "call_expression" => Self::extract_call_expression(node, source),
+            "new_expression" => node.child_by_field_name("constructor").map(|constructor| {
+                (
+                    Self::callee_text(constructor, source),
+                    CallType::Constructor,
+                )
+            }),
+            _ => None,
+        };
+        if let Some((callee, call_type)) = call {
+            let mut edge = CallEdge::new(
+                caller_sym.clone(),
+                JavaScriptAstUtils::extract_span(node),
+                JavaScriptAstUtils::extract_text(node, source),
+            );
+            edge.call_type = call_type;
+            edge.module_hints = vec![callee];
+            call_edges.push(edge);
+        }
+
+        // Recursively visit children, including call arguments and receivers
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::visit_node_for_calls(child, source, caller_sym, call_edges);
+        }
+    }
+
+    /// Classify a call (including tagged templates) and build its callee expression.
+    fn extract_call_expression(call_node: Node, source: &str) -> Option<(String, CallType)> {
+        let function = call_node.child_by_field_name("function")?;
+        let call_type = match function.kind() {
+            "import" => return None,
+            "identifier" => CallType::FreeFunction,
+            // `super(...)` runs the parent class constructor
+            "super" => CallType::Constructor,
+            "member_expression" => CallType::InstanceMethod,
+            // Computed members, call results, and function expressions
+            _ => CallType::Dynamic,
+        };
+        Some((Self::callee_text(function, source), call_type))
+    }
+
+    /// Callee expression with optional chaining folded into plain member access.
+    fn callee_text(function: Node, source: &str) -> String {
+        match function.kind() {
+            "member_expression" => {
+                let object = function
+                    .child_by_field_name("object")
+                    .map(|object| Self::callee_text(object, source))
+                    .unwrap_or_default();
+                let property = function
+                    .child_by_field_name("property")
+                    .map(|property| JavaScriptAstUtils::extract_text(property, source))
+                    .unwrap_or_default();
+                format!("{}.{}", object, property)
+            }
+            _ => JavaScriptAstUtils::extract_text(function, source),
+        }
+    }
+}
@@ -1,19 +1,589 @@
+use super::ast_utils::JavaScriptAstUtils;
 use crate::core::NTreeError;
-use crate::storage::{ExportEdge, ImportEdge};
-use std::path::PathBuf;
+use crate::storage::{ExportEdge, ExportType, ImportEdge, ImportType, SymbolId};
+use std::path::{Path, PathBuf};
 use tree_sitter::Node;
 
 /// JavaScript-specific import/export extractor.
+///
+/// Handles ESM `import`/`export` (including re-exports), dynamic `import()`,
+/// and CommonJS `require()`, `module.exports`, and `exports.x`. A binding such
+/// as `import { a as b }` becomes one `ImportEdge` per imported name, with the
+/// local name recorded as the alias.
 pub struct JavaScriptImportExtractor;
 
 impl JavaScriptImportExtractor {
     /// Extract JavaScript import/export relationships.
     pub fn extract_dependencies(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<(Vec<ImportEdge>, Vec<ExportEdge>), NTreeError> {
-        // JavaScript import/export extraction not implemented yet
-        Ok((Vec::new(), Vec::new()))
+        let mut imports = Vec::new();
+        let mut exports = Vec::new();
+
+        Self::visit(root, source, file_path, &mut imports, &mut exports);
+
+        Ok((imports, exports))
+    }
+
+    /// Walk the tree; `require()` and `import()` may appear in any expression.
+    fn visit(
+        node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        imports: &mut Vec<ImportEdge>,
+        exports: &mut Vec<ExportEdge>,
+    ) {
+        match node.kind() {
+            "import_statement" => {
+                Self::extract_import_statement(node, source, file_path, imports);
+                return;
+            }
+            "export_statement" => {
+                Self::extract_export_statement(node, source, file_path, imports, exports);
+            }
+            "variable_declarator" => {
+                // A bound `require()` call is fully handled by its declarator
+                let handled = Self::extract_require_binding(node, source, file_path, imports);
+                if handled {
+                    return;
+                }
+            }
+            "call_expression" => {
+                Self::extract_call(node, source, file_path, imports);
+            }
+            "assignment_expression" => {
+                Self::extract_commonjs_export(node, source, file_path, exports);
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            Self::visit(child, source, file_path, imports, exports);
+        }
+    }
+
+    /// Extract `import` declarations (default, named, namespace, and side-effect).
+    fn extract_import_statement(
+        import_node: Node,
+        source: &str,
+        file_path: &Path,
+        imports: &mut Vec<ImportEdge>,
+    ) {
+        let specifier = match import_node
+            .child_by_field_name("source")
+            .and_then(|string| Self::string_value(string, source))
+        {
+            Some(specifier) => specifier,
+            None => return,
+        };
+        let edge = |imported_symbol: Option<String>, import_type: ImportType| {
+            Self::create_import(
+                import_node,
+                source,
+                file_path,
+                &specifier,
+                imported_symbol,
+                import_type,
+            )
+        };
+
+        let mut cursor = import_node.walk();
+        let clause = import_node
+            .children(&mut cursor)
+            .find(|child| child.kind() == "import_clause");
+        let clause = match clause {
+            Some(clause) => clause,
+            None => {
+                // `import './polyfill';`
+                imports.push(edge(None, ImportType::Module));
+                return;
+            }
+        };
+
+        let mut clause_cursor = clause.walk();
+        for binding in clause.named_children(&mut clause_cursor) {
+            match binding.kind() {
+                "identifier" => {
+                    let local = JavaScriptAstUtils::extract_text(binding, source);
+                    imports.push(
+                        edge(Some("default".to_string()), ImportType::Symbol).with_alias(&local),
+                    );
+                }
+                "namespace_import" => {
+                    let mut namespace_cursor = binding.walk();
+                    let local = binding
+                        .named_children(&mut namespace_cursor)
+                        .find(|child| child.kind() == "identifier")
+                        .map(|identifier| JavaScriptAstUtils::extract_text(identifier, source));
+                    if let Some(local) = local {
+                        imports.push(edge(None, ImportType::Module).with_alias(&local));
+                    }
+                }
+                "named_imports" => {
+                    let mut named_cursor = binding.walk();
+                    for specifier_node in binding.named_children(&mut named_cursor) {
+                        if specifier_node.kind() != "import_specifier" {
+                            continue;
+                        }
+                        let (name, alias) = Self::specifier_names(specifier_node, source);
+                        if let Some(name) = name {
+                            let import = edge(Some(name), ImportType::Symbol);
+                            imports.push(match alias {
+                                Some(alias) => import.with_alias(&alias),
+                                None => import,
+                            });
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Extract `export` declarations, export lists, and re-exports.
+    fn extract_export_statement(
+        export_node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        imports: &mut Vec<ImportEdge>,
+        exports: &mut Vec<ExportEdge>,
+    ) {
+        let mut cursor = export_node.walk();
+        let children: Vec<Node> = export_node.children(&mut cursor).collect();
+        let is_default = children.iter().any(|child| child.kind() == "default");
+        let clause = children
+            .iter()
+            .find(|child| child.kind() == "export_clause")
+            .copied();
+
+        if let Some(specifier) = export_node
+            .child_by_field_name("source")
+            .and_then(|string| Self::string_value(string, source))
+        {
+            Self::extract_reexport(export_node, &specifier, source, file_path, imports, exports);
+            return;
+        }
+
+        if let Some(clause) = clause {
+            // `export { a, b as c };`
+            let mut clause_cursor = clause.walk();
+            for specifier_node in clause.named_children(&mut clause_cursor) {
+                if let (Some(name), alias) = Self::specifier_names(specifier_node, source) {
+                    let export =
+                        Self::create_export(export_node, file_path, &name, ExportType::Named);
+                    exports.push(match alias {
+                        Some(alias) => export.with_alias(&alias),
+                        None => export,
+                    });
+                }
+            }
+        } else if let Some(declaration) = export_node.child_by_field_name("declaration") {
+            let export_type = if is_default {
+                ExportType::Default
+            } else {
+                ExportType::Named
+            };
+            for name in Self::declared_names(declaration, source) {
+                let export =
+                    Self::create_export(export_node, file_path, &name, export_type.clone());
+                exports.push(if is_default {
+                    export.with_alias("default")
+                } else {
+                    export
+                });
+            }
+        } else if let Some(value) = export_node.child_by_field_name("value") {
+            // `export default <expression>`; anonymous values are symbols named `default`
+            let name = Self::value_name(value, source).unwrap_or_else(|| "default".to_string());
+            exports.push(
+                Self::create_export(export_node, file_path, &name, ExportType::Default)
+                    .with_alias("default"),
+            );
+        }
+    }
+
+    /// Extract `export * from`, `export * as ns from`, and `export { a } from`.
+    fn extract_reexport(
+        export_node: Node,
+        specifier: &str,
+        source: &str,
+        file_path: &PathBuf,
+        imports: &mut Vec<ImportEdge>,
+        exports: &mut Vec<ExportEdge>,
+    ) {
+        let mut cursor = export_node.walk();
+        let children: Vec<Node> = export_node.children(&mut cursor).collect();
+        let clause = children
+            .iter()
+            .find(|child| child.kind() == "export_clause")
+            .copied();
+        let reexport = |name: &str| {
+            let mut export =
+                Self::create_export(export_node, file_path, name, ExportType::ReExport);
+            export.target_module = Some(specifier.to_string());
+            export
+        };
+
+        match clause {
+            Some(clause) => {
+                let mut clause_cursor = clause.walk();
+                for specifier_node in clause.named_children(&mut clause_cursor) {
+                    if let (Some(name), alias) = Self::specifier_names(specifier_node, source) {
+                        imports.push(Self::create_import(
+                            export_node,
+                            source,
+                            file_path,
+                            specifier,
+                            Some(name.clone()),
+                            ImportType::Symbol,
+                        ));
+                        exports.push(match alias {
+                            Some(alias) => reexport(&name).with_alias(&alias),
+                            None => reexport(&name),
+                        });
+                    }
+                }
+            }
+            None => {
+                imports.push(Self::create_import(
+                    export_node,
+                    source,
+                    file_path,
+                    specifier,
+                    None,
+                    ImportType::Wildcard,
+                ));
+                let namespace = children
+                    .iter()
+                    .find(|child| child.kind() == "namespace_export")
+                    .and_then(|namespace| namespace.named_child(0))
+                    .map(|name| JavaScriptAstUtils::extract_text(name, source));
+                exports.push(match namespace {
+                    Some(namespace) => reexport("*").with_alias(&namespace),
+                    None => reexport("*"),
+                });
+            }
+        }
+    }
+
+    /// Extract `const x = require('m')` and `const { a, b: c } = require('m')`.
+    fn extract_require_binding(
+        declarator: Node,
+        source: &str,
+        file_path: &Path,
+        imports: &mut Vec<ImportEdge>,
+    ) -> bool {
+        let specifier = match declarator
+            .child_by_field_name("value")
+            .and_then(|value| Self::require_specifier(value, source))
+        {
+            Some(specifier) => specifier,
+            None => return false,
+        };
+        let pattern = match declarator.child_by_field_name("name") {
+            Some(pattern) => pattern,
+            None => return false,
+        };
+        let edge = |imported_symbol: &str, import_type: ImportType| {
+            Self::create_import(
+                declarator,
+                source,
+                file_path,
+                &specifier,
+                Some(imported_symbol.to_string()),
+                import_type,
+            )
+        };
+
+        match pattern.kind() {
+            // The whole `module.exports` value, registered as the default export
+            "identifier" => {
+                let local = JavaScriptAstUtils::extract_text(pattern, source);
+                imports.push(edge("default", ImportType::Module).with_alias(&local));
+            }
+            "object_pattern" => {
+                let mut cursor = pattern.walk();
+                for property in pattern.named_children(&mut cursor) {
+                    match property.kind() {
+                        "shorthand_property_identifier_pattern" => {
+                            let name = JavaScriptAstUtils::extract_text(property, source);
+                            imports.push(edge(&name, ImportType::Symbol));
+                        }
+                        "pair_pattern" => {
+                            let key = property.child_by_field_name("key");
+                            let value = property.child_by_field_name("value");
+                            if let (Some(key), Some(value)) = (key, value) {
+                                let name = JavaScriptAstUtils::extract_text(key, source);
+                                let local = JavaScriptAstUtils::extract_text(value, source);
+                                imports.push(edge(&name, ImportType::Symbol).with_alias(&local));
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {
+                imports.push(Self::create_import(
+                    declarator,
+                    source,
+                    file_path,
+                    &specifier,
+                    None,
+                    ImportType::Module,
+                ));
+            }
+        }
+
+        true
+    }
+
+    /// Extract dynamic `import('m')` and unbound `require('m')` calls.
+    fn extract_call(call: Node, source: &str, file_path: &Path, imports: &mut Vec<ImportEdge>) {
+        let is_dynamic_import = call
+            .child_by_field_name("function")
+            .is_some_and(|function| function.kind() == "import");
+
+        let specifier = if is_dynamic_import {
+            Self::first_string_argument(call, source)
+        } else {
+            Self::require_specifier(call, source)
+        };
+        if let Some(specifier) = specifier {
+            let import_type = if is_dynamic_import {
+                ImportType::Dynamic
+            } else {
+                ImportType::Module
+            };
+            imports.push(Self::create_import(
+                call,
+                source,
+                file_path,
+                &specifier,
+                None,
+                import_type,
+            ));
+        }
+    }
+
+    /// Extract CommonJS `module.exports = ...`, `exports.x = ...`, and `module.exports.x = ...`.
+    fn extract_commonjs_export(
+        assignment: Node,
+        source: &str,
+        file_path: &PathBuf,
+        exports: &mut Vec<ExportEdge>,
+    ) {
+        let (left, right) = match (
+            assignment.child_by_field_name("left"),
+            assignment.child_by_field_name("right"),
+        ) {
+            (Some(left), Some(right)) if left.kind() == "member_expression" => (left, right),
+            _ => return,
+        };
+        let target = JavaScriptAstUtils::extract_text(left, source);
+
+        if target == "module.exports" {
+            if right.kind() == "object" {
+                let mut cursor = right.walk();
+                for property in right.named_children(&mut cursor) {
+                    if let Some(export) = Self::object_property_export(property, source, file_path)
+                    {
+                        exports.push(export);
+                    }
+                }
+            } else {
+                let name = Self::value_name(right, source).unwrap_or_else(|| "default".to_string());
+                exports.push(
+                    Self::create_export(assignment, file_path, &name, ExportType::Default)
+                        .with_alias("default"),
+                );
+            }
+            return;
+        }
+
+        let exported = target
+            .strip_prefix("module.exports.")
+            .or_else(|| target.strip_prefix("exports."));
+        if let Some(exported) = exported {
+            let export = match Self::value_name(right, source) {
+                Some(name) if right.kind() == "identifier" => {
+                    Self::create_export(assignment, file_path, &name, ExportType::Named)
+                        .with_alias(exported)
+                }
+                _ => Self::create_export(assignment, file_path, exported, ExportType::Named),
+            };
+            exports.push(export);
+        }
+    }
+
+    /// Export for one property of a `module.exports = { ... }` object.
+    fn object_property_export(
+        property: Node,
+        source: &str,
+        file_path: &PathBuf,
+    ) -> Option<ExportEdge> {
+        match property.kind() {
+            "shorthand_property_identifier" => {
+                let name = JavaScriptAstUtils::extract_text(property, source);
+                Some(Self::create_export(
+                    property,
+                    file_path,
+                    &name,
+                    ExportType::Named,
+                ))
+            }
+            "pair" => {
+                let key =
+                    JavaScriptAstUtils::extract_text(property.child_by_field_name("key")?, source);
+                let value = property.child_by_field_name("value")?;
+                if value.kind() == "identifier" {
+                    let name = JavaScriptAstUtils::extract_text(value, source);
+                    Some(
+                        Self::create_export(property, file_path, &name, ExportType::Named)
+                            .with_alias(&key),
+                    )
+                } else {
+                    Some(Self::create_export(
+                        property,
+                        file_path,
+                        &key,
+                        ExportType::Named,
+                    ))
+                }
+            }
+            "method_definition" => {
+                let name = JavaScriptAstUtils::extract_name(property, source)?;
+                Some(Self::create_export(
+                    property,
+                    file_path,
+                    &name,
+                    ExportType::Named,
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Names bound by an exported declaration (`export const a = 1, b = 2` binds two).
+    fn declared_names(declaration: Node, source: &str) -> Vec<String> {
+        match declaration.kind() {
+            "lexical_declaration" | "variable_declaration" => {
+                let mut cursor = declaration.walk();
+                let names = declaration
+                    .named_children(&mut cursor)
+                    .filter(|child| child.kind() == "variable_declarator")
+                    .filter_map(|declarator| declarator.child_by_field_name("name"))
+                    .filter(|name| name.kind() == "identifier")
+                    .map(|name| JavaScriptAstUtils::extract_text(name, source))
+                    .collect();
+                names
+            }
+            // TypeScript `export declare ...`
+            "ambient_declaration" => declaration
+                .named_child(0)
+                .map(|inner| Self::declared_names(inner, source))
+                .unwrap_or_default(),
+            _ => declaration
+                .child_by_field_name("name")
+                .map(|name| {
+                    JavaScriptAstUtils::extract_text(name, source)
+                        .trim_matches(|c| c == '"' || c == '\'')
+                        .to_string()
+                })
+                .into_iter()
+                .collect(),
+        }
+    }
+
+    /// Name of an exported value: an identifier or a named function/class expression.
+    fn value_name(value: Node, source: &str) -> Option<String> {
+        if value.kind() == "identifier" {
+            return Some(JavaScriptAstUtils::extract_text(value, source));
+        }
+        JavaScriptAstUtils::extract_name(value, source)
+    }
+
+    /// `name` and optional `alias` of an import or export specifier.
+    fn specifier_names(specifier: Node, source: &str) -> (Option<String>, Option<String>) {
+        let text = |field: &str| {
+            specifier
+                .child_by_field_name(field)
+                .map(|node| JavaScriptAstUtils::extract_text(node, source))
+        };
+        (text("name"), text("alias"))
+    }
+
+    /// Module specifier of a `require('m')` call.
+    fn require_specifier(call: Node, source: &str) -> Option<String> {
+        if call.kind() != "call_expression" {
+            return None;
+        }
+        let function = call.child_by_field_name("function")?;
+        if function.kind() != "identifier"
+            || JavaScriptAstUtils::extract_text(function, source) != "require"
+        {
+            return None;
+        }
+        Self::first_string_argument(call, source)
+    }
+
+    /// First argument of a call when it is a plain string literal.
+    fn first_string_argument(call: Node, source: &str) -> Option<String> {
+        let arguments = call.child_by_field_name("arguments")?;
+        let first = arguments.named_child(0)?;
+        if first.kind() == "string" {
+            Self::string_value(first, source)
+        } else {
+            None
+        }
+    }
+
+    /// Contents of a string literal without its quotes.
+    fn string_value(string: Node, source: &str) -> Option<String> {
+        let text = JavaScriptAstUtils::extract_text(string, source);
+        let value = text.trim_matches(|c| c == '"' || c == '\'' || c == '`');
+        if value.is_empty() {
+            None
+        } else {
+            Some(value.to_string())
+        }
+    }
+
+    /// Create import edge spanning the statement or call that introduced it.
+    fn create_import(
+        node: Node,
+        source: &str,
+        file_path: &Path,
+        specifier: &str,
+        imported_symbol: Option<String>,
+        import_type: ImportType,
+    ) -> ImportEdge {
+        ImportEdge::new(
+            file_path.to_path_buf(),
+            specifier.to_string(),
+            imported_symbol,
+            import_type,
+            JavaScriptAstUtils::extract_span(node),
+            JavaScriptAstUtils::extract_text(node, source),
+        )
+    }
+
+    /// Create export edge for a symbol named `name` in this file.
+    fn create_export(
+        node: Node,
+        file_path: &PathBuf,
+        name: &str,
+        export_type: ExportType,
+    ) -> ExportEdge {
+        ExportEdge::new(
+            file_path.clone(),
+            SymbolId::new(file_path, name),
+            export_type,
+            None,
+            JavaScriptAstUtils::extract_span(node),
+            "public".to_string(),
+        )
     }
 }
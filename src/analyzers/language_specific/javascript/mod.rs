@@ -1,6 +1,10 @@
-pub mod import_extractor;
 /// JavaScript-specific analyzers and extractors.
+pub mod ast_utils;
+pub mod call_extractor;
+pub mod import_extractor;
 pub mod symbol_extractor;
 
+pub use ast_utils::JavaScriptAstUtils;
+pub use call_extractor::JavaScriptCallExtractor;
 pub use import_extractor::JavaScriptImportExtractor;
 pub use symbol_extractor::JavaScriptSymbolExtractor;
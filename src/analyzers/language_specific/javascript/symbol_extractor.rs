@@ -1,19 +1,266 @@
+use super::ast_utils::JavaScriptAstUtils;
 use crate::core::NTreeError;
-use crate::storage::TopLevelSymbol;
+use crate::storage::{SymbolId, TopLevelSymbol};
 use std::path::PathBuf;
 use tree_sitter::Node;
 
-/// JavaScript-specific symbol extractor.
+/// JavaScript-specific symbol extractor for functions, classes, and methods.
 pub struct JavaScriptSymbolExtractor;
 
 impl JavaScriptSymbolExtractor {
-    /// Extract symbols from JavaScript AST.
+    /// Extract symbols from JavaScript AST including class and object-literal methods.
     pub fn extract_symbols(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<Vec<TopLevelSymbol>, NTreeError> {
-        // JavaScript symbol extraction not implemented yet
-        Ok(Vec::new())
+        let mut symbols = Vec::new();
+        let module = JavaScriptAstUtils::module_name(file_path);
+        let mut cursor = root.walk();
+
+        for child in root.children(&mut cursor) {
+            Self::extract_declaration(child, source, file_path, &module, &mut symbols);
+        }
+
+        Ok(symbols)
+    }
+
+    /// Extract symbols from a top-level statement.
+    fn extract_declaration(
+        node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        module: &str,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        match node.kind() {
+            "function_declaration" | "generator_function_declaration" => {
+                if let Some(name) = JavaScriptAstUtils::extract_name(node, source) {
+                    symbols.push(Self::create_symbol(
+                        node, file_path, module, None, &name, "function",
+                    ));
+                }
+            }
+            "class_declaration" => {
+                if let Some(name) = JavaScriptAstUtils::extract_name(node, source) {
+                    Self::extract_class(node, source, file_path, module, None, &name, symbols);
+                }
+            }
+            "lexical_declaration" | "variable_declaration" => {
+                Self::extract_variable_declaration(node, source, file_path, module, None, symbols);
+            }
+            "export_statement" => {
+                if let Some(declaration) = node.child_by_field_name("declaration") {
+                    Self::extract_declaration(declaration, source, file_path, module, symbols);
+                } else {
+                    Self::extract_default_export(node, source, file_path, module, None, symbols);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Extract `export default <value>`; anonymous values are registered as `default`.
+    pub(crate) fn extract_default_export(
+        export_node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        module: &str,
+        parent: Option<&str>,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        if let Some(value) = export_node.child_by_field_name("value") {
+            let name = JavaScriptAstUtils::extract_name(value, source)
+                .unwrap_or_else(|| "default".to_string());
+
+            if value.kind() == "class" {
+                Self::extract_class(value, source, file_path, module, parent, &name, symbols);
+            } else if JavaScriptAstUtils::is_function_value(value) {
+                symbols.push(Self::create_symbol(
+                    value, file_path, module, parent, &name, "function",
+                ));
+            }
+        }
+    }
+
+    /// Extract functions bound to `const`/`let`/`var` and object-literal methods.
+    pub(crate) fn extract_variable_declaration(
+        declaration_node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        module: &str,
+        parent: Option<&str>,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        let mut cursor = declaration_node.walk();
+
+        for declarator in declaration_node.children(&mut cursor) {
+            if declarator.kind() != "variable_declarator" {
+                continue;
+            }
+
+            let name_node = match declarator.child_by_field_name("name") {
+                Some(name_node) if name_node.kind() == "identifier" => name_node,
+                _ => continue, // Destructuring patterns don't bind a callable
+            };
+            let value = match declarator.child_by_field_name("value") {
+                Some(value) => value,
+                None => continue,
+            };
+            let name = JavaScriptAstUtils::extract_text(name_node, source);
+
+            if JavaScriptAstUtils::is_function_value(value) {
+                symbols.push(Self::create_symbol(
+                    declarator, file_path, module, parent, &name, "function",
+                ));
+            } else if value.kind() == "class" {
+                Self::extract_class(value, source, file_path, module, parent, &name, symbols);
+            } else if value.kind() == "object" {
+                let object_path = JavaScriptAstUtils::join_path(parent, &name);
+                Self::extract_object_methods(
+                    value,
+                    source,
+                    file_path,
+                    module,
+                    &object_path,
+                    symbols,
+                );
+            }
+        }
+    }
+
+    /// Extract class and its methods.
+    pub(crate) fn extract_class(
+        class_node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        module: &str,
+        parent: Option<&str>,
+        class_name: &str,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        symbols.push(Self::create_symbol(
+            class_node, file_path, module, parent, class_name, "class",
+        ));
+
+        let class_path = JavaScriptAstUtils::join_path(parent, class_name);
+        if let Some(body) = class_node.child_by_field_name("body") {
+            let mut cursor = body.walk();
+            for member in body.children(&mut cursor) {
+                if member.kind() == "method_definition" {
+                    symbols.push(Self::create_method_symbol(
+                        member,
+                        source,
+                        file_path,
+                        module,
+                        &class_path,
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Extract methods declared in an object literal, recursing into nested objects.
+    fn extract_object_methods(
+        object_node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        module: &str,
+        object_path: &str,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        let mut cursor = object_node.walk();
+
+        for member in object_node.children(&mut cursor) {
+            match member.kind() {
+                "method_definition" => {
+                    symbols.push(Self::create_method_symbol(
+                        member,
+                        source,
+                        file_path,
+                        module,
+                        object_path,
+                    ));
+                }
+                "pair" => {
+                    let (key, value) = match (
+                        member.child_by_field_name("key"),
+                        member.child_by_field_name("value"),
+                    ) {
+                        (Some(key), Some(value)) => (key, value),
+                        _ => continue,
+                    };
+                    let key_name = JavaScriptAstUtils::extract_text(key, source)
+                        .trim_matches(|c| c == '"' || c == '\'')
+                        .to_string();
+
+                    if JavaScriptAstUtils::is_function_value(value) {
+                        symbols.push(Self::create_symbol(
+                            member,
+                            file_path,
+                            module,
+                            Some(object_path),
+                            &key_name,
+                            "method",
+                        ));
+                    } else if value.kind() == "object" {
+                        let nested_path = format!("{}.{}", object_path, key_name);
+                        Self::extract_object_methods(
+                            value,
+                            source,
+                            file_path,
+                            module,
+                            &nested_path,
+                            symbols,
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Create method symbol from a `method_definition` node.
+    pub(crate) fn create_method_symbol(
+        method_node: Node,
+        source: &str,
+        file_path: &PathBuf,
+        module: &str,
+        parent: &str,
+    ) -> TopLevelSymbol {
+        let name = JavaScriptAstUtils::extract_name(method_node, source)
+            .unwrap_or_else(|| "unknown".to_string());
+        let kind = JavaScriptAstUtils::get_method_type(method_node, &name);
+
+        Self::create_symbol(method_node, file_path, module, Some(parent), &name, kind)
+    }
+
+    /// Create symbol with a module-qualified name (`module.Class.method`).
+    ///
+    /// The symbol ID uses the in-file path (`Class.method`) so that members with
+    /// the same name in different classes or objects stay distinct. Accessors get
+    /// their kind appended since a getter and setter usually share a name.
+    pub(crate) fn create_symbol(
+        node: Node,
+        file_path: &PathBuf,
+        module: &str,
+        parent: Option<&str>,
+        name: &str,
+        kind: &str,
+    ) -> TopLevelSymbol {
+        let local_path = JavaScriptAstUtils::join_path(parent, name);
+        let id_path = match kind {
+            "getter" | "setter" => format!("{}#{}", local_path, kind),
+            _ => local_path.clone(),
+        };
+
+        TopLevelSymbol::new(
+            file_path.clone(),
+            name.to_string(),
+            kind.to_string(),
+            format!("{}.{}", module, local_path),
+            JavaScriptAstUtils::extract_span(node),
+        )
+        .with_id(SymbolId::new(file_path, &id_path))
     }
 }
@@ -7,10 +7,13 @@ pub mod python;
 pub mod rust;
 pub mod typescript;
 
-pub use c::{CImportExtractor, CSymbolExtractor};
-pub use cpp::{CppImportExtractor, CppSymbolExtractor};
-pub use java::{JavaImportExtractor, JavaSymbolExtractor};
-pub use javascript::{JavaScriptImportExtractor, JavaScriptSymbolExtractor};
+pub use c::{CAstUtils, CCallExtractor, CImportExtractor, CSymbolExtractor};
+pub use cpp::{CppAstUtils, CppCallExtractor, CppImportExtractor, CppSymbolExtractor};
+pub use java::{JavaAstUtils, JavaCallExtractor, JavaImportExtractor, JavaSymbolExtractor};
+pub use javascript::{
+    JavaScriptAstUtils, JavaScriptCallExtractor, JavaScriptImportExtractor,
+    JavaScriptSymbolExtractor,
+};
 pub use python::{
     PythonAstUtils, PythonCallExtractor, PythonImportExtractor, PythonSymbolExtractor,
 };
@@ -18,4 +21,6 @@ pub use rust::{
     RustAstUtils, RustCallExtractor, RustEarlyExitAnalyzer, RustForLoopAnalyzer,
     RustImportExtractor, RustSymbolExtractor,
 };
-pub use typescript::{TypeScriptImportExtractor, TypeScriptSymbolExtractor};
+pub use typescript::{
+    TypeScriptCallExtractor, TypeScriptImportExtractor, TypeScriptSymbolExtractor,
+};
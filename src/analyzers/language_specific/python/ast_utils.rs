@@ -1,3 +1,4 @@
+use crate::core::format_node_span;
 use tree_sitter::Node;
 
 /// Utilities for working with Python AST nodes.
@@ -17,33 +18,41 @@ impl PythonAstUtils {
         "unknown".to_string()
     }
 
+    /// Extract text content from node.
+    pub fn extract_text(node: Node, source: &str) -> String {
+        let start = node.start_byte();
+        let end = node.end_byte();
+        source.get(start..end).unwrap_or("").trim().to_string()
+    }
+
     /// Extract span information from a node.
     pub fn extract_span(node: Node) -> String {
-        let start_point = node.start_position();
-        let end_point = node.end_position();
-        format!(
-            "{}:{}–{}:{}",
-            start_point.row + 1,
-            start_point.column + 1,
-            end_point.row + 1,
-            end_point.column + 1
-        )
+        format_node_span(node)
     }
 
-    /// Find all function definitions in a block.
+    /// Find all function definitions in a block, including decorated ones.
     pub fn find_functions_in_block(block: Node) -> Vec<Node> {
         let mut functions = Vec::new();
         let mut cursor = block.walk();
 
         for child in block.children(&mut cursor) {
-            if child.kind() == "function_definition" {
-                functions.push(child);
+            if let Some(function) = Self::unwrap_decorated(child, "function_definition") {
+                functions.push(function);
             }
         }
 
         functions
     }
 
+    /// Return the definition of the given kind, looking through decorators.
+    pub fn unwrap_decorated<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+        let definition = match node.kind() {
+            "decorated_definition" => node.child_by_field_name("definition")?,
+            _ => node,
+        };
+        (definition.kind() == kind).then_some(definition)
+    }
+
     /// Find the class body block.
     pub fn find_class_body(class_node: Node) -> Option<Node> {
         let mut cursor = class_node.walk();
@@ -25,20 +25,17 @@ impl PythonCallExtractor {
         caller_sym: &SymbolId,
         call_edges: &mut Vec<CallEdge>,
     ) {
-        match node.kind() {
-            "call" => {
-                if let Some(call_edge) = Self::extract_call_expression(node, source, caller_sym) {
-                    call_edges.push(call_edge);
-                }
-            }
-            _ => {
-                // Recursively visit children
-                let mut cursor = node.walk();
-                for child in node.children(&mut cursor) {
-                    Self::visit_node_for_calls(child, source, caller_sym, call_edges);
-                }
+        if node.kind() == "call" {
+            if let Some(call_edge) = Self::extract_call_expression(node, source, caller_sym) {
+                call_edges.push(call_edge);
             }
         }
+
+        // Recursively visit children, including call arguments and receivers
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::visit_node_for_calls(child, source, caller_sym, call_edges);
+        }
     }
 
     /// Extract call edge from Python call expression.
@@ -53,21 +50,19 @@ impl PythonCallExtractor {
         // Extract function name being called
         let function_name = Self::extract_function_name(call_node, source);
 
-        Some(
-            CallEdge::new(caller_sym.clone(), span, call_text)
-                .with_dynamic_hints(vec![function_name]),
-        ) // Python calls are dynamic by default
+        // Targets are resolved later; unresolved Python calls are dynamic
+        let mut edge = CallEdge::new(caller_sym.clone(), span, call_text);
+        edge.call_type = Self::classify_call_type(&function_name);
+        edge.module_hints = vec![function_name];
+        Some(edge)
     }
 
-    /// Extract the function name from call expression.
+    /// Extract the callee expression (`helper`, `self.run`, `os.path.join`).
     fn extract_function_name(call_node: Node, source: &str) -> String {
-        let mut cursor = call_node.walk();
-        for child in call_node.children(&mut cursor) {
-            if child.kind() == "identifier" || child.kind() == "attribute" {
-                return Self::extract_call_text(child, source);
-            }
+        match call_node.child_by_field_name("function") {
+            Some(function) => Self::extract_call_text(function, source),
+            None => "unknown_function".to_string(),
         }
-        "unknown_function".to_string()
     }
 
     /// Extract call expression text.
@@ -1,10 +1,14 @@
 use super::ast_utils::PythonAstUtils;
 use crate::core::NTreeError;
-use crate::storage::{ExportEdge, ImportEdge, ImportType};
+use crate::storage::{ExportEdge, ExportType, ImportEdge, ImportType, SymbolId};
 use std::path::PathBuf;
 use tree_sitter::Node;
 
 /// Python-specific import/export extractor.
+///
+/// `from module import a, b as c` becomes one `ImportEdge` per imported name,
+/// with the local name recorded as the alias. Every top-level function and
+/// class is exported, since Python modules expose all of their names.
 pub struct PythonImportExtractor;
 
 impl PythonImportExtractor {
@@ -15,10 +19,13 @@ impl PythonImportExtractor {
         file_path: &PathBuf,
     ) -> Result<(Vec<ImportEdge>, Vec<ExportEdge>), NTreeError> {
         let mut imports = Vec::new();
-        let exports = Vec::new();
+        let mut exports = Vec::new();
         let mut cursor = root.walk();
 
         for child in root.children(&mut cursor) {
+            if let Some(export) = Self::extract_export(child, source, file_path) {
+                exports.push(export);
+            }
             match child.kind() {
                 "import_statement" => {
                     if let Some(import) = Self::extract_import_statement(child, source, file_path)?
@@ -27,9 +34,7 @@ impl PythonImportExtractor {
                     }
                 }
                 "import_from_statement" => {
-                    if let Some(import) = Self::extract_from_import(child, source, file_path)? {
-                        imports.push(import);
-                    }
+                    imports.extend(Self::extract_from_import(child, source, file_path)?);
                 }
                 _ => {}
             }
@@ -68,32 +73,69 @@ impl PythonImportExtractor {
         import_node: Node,
         source: &str,
         file_path: &PathBuf,
-    ) -> Result<Option<ImportEdge>, NTreeError> {
-        let mut cursor = import_node.walk();
-        let mut module_name = String::new();
-        let mut import_type = ImportType::Symbol;
-
-        for child in import_node.children(&mut cursor) {
-            if child.kind() == "dotted_name" && module_name.is_empty() {
-                module_name = PythonAstUtils::extract_name(child, source);
-            } else if child.kind() == "wildcard_import" {
-                import_type = ImportType::Wildcard;
-                break;
+    ) -> Result<Vec<ImportEdge>, NTreeError> {
+        let module_name = match import_node.child_by_field_name("module_name") {
+            Some(module) if module.kind() == "dotted_name" => {
+                PythonAstUtils::extract_text(module, source)
             }
-        }
-
-        if !module_name.is_empty() {
-            Ok(Some(ImportEdge::new(
+            _ => return Ok(Vec::new()),
+        };
+        let edge = |imported_symbol: Option<String>, import_type: ImportType| {
+            ImportEdge::new(
                 file_path.clone(),
-                module_name,
-                None,
+                module_name.clone(),
+                imported_symbol,
                 import_type,
                 PythonAstUtils::extract_span(import_node),
                 Self::extract_import_text(import_node, source),
-            )))
-        } else {
-            Ok(None)
+            )
+        };
+
+        let mut cursor = import_node.walk();
+        if import_node
+            .children(&mut cursor)
+            .any(|child| child.kind() == "wildcard_import")
+        {
+            return Ok(vec![edge(None, ImportType::Wildcard)]);
+        }
+
+        let mut imports = Vec::new();
+        for name in import_node.children_by_field_name("name", &mut cursor) {
+            match name.kind() {
+                "aliased_import" => {
+                    let (Some(imported), Some(alias)) = (
+                        name.child_by_field_name("name"),
+                        name.child_by_field_name("alias"),
+                    ) else {
+                        continue;
+                    };
+                    let imported = PythonAstUtils::extract_text(imported, source);
+                    let alias = PythonAstUtils::extract_text(alias, source);
+                    imports.push(edge(Some(imported), ImportType::Symbol).with_alias(&alias));
+                }
+                _ => {
+                    let imported = PythonAstUtils::extract_text(name, source);
+                    imports.push(edge(Some(imported), ImportType::Symbol));
+                }
+            }
         }
+
+        Ok(imports)
+    }
+
+    /// Export a top-level function or class under its own name.
+    fn extract_export(node: Node, source: &str, file_path: &PathBuf) -> Option<ExportEdge> {
+        let definition = PythonAstUtils::unwrap_decorated(node, "function_definition")
+            .or_else(|| PythonAstUtils::unwrap_decorated(node, "class_definition"))?;
+        let name = PythonAstUtils::extract_name(definition, source);
+        Some(ExportEdge::new(
+            file_path.clone(),
+            SymbolId::new(file_path, &name),
+            ExportType::Public,
+            None,
+            PythonAstUtils::extract_span(definition),
+            "public".to_string(),
+        ))
     }
 
     /// Extract import statement text.
@@ -1,6 +1,6 @@
 use super::ast_utils::PythonAstUtils;
 use crate::core::NTreeError;
-use crate::storage::TopLevelSymbol;
+use crate::storage::{SymbolId, TopLevelSymbol};
 use std::path::PathBuf;
 use tree_sitter::Node;
 
@@ -18,18 +18,15 @@ impl PythonSymbolExtractor {
         let mut cursor = root.walk();
 
         for child in root.children(&mut cursor) {
-            match child.kind() {
-                "function_definition" => {
-                    // Top-level function
-                    symbols.push(Self::create_function_symbol(
-                        child, source, file_path, None,
-                    )?);
-                }
-                "class_definition" => {
-                    // Class and its methods
-                    symbols.extend(Self::extract_class_symbols(child, source, file_path)?);
-                }
-                _ => {}
+            if let Some(function) = PythonAstUtils::unwrap_decorated(child, "function_definition") {
+                // Top-level function
+                symbols.push(Self::create_function_symbol(
+                    function, source, file_path, None,
+                )?);
+            } else if let Some(class) = PythonAstUtils::unwrap_decorated(child, "class_definition")
+            {
+                // Class and its methods
+                symbols.extend(Self::extract_class_symbols(class, source, file_path)?);
             }
         }
 
@@ -90,12 +87,15 @@ impl PythonSymbolExtractor {
             ),
         };
 
-        Ok(TopLevelSymbol::new(
-            file_path.clone(),
-            func_name,
-            kind,
-            qualname,
-            span,
-        ))
+        let symbol =
+            TopLevelSymbol::new(file_path.clone(), func_name.clone(), kind, qualname, span);
+        Ok(match class_name {
+            // Methods of different classes may share a name
+            Some(class) => symbol.with_id(SymbolId::new(
+                file_path,
+                &format!("{}::{}", class, func_name),
+            )),
+            None => symbol,
+        })
     }
 }
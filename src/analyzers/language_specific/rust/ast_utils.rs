@@ -1,3 +1,4 @@
+use crate::core::format_node_span;
 use tree_sitter::Node;
 
 /// Utilities for working with Rust AST nodes.
@@ -6,6 +7,10 @@ pub struct RustAstUtils;
 impl RustAstUtils {
     /// Extract identifier name from a node.
     pub fn extract_name(node: Node, source: &str) -> String {
+        // Items name themselves through the `name` field (a `type_identifier` for types)
+        if let Some(name) = node.child_by_field_name("name") {
+            return Self::extract_text(name, source);
+        }
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
             if child.kind() == "identifier" {
@@ -19,28 +24,22 @@ impl RustAstUtils {
 
     /// Extract span information from a node.
     pub fn extract_span(node: Node) -> String {
-        let start_point = node.start_position();
-        let end_point = node.end_position();
-        format!(
-            "{}:{}–{}:{}",
-            start_point.row + 1,
-            start_point.column + 1,
-            end_point.row + 1,
-            end_point.column + 1
-        )
+        format_node_span(node)
     }
 
-    /// Extract impl target (what struct/trait is being implemented).
+    /// Extract impl target (the type being implemented, without generic arguments).
+    ///
+    /// For trait impls this is the implementing type, so `impl Display for Foo`
+    /// yields `Foo`.
     pub fn extract_impl_target(impl_node: Node, source: &str) -> String {
-        let mut cursor = impl_node.walk();
-        for child in impl_node.children(&mut cursor) {
-            if child.kind() == "type_identifier" || child.kind() == "generic_type" {
-                let start = child.start_byte();
-                let end = child.end_byte();
-                return source.get(start..end).unwrap_or("unknown").to_string();
-            }
+        match impl_node.child_by_field_name("type") {
+            Some(target) if target.kind() == "generic_type" => target
+                .child_by_field_name("type")
+                .map(|base| Self::extract_text(base, source))
+                .unwrap_or_else(|| Self::extract_text(target, source)),
+            Some(target) => Self::extract_text(target, source),
+            None => "unknown_impl".to_string(),
         }
-        "unknown_impl".to_string()
     }
 
     /// Find function items in a block or impl.
@@ -1,5 +1,5 @@
-use crate::core::NTreeError;
-use crate::storage::{CallConfidence, CallEdge, CallType, SymbolId};
+use crate::core::{format_node_span, NTreeError};
+use crate::storage::{CallEdge, CallType, SymbolId};
 use tree_sitter::Node;
 
 /// Rust-specific call site extractor.
@@ -35,13 +35,13 @@ impl RustCallExtractor {
                     call_edges.push(call_edge);
                 }
             }
-            _ => {
-                // Recursively visit children
-                let mut cursor = node.walk();
-                for child in node.children(&mut cursor) {
-                    Self::visit_node_for_calls(child, source, caller_sym, call_edges);
-                }
-            }
+            _ => {}
+        }
+
+        // Recursively visit children, including call arguments and receivers
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::visit_node_for_calls(child, source, caller_sym, call_edges);
         }
     }
 
@@ -55,23 +55,20 @@ impl RustCallExtractor {
         let call_text = Self::extract_call_text(call_node, source);
         let function_name = Self::extract_function_name(call_node, source);
 
-        // Classify call type based on Rust syntax
-        let call_type = Self::classify_rust_call_type(&call_text);
-        let confidence = match call_type {
-            CallType::FreeFunction | CallType::StaticMethod => CallConfidence::Direct,
-            CallType::InstanceMethod => CallConfidence::Virtual,
-            _ => CallConfidence::Unknown,
-        };
+        // Classify call type based on Rust syntax; targets are resolved later
+        let call_type = Self::classify_rust_call_type(&function_name);
 
         let mut edge = CallEdge::new(caller_sym.clone(), span, call_text);
         edge.call_type = call_type;
-        edge.confidence = confidence;
         edge.module_hints = vec![function_name];
 
         Some(edge)
     }
 
     /// Extract macro invocation as call.
+    ///
+    /// Macros expand in place and are never resolved to a symbol, so the edge
+    /// stays unresolved with the macro name as its hint.
     fn extract_macro_call(
         macro_node: Node,
         source: &str,
@@ -79,11 +76,13 @@ impl RustCallExtractor {
     ) -> Option<CallEdge> {
         let span = Self::extract_span(macro_node);
         let call_text = Self::extract_call_text(macro_node, source);
+        let macro_name = macro_node
+            .child_by_field_name("macro")
+            .map(|name| format!("{}!", Self::extract_call_text(name, source)))?;
 
-        Some(
-            CallEdge::new(caller_sym.clone(), span, call_text)
-                .with_direct_target(caller_sym.clone()),
-        ) // Placeholder for macro resolution
+        let mut edge = CallEdge::new(caller_sym.clone(), span, call_text);
+        edge.module_hints = vec![macro_name];
+        Some(edge)
     }
 
     /// Classify Rust call type based on syntax.
@@ -101,15 +100,7 @@ impl RustCallExtractor {
 
     /// Extract span from node.
     fn extract_span(node: Node) -> String {
-        let start = node.start_position();
-        let end = node.end_position();
-        format!(
-            "{}:{}–{}:{}",
-            start.row + 1,
-            start.column + 1,
-            end.row + 1,
-            end.column + 1
-        )
+        format_node_span(node)
     }
 
     /// Extract call text.
@@ -119,14 +110,11 @@ impl RustCallExtractor {
         source.get(start..end).unwrap_or("").trim().to_string()
     }
 
-    /// Extract function name being called.
+    /// Extract the callee expression (`helper`, `self.run`, `Foo::new`).
     fn extract_function_name(call_node: Node, source: &str) -> String {
-        let mut cursor = call_node.walk();
-        for child in call_node.children(&mut cursor) {
-            if child.kind() == "identifier" || child.kind() == "field_expression" {
-                return Self::extract_call_text(child, source);
-            }
+        match call_node.child_by_field_name("function") {
+            Some(function) => Self::extract_call_text(function, source),
+            None => "unknown_function".to_string(),
         }
-        "unknown_function".to_string()
     }
 }
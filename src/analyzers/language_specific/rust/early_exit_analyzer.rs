@@ -1,6 +1,9 @@
 use crate::models::EarlyExitIR;
 use tree_sitter::Node;
 
+/// Macros that panic or otherwise never return.
+const DIVERGING_MACROS: &[&str] = &["panic!", "unreachable!", "todo!", "unimplemented!"];
+
 /// Rust-specific analyzer for early-exit constructs.
 pub struct RustEarlyExitAnalyzer;
 
@@ -25,15 +28,81 @@ impl RustEarlyExitAnalyzer {
     }
 
     /// Check if a statement contains a try operator (?).
+    ///
+    /// Looks for a `try_expression` node, so `?:` ternaries and `?` in string
+    /// literals are not mistaken for it.
     pub fn contains_try_operator(stmt: Node, source: &str) -> bool {
         let text = Self::extract_text(stmt, source);
-        text.contains("?") && !text.starts_with("if") && !text.starts_with("match")
+        Self::has_try_expression(stmt) && !text.starts_with("if") && !text.starts_with("match")
+    }
+
+    fn has_try_expression(node: Node) -> bool {
+        match node.kind() {
+            "try_expression" => return true,
+            // A `?` in a closure or async block returns from it, not the function
+            "closure_expression" | "async_block" | "function_item" => return false,
+            _ => {}
+        }
+        node.named_children(&mut node.walk())
+            .any(Self::has_try_expression)
     }
 
-    /// Check if a statement is a panic! macro.
+    /// Check if a statement is a panic! macro, or `unreachable!`, `todo!` or
+    /// `unimplemented!`, which panic too.
     pub fn is_panic_macro(stmt: Node, source: &str) -> bool {
         let text = Self::extract_text(stmt, source);
-        text.starts_with("panic!")
+        Self::is_panic_text(&text)
+    }
+
+    fn is_panic_text(text: &str) -> bool {
+        DIVERGING_MACROS.iter().any(|name| text.starts_with(name))
+    }
+
+    /// Check if a statement calls `std::process::exit` or a function in
+    /// `diverging_functions`, so control never returns from it.
+    pub fn is_diverging_call(stmt: Node, source: &str, diverging_functions: &[String]) -> bool {
+        let call = match stmt.kind() {
+            "expression_statement" => stmt.named_child(0),
+            _ => Some(stmt),
+        };
+        let function = match call {
+            Some(call) if call.kind() == "call_expression" => call.child_by_field_name("function"),
+            _ => None,
+        };
+        let path = match function {
+            Some(function) if matches!(function.kind(), "identifier" | "scoped_identifier") => {
+                Self::extract_text(function, source)
+            }
+            _ => return false,
+        };
+
+        if path == "process::exit" || path.ends_with("::process::exit") {
+            return true;
+        }
+        let name = path.rsplit("::").next().unwrap_or(&path);
+        diverging_functions.iter().any(|function| function == name)
+    }
+
+    /// Collect the names of the functions under `root` declared to return `!`.
+    pub fn diverging_functions(root: Node, source: &str) -> Vec<String> {
+        let mut names = Vec::new();
+        Self::collect_diverging_functions(root, source, &mut names);
+        names
+    }
+
+    fn collect_diverging_functions(node: Node, source: &str, names: &mut Vec<String>) {
+        if node.kind() == "function_item" {
+            let returns_never = node
+                .child_by_field_name("return_type")
+                .is_some_and(|return_type| return_type.kind() == "never_type");
+            if let (true, Some(name)) = (returns_never, node.child_by_field_name("name")) {
+                names.push(Self::extract_text(name, source));
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            Self::collect_diverging_functions(child, source, names);
+        }
     }
 
     /// Check if node contains any Rust early-exit construct.
@@ -42,17 +111,9 @@ impl RustEarlyExitAnalyzer {
     }
 
     /// Extract Rust try operator: expr?
+    ///
+    /// The trigger is the whole `expr?`, as for a statement containing one.
     fn extract_try_operator(try_node: Node, source: &str, exit_id: String) -> Option<EarlyExitIR> {
-        // Get the expression before the ?
-        let mut cursor = try_node.walk();
-        for child in try_node.named_children(&mut cursor) {
-            if child.kind() != "?" {
-                let expr_text = Self::extract_text(child, source);
-                return Some(EarlyExitIR::new_try_operator(exit_id, expr_text));
-            }
-        }
-
-        // Fallback to full text
         let full_text = Self::extract_text(try_node, source);
         Some(EarlyExitIR::new_try_operator(exit_id, full_text))
     }
@@ -62,7 +123,7 @@ impl RustEarlyExitAnalyzer {
         let full_text = Self::extract_text(macro_node, source);
 
         // Check if it's a panic! macro
-        if full_text.starts_with("panic!") {
+        if Self::is_panic_text(&full_text) {
             // Extract panic message if present
             let message = Self::extract_panic_message(macro_node, source);
             return Some(EarlyExitIR::new_panic(exit_id, full_text, message));
@@ -121,7 +182,7 @@ impl RustEarlyExitAnalyzer {
         exit_id: String,
     ) -> Option<EarlyExitIR> {
         let text = Self::extract_text(stmt, source);
-        if text.starts_with("panic!") {
+        if Self::is_panic_text(&text) {
             let message = Self::extract_panic_message_from_text(&text);
             return Some(EarlyExitIR::new_panic(exit_id, text, message));
         }
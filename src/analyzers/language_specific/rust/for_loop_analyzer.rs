@@ -13,6 +13,17 @@ impl RustForLoopAnalyzer {
 
     /// Extract Rust iterator-style for loop: for pattern in iterator
     fn extract_iterator_loop(for_node: Node, source: &str, loop_id: String) -> Option<ForLoopIR> {
+        if let (Some(pattern), Some(value)) = (
+            for_node.child_by_field_name("pattern"),
+            for_node.child_by_field_name("value"),
+        ) {
+            return Some(ForLoopIR::new_iterator(
+                loop_id,
+                Self::extract_text(pattern, source),
+                Self::extract_text(value, source),
+            ));
+        }
+
         let mut cursor = for_node.walk();
         let mut pattern = None;
         let mut iter_expr = None;
@@ -1,6 +1,6 @@
 use super::ast_utils::RustAstUtils;
 use crate::core::NTreeError;
-use crate::storage::TopLevelSymbol;
+use crate::storage::{SymbolId, TopLevelSymbol};
 use std::path::PathBuf;
 use tree_sitter::Node;
 
@@ -51,8 +51,13 @@ impl RustSymbolExtractor {
         let mut symbols = Vec::new();
         let impl_target = RustAstUtils::extract_impl_target(impl_node, source);
 
+        let body = match impl_node.child_by_field_name("body") {
+            Some(body) => body,
+            None => return Ok(symbols),
+        };
+
         // Add methods
-        for method_node in RustAstUtils::find_functions_in_node(impl_node) {
+        for method_node in RustAstUtils::find_functions_in_node(body) {
             symbols.push(Self::create_symbol(
                 method_node,
                 source,
@@ -90,12 +95,14 @@ impl RustSymbolExtractor {
             ),
         };
 
-        Ok(TopLevelSymbol::new(
-            file_path.clone(),
-            name,
-            kind,
-            qualname,
-            span,
-        ))
+        let symbol = TopLevelSymbol::new(file_path.clone(), name.clone(), kind, qualname, span);
+        Ok(match parent {
+            // Methods of different types may share a name
+            Some(parent_name) => symbol.with_id(SymbolId::new(
+                file_path,
+                &format!("{}::{}", parent_name, name),
+            )),
+            None => symbol,
+        })
     }
 }
@@ -0,0 +1,21 @@
+use crate::analyzers::language_specific::javascript::JavaScriptCallExtractor;
+use crate::core::NTreeError;
+use crate::storage::{CallEdge, SymbolId};
+use tree_sitter::Node;
+
+/// TypeScript-specific call site extractor.
+pub struct TypeScriptCallExtractor;
+
+impl TypeScriptCallExtractor {
+    /// Extract call sites from TypeScript function body.
+    ///
+    /// TypeScript call syntax matches JavaScript; type arguments sit outside the
+    /// callee expression and do not affect classification.
+    pub fn extract_call_sites(
+        function_body: Node,
+        source: &str,
+        caller_sym: &SymbolId,
+    ) -> Result<Vec<CallEdge>, NTreeError> {
+        JavaScriptCallExtractor::extract_call_sites(function_body, source, caller_sym)
+    }
+}
@@ -1,19 +1,24 @@
+use crate::analyzers::language_specific::javascript::JavaScriptImportExtractor;
 use crate::core::NTreeError;
 use crate::storage::{ExportEdge, ImportEdge};
 use std::path::PathBuf;
 use tree_sitter::Node;
 
 /// TypeScript-specific import/export extractor.
+///
+/// TypeScript module syntax is a superset of JavaScript's, so this defers to
+/// the JavaScript extractor. `import type` and `export type` produce the same
+/// edges as their value counterparts; exported interfaces, type aliases,
+/// enums, and namespaces are picked up through their `name` field.
 pub struct TypeScriptImportExtractor;
 
 impl TypeScriptImportExtractor {
     /// Extract TypeScript import/export relationships.
     pub fn extract_dependencies(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<(Vec<ImportEdge>, Vec<ExportEdge>), NTreeError> {
-        // Not implemented yet
-        Ok((Vec::new(), Vec::new()))
+        JavaScriptImportExtractor::extract_dependencies(root, source, file_path)
     }
 }
@@ -1,6 +1,8 @@
+pub mod call_extractor;
 pub mod import_extractor;
 /// TypeScript-specific analyzers and extractors.
 pub mod symbol_extractor;
 
+pub use call_extractor::TypeScriptCallExtractor;
 pub use import_extractor::TypeScriptImportExtractor;
 pub use symbol_extractor::TypeScriptSymbolExtractor;
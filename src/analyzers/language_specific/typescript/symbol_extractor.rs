@@ -1,19 +1,318 @@
+use crate::analyzers::language_specific::javascript::{
+    JavaScriptAstUtils, JavaScriptSymbolExtractor,
+};
 use crate::core::NTreeError;
 use crate::storage::TopLevelSymbol;
 use std::path::PathBuf;
 use tree_sitter::Node;
 
-/// TypeScript-specific symbol extractor.
+/// Per-file inputs shared by every extraction step.
+struct FileContext<'a> {
+    source: &'a str,
+    file_path: &'a PathBuf,
+    module: String,
+}
+
+/// TypeScript-specific symbol extractor for declarations, types, and namespaces.
+///
+/// Builds on the JavaScript extractor for the shared syntax (functions, classes,
+/// object literals) and adds TypeScript-only declarations. Declarations under
+/// `declare`, including the members of declared classes, interfaces and
+/// namespaces, get an `ambient_` kind prefix.
 pub struct TypeScriptSymbolExtractor;
 
 impl TypeScriptSymbolExtractor {
     /// Extract symbols from TypeScript AST.
     pub fn extract_symbols(
-        _root: Node,
-        _source: &str,
-        _file_path: &PathBuf,
+        root: Node,
+        source: &str,
+        file_path: &PathBuf,
     ) -> Result<Vec<TopLevelSymbol>, NTreeError> {
-        // Not implemented yet
-        Ok(Vec::new())
+        let mut symbols = Vec::new();
+        let ctx = FileContext {
+            source,
+            file_path,
+            module: JavaScriptAstUtils::module_name(file_path),
+        };
+        let mut cursor = root.walk();
+
+        for child in root.children(&mut cursor) {
+            Self::extract_declaration(child, &ctx, None, false, &mut symbols);
+        }
+
+        Ok(symbols)
+    }
+
+    /// Extract symbols from a statement at module or namespace level.
+    fn extract_declaration(
+        node: Node,
+        ctx: &FileContext,
+        parent: Option<&str>,
+        ambient: bool,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        match node.kind() {
+            "function_declaration" | "generator_function_declaration" => {
+                Self::push_named(node, ctx, parent, ambient, "function", symbols);
+            }
+            // Outside `declare` a bodiless signature is an overload of a real declaration
+            "function_signature" if ambient => {
+                Self::push_named(node, ctx, parent, ambient, "function", symbols);
+            }
+            "class_declaration" => {
+                Self::extract_class(node, ctx, parent, ambient, "class", symbols);
+            }
+            "abstract_class_declaration" => {
+                Self::extract_class(node, ctx, parent, ambient, "abstract_class", symbols);
+            }
+            "interface_declaration" => {
+                Self::extract_interface(node, ctx, parent, ambient, symbols);
+            }
+            "enum_declaration" => {
+                Self::push_named(node, ctx, parent, ambient, "enum", symbols);
+            }
+            "type_alias_declaration" => {
+                Self::push_named(node, ctx, parent, ambient, "type_alias", symbols);
+            }
+            "internal_module" => {
+                Self::extract_namespace(node, ctx, parent, ambient, "namespace", symbols);
+            }
+            "module" => {
+                Self::extract_namespace(node, ctx, parent, ambient, "module", symbols);
+            }
+            "lexical_declaration" | "variable_declaration" => {
+                if ambient {
+                    Self::extract_ambient_variables(node, ctx, parent, symbols);
+                } else {
+                    JavaScriptSymbolExtractor::extract_variable_declaration(
+                        node,
+                        ctx.source,
+                        ctx.file_path,
+                        &ctx.module,
+                        parent,
+                        symbols,
+                    );
+                }
+            }
+            "ambient_declaration" => {
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    Self::extract_declaration(child, ctx, parent, true, symbols);
+                }
+            }
+            "export_statement" => {
+                if let Some(declaration) = node.child_by_field_name("declaration") {
+                    Self::extract_declaration(declaration, ctx, parent, ambient, symbols);
+                } else {
+                    JavaScriptSymbolExtractor::extract_default_export(
+                        node,
+                        ctx.source,
+                        ctx.file_path,
+                        &ctx.module,
+                        parent,
+                        symbols,
+                    );
+                }
+            }
+            "expression_statement" => {
+                // `namespace X {}` parses as an expression statement wrapping the module
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    if child.kind() == "internal_module" {
+                        Self::extract_declaration(child, ctx, parent, ambient, symbols);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Extract a namespace or module and the declarations in its body.
+    fn extract_namespace(
+        namespace_node: Node,
+        ctx: &FileContext,
+        parent: Option<&str>,
+        ambient: bool,
+        kind: &str,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        let name = match namespace_node.child_by_field_name("name") {
+            Some(name_node) => JavaScriptAstUtils::extract_text(name_node, ctx.source)
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_string(),
+            None => return,
+        };
+        symbols.push(Self::create_symbol(
+            namespace_node,
+            ctx,
+            parent,
+            &name,
+            ambient,
+            kind,
+        ));
+
+        let namespace_path = JavaScriptAstUtils::join_path(parent, &name);
+        if let Some(body) = namespace_node.child_by_field_name("body") {
+            let mut cursor = body.walk();
+            for child in body.children(&mut cursor) {
+                Self::extract_declaration(child, ctx, Some(&namespace_path), ambient, symbols);
+            }
+        }
+    }
+
+    /// Extract class (regular, abstract, or ambient) and its members.
+    fn extract_class(
+        class_node: Node,
+        ctx: &FileContext,
+        parent: Option<&str>,
+        ambient: bool,
+        kind: &str,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        let class_name = match JavaScriptAstUtils::extract_name(class_node, ctx.source) {
+            Some(name) => name,
+            None => return,
+        };
+        symbols.push(Self::create_symbol(
+            class_node,
+            ctx,
+            parent,
+            &class_name,
+            ambient,
+            kind,
+        ));
+
+        let class_path = JavaScriptAstUtils::join_path(parent, &class_name);
+        let body = match class_node.child_by_field_name("body") {
+            Some(body) => body,
+            None => return,
+        };
+        let mut cursor = body.walk();
+
+        for member in body.children(&mut cursor) {
+            match member.kind() {
+                "method_definition" | "method_signature" => {
+                    // Outside `declare` a bodiless method signature is an overload
+                    if member.kind() == "method_signature" && !ambient {
+                        continue;
+                    }
+                    let mut method = JavaScriptSymbolExtractor::create_method_symbol(
+                        member,
+                        ctx.source,
+                        ctx.file_path,
+                        &ctx.module,
+                        &class_path,
+                    );
+                    if ambient {
+                        method.kind = format!("ambient_{}", method.kind);
+                    }
+                    symbols.push(method);
+                }
+                "abstract_method_signature" => {
+                    Self::push_named(
+                        member,
+                        ctx,
+                        Some(&class_path),
+                        ambient,
+                        "abstract_method",
+                        symbols,
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Extract interface and its method signatures.
+    fn extract_interface(
+        interface_node: Node,
+        ctx: &FileContext,
+        parent: Option<&str>,
+        ambient: bool,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        let interface_name = match JavaScriptAstUtils::extract_name(interface_node, ctx.source) {
+            Some(name) => name,
+            None => return,
+        };
+        symbols.push(Self::create_symbol(
+            interface_node,
+            ctx,
+            parent,
+            &interface_name,
+            ambient,
+            "interface",
+        ));
+
+        let interface_path = JavaScriptAstUtils::join_path(parent, &interface_name);
+        if let Some(body) = interface_node.child_by_field_name("body") {
+            let mut cursor = body.walk();
+            for member in body.children(&mut cursor) {
+                if member.kind() == "method_signature" {
+                    Self::push_named(
+                        member,
+                        ctx,
+                        Some(&interface_path),
+                        ambient,
+                        "method_signature",
+                        symbols,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Extract `declare const/let/var` bindings.
+    fn extract_ambient_variables(
+        declaration_node: Node,
+        ctx: &FileContext,
+        parent: Option<&str>,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        let mut cursor = declaration_node.walk();
+        for declarator in declaration_node.children(&mut cursor) {
+            if declarator.kind() == "variable_declarator" {
+                Self::push_named(declarator, ctx, parent, true, "variable", symbols);
+            }
+        }
+    }
+
+    /// Push a symbol for a node with a `name` field.
+    fn push_named(
+        node: Node,
+        ctx: &FileContext,
+        parent: Option<&str>,
+        ambient: bool,
+        kind: &str,
+        symbols: &mut Vec<TopLevelSymbol>,
+    ) {
+        if let Some(name) = JavaScriptAstUtils::extract_name(node, ctx.source) {
+            symbols.push(Self::create_symbol(node, ctx, parent, &name, ambient, kind));
+        }
+    }
+
+    /// Create symbol, prefixing the kind with `ambient_` for `declare` declarations.
+    fn create_symbol(
+        node: Node,
+        ctx: &FileContext,
+        parent: Option<&str>,
+        name: &str,
+        ambient: bool,
+        kind: &str,
+    ) -> TopLevelSymbol {
+        let kind = if ambient {
+            format!("ambient_{}", kind)
+        } else {
+            kind.to_string()
+        };
+
+        JavaScriptSymbolExtractor::create_symbol(
+            node,
+            ctx.file_path,
+            &ctx.module,
+            parent,
+            name,
+            &kind,
+        )
     }
 }
@@ -0,0 +1,283 @@
+use crate::analyzers::dominance_analyzer::{DominanceAnalyzer, IndexedCfg};
+use crate::models::ir::FunctionCFGIR;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// An edge leaving a loop.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoopExit {
+    /// Node in the loop body
+    pub from: String,
+    /// Node outside the loop
+    pub to: String,
+    /// Kind of the CFG edge (e.g., "false", "break")
+    pub kind: String,
+}
+
+/// A loop of a function's CFG: its header and the cycles through it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NaturalLoop {
+    #[serde(rename = "type")]
+    pub loop_type: String,
+    /// Function this loop belongs to
+    pub func: String,
+    /// Node every iteration goes through
+    pub header: String,
+    /// Node identifiers in the loop in CFG order, header included
+    pub body: Vec<String>,
+    /// Nodes jumping back to the header
+    pub latches: Vec<String>,
+    /// Edges from the body to nodes after the loop
+    pub exits: Vec<LoopExit>,
+    /// Nesting depth, 1 for an outermost loop
+    pub depth: u32,
+    /// Header of the innermost enclosing loop
+    pub parent: Option<String>,
+    /// Whether the loop can only be entered through its header. A `goto`
+    /// into a loop body makes it irreducible.
+    pub reducible: bool,
+}
+
+/// Loop-nesting forest of a function.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoopForest {
+    /// Function name being analyzed
+    pub function: String,
+    /// Qualified name of the function, e.g. `Parser::parse`
+    #[serde(default)]
+    pub qualname: String,
+    /// Loops in CFG order of their headers
+    pub loops: Vec<NaturalLoop>,
+}
+
+impl LoopForest {
+    /// Get the deepest loop nesting, 0 without loops.
+    pub fn max_depth(&self) -> u32 {
+        self.loops
+            .iter()
+            .map(|natural_loop| natural_loop.depth)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Check whether every loop can only be entered through its header.
+    pub fn is_reducible(&self) -> bool {
+        self.loops.iter().all(|natural_loop| natural_loop.reducible)
+    }
+
+    /// Get the loop with the given header.
+    pub fn loop_at(&self, header: &str) -> Option<&NaturalLoop> {
+        self.loops
+            .iter()
+            .find(|natural_loop| natural_loop.header == header)
+    }
+
+    /// Get the innermost loop containing a node.
+    pub fn innermost_loop(&self, node: &str) -> Option<&NaturalLoop> {
+        self.loops
+            .iter()
+            .filter(|natural_loop| natural_loop.body.iter().any(|body_node| body_node == node))
+            .max_by_key(|natural_loop| natural_loop.depth)
+    }
+
+    /// Get the outermost loops.
+    pub fn roots(&self) -> Vec<&NaturalLoop> {
+        self.loops
+            .iter()
+            .filter(|natural_loop| natural_loop.parent.is_none())
+            .collect()
+    }
+
+    /// Get the loops directly nested in the loop with the given header.
+    pub fn children(&self, header: &str) -> Vec<&NaturalLoop> {
+        self.loops
+            .iter()
+            .filter(|natural_loop| natural_loop.parent.as_deref() == Some(header))
+            .collect()
+    }
+
+    /// Convert to JSONL format, one loop per line.
+    pub fn to_jsonl(&self) -> String {
+        let mut jsonl = String::new();
+        for natural_loop in &self.loops {
+            match serde_json::to_string(natural_loop) {
+                Ok(json) => {
+                    jsonl.push_str(&json);
+                    jsonl.push('\n');
+                }
+                Err(_) => continue,
+            }
+        }
+        jsonl
+    }
+}
+
+/// Analyzer for detecting the loops of a function's CFG and how they nest.
+pub struct LoopAnalyzer;
+
+impl LoopAnalyzer {
+    /// Create a new loop analyzer.
+    pub fn new() -> Self {
+        LoopAnalyzer
+    }
+
+    /// Analyze a function's CFG for loops.
+    pub fn analyze(&self, cfg: &FunctionCFGIR) -> Result<LoopForest, String> {
+        let func = cfg.function_name.clone();
+        let mut forest = LoopForest {
+            function: func.clone(),
+            qualname: cfg.qualname.clone(),
+            loops: Vec::new(),
+        };
+        if cfg.nodes.is_empty() {
+            return Ok(forest);
+        }
+
+        let graph = IndexedCfg::new(cfg);
+        let forward = graph.targets();
+        let idom =
+            DominanceAnalyzer::immediate_dominators(&forward, &graph.predecessors, graph.entry);
+
+        // Every cycle has a retreating edge, closing it back to the node the
+        // search entered it through
+        let mut headers: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for (latch, header) in Self::retreating_edges(&forward, graph.entry) {
+            headers.entry(header).or_default().push(latch);
+        }
+
+        let mut bodies = Vec::new();
+        for (&header, latches) in &headers {
+            bodies.push(Self::loop_body(&graph, &forward, header, latches));
+        }
+
+        // The parent of a loop is the smallest other loop around its header
+        let sizes: Vec<usize> = bodies.iter().map(|body| body.len()).collect();
+        let parents: Vec<Option<usize>> = headers
+            .keys()
+            .enumerate()
+            .map(|(i, header)| {
+                (0..bodies.len())
+                    .filter(|&j| j != i && sizes[j] > sizes[i] && bodies[j].contains(header))
+                    .min_by_key(|&j| sizes[j])
+            })
+            .collect();
+        let mut depths = vec![0; bodies.len()];
+        let mut by_size: Vec<usize> = (0..bodies.len()).collect();
+        by_size.sort_by_key(|&i| std::cmp::Reverse(sizes[i]));
+        for i in by_size {
+            depths[i] = parents[i].map_or(1, |parent| depths[parent] + 1);
+        }
+
+        let header_nodes: Vec<usize> = headers.keys().copied().collect();
+        for (i, (&header, latches)) in headers.iter().enumerate() {
+            let body = &bodies[i];
+            let mut nodes: Vec<usize> = body.iter().copied().collect();
+            nodes.sort_unstable();
+
+            let mut exits = Vec::new();
+            for &node in &nodes {
+                for &(to, kind) in &graph.successors[node] {
+                    if !body.contains(&to) {
+                        exits.push(LoopExit {
+                            from: graph.id(node),
+                            to: graph.id(to),
+                            kind: kind.to_string(),
+                        });
+                    }
+                }
+            }
+
+            forest.loops.push(NaturalLoop {
+                loop_type: "Loop".to_string(),
+                func: func.clone(),
+                header: graph.id(header),
+                body: nodes.into_iter().map(|node| graph.id(node)).collect(),
+                latches: latches.iter().map(|&latch| graph.id(latch)).collect(),
+                exits,
+                depth: depths[i],
+                parent: parents[i].map(|parent| graph.id(header_nodes[parent])),
+                // A latch the header doesn't dominate was reached around it
+                reducible: latches
+                    .iter()
+                    .all(|&latch| Self::dominates(&idom, header, latch)),
+            });
+        }
+
+        Ok(forest)
+    }
+
+    /// Find the edges to a node still being searched in a depth-first search
+    /// from `root`, as `(from, to)` pairs.
+    fn retreating_edges(successors: &[Vec<usize>], root: usize) -> Vec<(usize, usize)> {
+        let mut visited = vec![false; successors.len()];
+        let mut on_stack = vec![false; successors.len()];
+        let mut edges = Vec::new();
+        let mut stack = vec![(root, 0)];
+        visited[root] = true;
+        on_stack[root] = true;
+
+        while let Some((node, next)) = stack.pop() {
+            match successors[node].get(next) {
+                Some(&child) => {
+                    stack.push((node, next + 1));
+                    if on_stack[child] {
+                        edges.push((node, child));
+                    } else if !visited[child] {
+                        visited[child] = true;
+                        on_stack[child] = true;
+                        stack.push((child, 0));
+                    }
+                }
+                None => on_stack[node] = false,
+            }
+        }
+
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
+
+    /// Collect the nodes reaching a latch without going through the header.
+    /// Only nodes reachable from the header count, so the entries of an
+    /// irreducible loop stay out of it.
+    fn loop_body(
+        graph: &IndexedCfg,
+        successors: &[Vec<usize>],
+        header: usize,
+        latches: &[usize],
+    ) -> HashSet<usize> {
+        let from_header: HashSet<usize> = DominanceAnalyzer::reverse_postorder(successors, header)
+            .into_iter()
+            .collect();
+
+        let mut body = HashSet::from([header]);
+        let mut worklist: Vec<usize> = latches.to_vec();
+        while let Some(node) = worklist.pop() {
+            if !from_header.contains(&node) || !body.insert(node) {
+                continue;
+            }
+            worklist.extend(graph.predecessors[node].iter().copied());
+        }
+        body
+    }
+
+    /// Check whether `dominator` is on the dominator tree path to `node`.
+    fn dominates(idom: &[Option<usize>], dominator: usize, node: usize) -> bool {
+        let mut current = node;
+        loop {
+            if current == dominator {
+                return true;
+            }
+            match idom[current] {
+                Some(parent) if parent != current => current = parent,
+                _ => return false,
+            }
+        }
+    }
+}
+
+impl Default for LoopAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
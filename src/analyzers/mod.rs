@@ -1,17 +1,31 @@
 /// Language-specific analyzers and language-agnostic IR normalization.
+pub mod cognitive_complexity_analyzer;
 pub mod complexity_analyzer;
 pub mod data_flow_analyzer;
+pub mod def_use_extractor;
+pub mod dominance_analyzer;
 pub mod early_exit_normalizer;
 pub mod for_loop_normalizer;
+pub mod halstead_analyzer;
 pub mod language_specific;
+pub mod loop_analyzer;
+pub mod ssa_builder;
 pub mod variable_lifecycle;
 pub mod variable_lifecycle_analyzer;
 pub mod workspace_data_flow_analyzer;
 
+pub use cognitive_complexity_analyzer::CognitiveComplexityAnalyzer;
 pub use complexity_analyzer::{ComplexityAnalyzer, ComplexityResult};
 pub use data_flow_analyzer::DataFlowAnalyzer;
+pub use def_use_extractor::DefUseExtractor;
+pub use dominance_analyzer::{
+    ControlDependence, DominanceAnalyzer, DominanceFrontier, DominanceResult, DominatorTreeEdge,
+};
 pub use early_exit_normalizer::EarlyExitNormalizer;
 pub use for_loop_normalizer::ForLoopNormalizer;
+pub use halstead_analyzer::HalsteadAnalyzer;
+pub use loop_analyzer::{LoopAnalyzer, LoopExit, LoopForest, NaturalLoop};
+pub use ssa_builder::SsaBuilder;
 pub use variable_lifecycle_analyzer::VariableLifecycleAnalyzer;
 pub use workspace_data_flow_analyzer::{
     CrossFileVariable, WorkspaceDataFlowAnalyzer, WorkspaceDataFlowResult,
@@ -0,0 +1,206 @@
+use crate::analyzers::def_use_extractor::DefUseExtractor;
+use crate::analyzers::dominance_analyzer::{DominanceAnalyzer, IndexedCfg};
+use crate::models::ir::{
+    FunctionCFGIR, FunctionSSAIR, NodeDefUse, PhiNodeIR, PhiOperandIR, SsaNodeIR, SsaValue,
+};
+use std::collections::HashMap;
+
+/// Builds the static single assignment form of a function's CFG.
+///
+/// Phi nodes are placed at the iterated dominance frontiers of each
+/// variable's definitions, then variables are renamed along the dominator
+/// tree so every version has exactly one definition.
+pub struct SsaBuilder;
+
+impl SsaBuilder {
+    /// Create a new SSA builder.
+    pub fn new() -> Self {
+        SsaBuilder
+    }
+
+    /// Build SSA form, reading each node's definitions and uses from its label.
+    pub fn build(&self, cfg: &FunctionCFGIR) -> Result<FunctionSSAIR, String> {
+        let def_use: Vec<NodeDefUse> = cfg
+            .nodes
+            .iter()
+            .map(|node| DefUseExtractor::extract(&node.label))
+            .collect();
+        self.build_with_def_use(cfg, &def_use)
+    }
+
+    /// Build SSA form from the definitions and uses of each node, in CFG order.
+    pub fn build_with_def_use(
+        &self,
+        cfg: &FunctionCFGIR,
+        def_use: &[NodeDefUse],
+    ) -> Result<FunctionSSAIR, String> {
+        if def_use.len() != cfg.nodes.len() {
+            return Err(format!(
+                "Expected definitions and uses for {} nodes, got {}",
+                cfg.nodes.len(),
+                def_use.len()
+            ));
+        }
+
+        let func = cfg.function_name.clone();
+        let mut ssa = FunctionSSAIR::new(func.clone(), cfg.source_file.clone())
+            .with_qualname(cfg.qualname.clone());
+        if cfg.nodes.is_empty() {
+            return Ok(ssa);
+        }
+
+        let graph = IndexedCfg::new(cfg);
+        let forward = graph.targets();
+        let count = cfg.nodes.len();
+        let idom =
+            DominanceAnalyzer::immediate_dominators(&forward, &graph.predecessors, graph.entry);
+        let frontiers = DominanceAnalyzer::dominance_frontiers(&graph.predecessors, &idom);
+
+        // Place phi nodes at the iterated dominance frontier of each
+        // variable's definitions
+        let mut variables: Vec<&str> = Vec::new();
+        let mut def_sites: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (node, node_def_use) in def_use.iter().enumerate() {
+            if idom[node].is_none() {
+                continue; // Unreachable
+            }
+            for variable in &node_def_use.defs {
+                def_sites
+                    .entry(variable.as_str())
+                    .or_insert_with(|| {
+                        variables.push(variable);
+                        Vec::new()
+                    })
+                    .push(node);
+            }
+        }
+        let mut phis: Vec<Vec<&str>> = vec![Vec::new(); count];
+        for &variable in &variables {
+            let mut has_phi = vec![false; count];
+            let mut worklist = def_sites[variable].clone();
+            while let Some(node) = worklist.pop() {
+                for &frontier in &frontiers[node] {
+                    if !has_phi[frontier] {
+                        has_phi[frontier] = true;
+                        phis[frontier].push(variable);
+                        worklist.push(frontier);
+                    }
+                }
+            }
+        }
+
+        let mut children = vec![Vec::new(); count];
+        for (node, parent) in idom.iter().enumerate() {
+            match parent {
+                Some(parent) if *parent != node => children[*parent].push(node),
+                _ => {}
+            }
+        }
+
+        // Rename along the dominator tree, popping a node's versions once
+        // its subtree is done
+        let mut versions: HashMap<&str, u32> = HashMap::new();
+        let mut stacks: HashMap<&str, Vec<u32>> = HashMap::new();
+        let mut pushed: Vec<Vec<&str>> = vec![Vec::new(); count];
+        let mut nodes: Vec<Option<SsaNodeIR>> = vec![None; count];
+        let mut phi_targets: Vec<Vec<SsaValue>> = vec![Vec::new(); count];
+        let mut phi_operands: Vec<Vec<Vec<(usize, SsaValue)>>> = phis
+            .iter()
+            .map(|node_phis| vec![Vec::new(); node_phis.len()])
+            .collect();
+
+        let mut stack = vec![(graph.entry, false)];
+        while let Some((node, done)) = stack.pop() {
+            if done {
+                for variable in &pushed[node] {
+                    if let Some(stack) = stacks.get_mut(variable) {
+                        stack.pop();
+                    }
+                }
+                continue;
+            }
+            stack.push((node, true));
+
+            for &variable in &phis[node] {
+                phi_targets[node].push(Self::define(variable, &mut versions, &mut stacks));
+                pushed[node].push(variable);
+            }
+
+            let mut ssa_node =
+                SsaNodeIR::new(func.clone(), graph.id(node), cfg.nodes[node].label.clone());
+            for variable in &def_use[node].uses {
+                ssa_node.uses.push(SsaValue::new(
+                    variable.clone(),
+                    Self::current(&stacks, variable),
+                ));
+            }
+            for variable in &def_use[node].defs {
+                ssa_node
+                    .defs
+                    .push(Self::define(variable, &mut versions, &mut stacks));
+                pushed[node].push(variable);
+            }
+            nodes[node] = Some(ssa_node);
+
+            // Each successor's phi nodes take the versions live here
+            for &successor in &forward[node] {
+                for (i, &variable) in phis[successor].iter().enumerate() {
+                    let operands = &mut phi_operands[successor][i];
+                    if !operands.iter().any(|(from, _)| *from == node) {
+                        let version = Self::current(&stacks, variable);
+                        operands.push((node, SsaValue::new(variable.to_string(), version)));
+                    }
+                }
+            }
+
+            for &child in children[node].iter().rev() {
+                stack.push((child, false));
+            }
+        }
+
+        ssa.nodes = nodes.into_iter().flatten().collect();
+        for (node, targets) in phi_targets.into_iter().enumerate() {
+            for (target, mut operands) in targets.into_iter().zip(phi_operands[node].drain(..)) {
+                operands.sort_by_key(|(from, _)| *from);
+                let mut phi = PhiNodeIR::new(func.clone(), graph.id(node), target);
+                phi.operands = operands
+                    .into_iter()
+                    .map(|(from, value)| PhiOperandIR {
+                        from: graph.id(from),
+                        value,
+                    })
+                    .collect();
+                ssa.phis.push(phi);
+            }
+        }
+
+        Ok(ssa)
+    }
+
+    /// Create the next version of a variable and make it the live one.
+    fn define<'a>(
+        variable: &'a str,
+        versions: &mut HashMap<&'a str, u32>,
+        stacks: &mut HashMap<&'a str, Vec<u32>>,
+    ) -> SsaValue {
+        let version = versions.entry(variable).or_insert(0);
+        *version += 1;
+        stacks.entry(variable).or_default().push(*version);
+        SsaValue::new(variable.to_string(), *version)
+    }
+
+    /// Get the version of a variable live at this point of the renaming.
+    fn current(stacks: &HashMap<&str, Vec<u32>>, variable: &str) -> u32 {
+        stacks
+            .get(variable)
+            .and_then(|stack| stack.last())
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl Default for SsaBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,31 +1,53 @@
 use crate::analyzers::{
-    ComplexityAnalyzer, ComplexityResult, DataFlowAnalyzer, VariableLifecycleAnalyzer,
+    CognitiveComplexityAnalyzer, ComplexityAnalyzer, ComplexityResult, DataFlowAnalyzer,
+    DominanceAnalyzer, DominanceResult, HalsteadAnalyzer, LoopAnalyzer, LoopForest, SsaBuilder,
+    VariableLifecycleAnalyzer,
 };
-use crate::api::analysis::{generate_cfg_ir, BasicBlockResult, CfgResult};
+use crate::api::analysis::cfg::map_functions;
+use crate::api::analysis::{
+    generate_cfg_ir_with_options, generate_cfgs_with_options, BasicBlockResult, CfgResult,
+};
+use crate::api::extractors::language_extractors::LanguageExtractors;
 use crate::core::NTreeError;
-use crate::models::FunctionSpan;
+use crate::extractors::CfgOptions;
 use crate::models::{ControlFlowGraph, DataFlowGraph, VariableLifecycleSet};
+use crate::models::{FunctionCFGIR, FunctionSSAIR, FunctionSpan, HalsteadResult};
+use crate::storage::{CallGraph, CallResolver, FunctionFacts, NameResolver, SymbolStore};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Internal module for running individual analyses.
 pub struct AnalysisRunner;
 
 impl AnalysisRunner {
-    /// Run complexity analysis on CFG IR data.
+    /// Build the CFG IR of every function in a file, shared by the analyses
+    /// that run on it.
+    pub fn run_cfg_ir_generation(
+        file_path: &PathBuf,
+        cfg_options: &CfgOptions,
+    ) -> Result<Vec<FunctionCFGIR>, NTreeError> {
+        generate_cfg_ir_with_options(file_path, cfg_options)
+    }
+
+    /// Run complexity analysis on the CFG IR of a file's functions.
     pub fn run_complexity_analysis(
         file_path: &PathBuf,
+        cfg_ir_results: &[FunctionCFGIR],
     ) -> Result<Vec<ComplexityResult>, NTreeError> {
-        // Generate CFG IR data if needed for complexity analysis
-        let cfg_ir_results = match generate_cfg_ir(file_path) {
-            Ok(results) => results,
-            Err(e) => return Err(e),
-        };
+        // Cognitive complexity reads the syntax tree rather than the CFG
+        let cognitive_analyzer = CognitiveComplexityAnalyzer::new();
+        let cognitive_scores = map_functions(file_path, |name, _, body, source| {
+            cognitive_analyzer.analyze(body, source, name)
+        })?;
 
         let mut complexity_data = Vec::new();
         let analyzer = ComplexityAnalyzer::new();
-        for cfg_ir in cfg_ir_results {
-            match analyzer.analyze(&cfg_ir) {
-                Ok(result) => complexity_data.push(result),
+        for (cfg_ir, cognitive) in cfg_ir_results.iter().zip(cognitive_scores) {
+            match analyzer.analyze(cfg_ir) {
+                Ok(mut result) => {
+                    result.cognitive = cognitive;
+                    complexity_data.push(result)
+                }
                 Err(e) => {
                     return Err(NTreeError::ParseError(format!(
                         "Complexity analysis failed: {}",
@@ -38,9 +60,167 @@ impl AnalysisRunner {
         Ok(complexity_data)
     }
 
+    /// Run dominator, post-dominator and control dependence analysis on CFG IR data.
+    pub fn run_dominance_analysis(
+        cfg_ir_results: &[FunctionCFGIR],
+    ) -> Result<Vec<DominanceResult>, NTreeError> {
+        let analyzer = DominanceAnalyzer::new();
+        let mut dominance_data = Vec::new();
+        for cfg_ir in cfg_ir_results {
+            match analyzer.analyze(cfg_ir) {
+                Ok(result) => dominance_data.push(result),
+                Err(e) => {
+                    return Err(NTreeError::ParseError(format!(
+                        "Dominance analysis failed: {}",
+                        e
+                    )))
+                }
+            }
+        }
+
+        Ok(dominance_data)
+    }
+
+    /// Run natural loop detection on CFG IR data.
+    pub fn run_loop_analysis(
+        cfg_ir_results: &[FunctionCFGIR],
+    ) -> Result<Vec<LoopForest>, NTreeError> {
+        let analyzer = LoopAnalyzer::new();
+        let mut loop_data = Vec::new();
+        for cfg_ir in cfg_ir_results {
+            match analyzer.analyze(cfg_ir) {
+                Ok(result) => loop_data.push(result),
+                Err(e) => {
+                    return Err(NTreeError::ParseError(format!(
+                        "Loop analysis failed: {}",
+                        e
+                    )))
+                }
+            }
+        }
+
+        Ok(loop_data)
+    }
+
+    /// Run static single assignment form construction on CFG IR data.
+    pub fn run_ssa_construction(
+        cfg_ir_results: &[FunctionCFGIR],
+    ) -> Result<Vec<FunctionSSAIR>, NTreeError> {
+        let builder = SsaBuilder::new();
+        let mut ssa_data = Vec::new();
+        for cfg_ir in cfg_ir_results {
+            match builder.build(cfg_ir) {
+                Ok(result) => ssa_data.push(result),
+                Err(e) => {
+                    return Err(NTreeError::ParseError(format!(
+                        "SSA construction failed: {}",
+                        e
+                    )))
+                }
+            }
+        }
+
+        Ok(ssa_data)
+    }
+
+    /// Run Halstead metrics and Maintainability Index computation on each
+    /// function's tokens, with cyclomatic complexity from its CFG IR.
+    pub fn run_halstead_analysis(
+        file_path: &PathBuf,
+        cfg_ir_results: &[FunctionCFGIR],
+    ) -> Result<Vec<HalsteadResult>, NTreeError> {
+        let complexity_analyzer = ComplexityAnalyzer::new();
+        let mut function_complexity = Vec::new();
+        for cfg_ir in cfg_ir_results {
+            match complexity_analyzer.analyze(cfg_ir) {
+                Ok(result) => {
+                    function_complexity.push((cfg_ir.qualname.clone(), result.cyclomatic))
+                }
+                Err(e) => {
+                    return Err(NTreeError::ParseError(format!(
+                        "Halstead analysis failed: {}",
+                        e
+                    )))
+                }
+            }
+        }
+
+        let analyzer = HalsteadAnalyzer::new();
+        let mut function_complexity = function_complexity.into_iter();
+        map_functions(file_path, |name, function, _, source| {
+            let (qualname, cyclomatic) = function_complexity
+                .next()
+                .unwrap_or_else(|| (name.to_string(), 1));
+            analyzer
+                .analyze(name, function, source, cyclomatic)
+                .with_qualname(qualname)
+        })
+    }
+
+    /// Record function facts for the function symbols of a file.
+    ///
+    /// Symbols are joined with the spans and results of the same name in
+    /// source order, so same-named methods keep their own facts. Functions
+    /// without complexity results get no facts.
+    pub fn record_function_facts(
+        file_path: &PathBuf,
+        symbol_store: &mut SymbolStore,
+        function_spans: &[FunctionSpan],
+        complexity_data: &[ComplexityResult],
+        halstead_data: &[HalsteadResult],
+    ) {
+        let mut occurrences: HashMap<&str, usize> = HashMap::new();
+        let mut facts = Vec::new();
+        for symbol in symbol_store.get_file_symbols(file_path) {
+            let is_function = symbol.kind.contains("function")
+                || symbol.kind.contains("method")
+                || symbol.kind == "constructor";
+            if !is_function {
+                continue;
+            }
+            let occurrence = occurrences.entry(symbol.name.as_str()).or_insert(0);
+            let index = *occurrence;
+            *occurrence += 1;
+
+            let Some(complexity) = complexity_data
+                .iter()
+                .filter(|result| result.function == symbol.name)
+                .nth(index)
+            else {
+                continue;
+            };
+            let span = function_spans
+                .iter()
+                .filter(|span| span.function == symbol.name)
+                .nth(index)
+                .cloned()
+                .unwrap_or_else(|| {
+                    FunctionSpan::new(symbol.name.clone(), symbol.span.clone(), None)
+                });
+
+            let mut function_facts =
+                FunctionFacts::from_function_analysis(symbol, &span, complexity);
+            if let Some(halstead) = halstead_data
+                .iter()
+                .filter(|result| result.function == symbol.name)
+                .nth(index)
+            {
+                function_facts = function_facts.with_halstead(halstead.clone());
+            }
+            facts.push(function_facts);
+        }
+
+        for function_facts in facts {
+            symbol_store.add_function_facts(function_facts);
+        }
+    }
+
     /// Run CFG generation if enabled.
-    pub fn run_cfg_generation(file_path: &PathBuf) -> Result<Vec<CfgResult>, NTreeError> {
-        match crate::api::analysis::generate_cfgs(file_path) {
+    pub fn run_cfg_generation(
+        file_path: &PathBuf,
+        cfg_options: &CfgOptions,
+    ) -> Result<Vec<CfgResult>, NTreeError> {
+        match generate_cfgs_with_options(file_path, cfg_options) {
             Ok(cfgs) => Ok(cfgs),
             Err(e) => Err(e),
         }
@@ -70,7 +250,7 @@ impl AnalysisRunner {
         let mut analyzer = DataFlowAnalyzer::new();
 
         // Get CFG data for the file
-        let cfg_results = Self::run_cfg_generation(file_path)?;
+        let cfg_results = Self::run_cfg_generation(file_path, &CfgOptions::default())?;
 
         for cfg_result in cfg_results {
             // Create empty CFG for now (would need proper conversion)
@@ -96,7 +276,7 @@ impl AnalysisRunner {
         let mut analyzer = VariableLifecycleAnalyzer::new();
         let mut combined_lifecycles = VariableLifecycleSet::new();
 
-        let cfg_results = Self::run_cfg_generation(file_path)?;
+        let cfg_results = Self::run_cfg_generation(file_path, &CfgOptions::default())?;
 
         for (cfg_result, data_flow) in cfg_results.iter().zip(data_flow_graphs.iter()) {
             // Create empty CFG for now
@@ -117,4 +297,31 @@ impl AnalysisRunner {
 
         Ok(combined_lifecycles)
     }
+
+    /// Build the call graph for the given files.
+    ///
+    /// Call sites are extracted from every function with a symbol in
+    /// `symbol_store`, then resolved against those symbols and, in workspace
+    /// mode, the name resolver's import bindings.
+    pub fn run_call_graph_construction(
+        file_paths: &[PathBuf],
+        symbol_store: &SymbolStore,
+        name_resolver: Option<&NameResolver>,
+    ) -> Result<CallGraph, NTreeError> {
+        let resolver = CallResolver::new(symbol_store, name_resolver);
+        let mut call_graph = CallGraph::new();
+
+        for file_path in file_paths {
+            match LanguageExtractors::extract_calls(file_path, symbol_store) {
+                Ok(call_edges) => {
+                    for edge in call_edges {
+                        call_graph.add_call_edge(resolver.resolve(edge));
+                    }
+                }
+                Err(_) => continue, // Skip files with extraction errors
+            }
+        }
+
+        Ok(call_graph)
+    }
 }
@@ -1,7 +1,7 @@
 use crate::core::{read_file, NTreeError};
-use crate::extractors::cfg::build_cfg_from_block;
 use crate::extractors::cfg::ir_converter::CFGToIRConverter;
 use crate::extractors::cfg::processors::build_basic_blocks_from_block;
+use crate::extractors::cfg::{build_cfg_with_options, CfgOptions};
 use crate::language::{detect_language_config, LanguageConfig};
 use crate::models::FunctionCFGIR;
 use serde::{Deserialize, Serialize};
@@ -12,6 +12,9 @@ use tree_sitter::{Node, Parser};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CfgResult {
     pub function_name: String,
+    /// Function name qualified by its enclosing types, modules and functions,
+    /// e.g. `Parser::parse`
+    pub qualname: String,
     pub mermaid: String,
     pub jsonl: String,
 }
@@ -32,6 +35,15 @@ pub struct BasicBlockResult {
 /// * `Ok(Vec<CfgResult>)` - CFG results for each function
 /// * `Err(NTreeError)` - If file cannot be read, parsed, or language unsupported
 pub fn generate_cfgs<P: AsRef<Path>>(path: P) -> Result<Vec<CfgResult>, NTreeError> {
+    generate_cfgs_with_options(path, &CfgOptions::default())
+}
+
+/// Generates Control Flow Graphs for all functions in a source file, with
+/// optional expansions such as short-circuit conditions.
+pub fn generate_cfgs_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &CfgOptions,
+) -> Result<Vec<CfgResult>, NTreeError> {
     let path_ref = path.as_ref();
     let content = read_file(path_ref)?;
     let config = detect_language_config(path_ref)?;
@@ -56,7 +68,18 @@ pub fn generate_cfgs<P: AsRef<Path>>(path: P) -> Result<Vec<CfgResult>, NTreeErr
     let mut results = Vec::new();
 
     // Find all functions and generate CFGs for their bodies
-    extract_function_cfgs(root_node, &content, &config, &mut results);
+    for function in collect_functions(root_node, &content, &config) {
+        // Build CFG from the body
+        let cfg = build_cfg_with_options(function.body, &content, options);
+
+        // Create result with both representations
+        results.push(CfgResult {
+            function_name: function.name,
+            qualname: function.qualname,
+            mermaid: cfg.to_mermaid(),
+            jsonl: cfg.to_jsonl(),
+        });
+    }
 
     Ok(results)
 }
@@ -64,57 +87,152 @@ pub fn generate_cfgs<P: AsRef<Path>>(path: P) -> Result<Vec<CfgResult>, NTreeErr
 /// Alias for generate_cfgs to maintain backward compatibility with v2 naming.
 pub use self::generate_cfgs as generate_cfgs_v2;
 
-/// Recursively extracts CFGs from function nodes.
-fn extract_function_cfgs(
-    node: Node,
+/// A function-like node found in a syntax tree.
+struct FunctionNode<'a> {
+    name: String,
+    qualname: String,
+    node: Node<'a>,
+    body: Node<'a>,
+}
+
+/// Finds every function, method, constructor, lambda and closure in a tree,
+/// outermost first.
+fn collect_functions<'a>(
+    root: Node<'a>,
+    source: &str,
+    config: &LanguageConfig,
+) -> Vec<FunctionNode<'a>> {
+    let mut functions = Vec::new();
+    let mut scope = Vec::new();
+    collect_functions_recursive(root, source, config, &mut scope, &mut functions);
+    functions
+}
+
+fn collect_functions_recursive<'a>(
+    node: Node<'a>,
     source: &str,
     config: &LanguageConfig,
-    results: &mut Vec<CfgResult>,
+    scope: &mut Vec<String>,
+    functions: &mut Vec<FunctionNode<'a>>,
 ) {
-    if node.kind() == config.get_function_node_type() {
-        // Extract function name
-        let function_name = extract_function_name(node, source, config);
-
-        // Find the body block
-        if let Some(body_node) = find_body_node(node, config) {
-            // Build CFG from the body
-            let cfg = build_cfg_from_block(body_node, source);
-
-            // Create result with both representations
-            let result = CfgResult {
-                function_name,
-                mermaid: cfg.to_mermaid(),
-                jsonl: cfg.to_jsonl(),
-            };
-
-            results.push(result);
+    let scope_name = if config.is_function_node(node.kind()) {
+        // A C++ out-of-class definition names its own scope: `Shape::area`
+        let qualified_name = extract_function_name(node, source, config);
+        if let Some(body) = find_body_node(node, config) {
+            let qualname = scope
+                .iter()
+                .chain(std::iter::once(&qualified_name))
+                .cloned()
+                .collect::<Vec<_>>()
+                .join("::");
+            let name = qualified_name.rsplit("::").next().unwrap_or_default();
+            functions.push(FunctionNode {
+                name: name.to_string(),
+                qualname,
+                node,
+                body,
+            });
         }
-    }
+        Some(qualified_name)
+    } else {
+        enclosing_scope_name(node, source)
+    };
+
+    let pushed = scope_name.is_some();
+    scope.extend(scope_name);
 
     // Recurse to children
     let mut cursor = node.walk();
     for child in node.named_children(&mut cursor) {
-        extract_function_cfgs(child, source, config, results);
+        collect_functions_recursive(child, source, config, scope, functions);
+    }
+
+    if pushed {
+        scope.pop();
     }
 }
 
+/// Name of a type, module or namespace that qualifies the functions inside it.
+fn enclosing_scope_name(node: Node, source: &str) -> Option<String> {
+    let name = match node.kind() {
+        // Rust `impl Type` and `impl Trait for Type` qualify methods by the type
+        "impl_item" => node.child_by_field_name("type")?,
+        "trait_item"
+        | "mod_item"
+        | "class_definition"
+        | "class_declaration"
+        | "class"
+        | "interface_declaration"
+        | "enum_declaration"
+        | "record_declaration"
+        | "class_specifier"
+        | "struct_specifier"
+        | "namespace_definition" => node.child_by_field_name("name")?,
+        _ => return None,
+    };
+    let text = &source[name.start_byte()..name.end_byte()];
+    // Drop generic parameters: `impl<T> Stack<T>` qualifies as `Stack`
+    Some(text.split('<').next().unwrap_or(text).trim().to_string())
+}
+
 /// Extracts the function name from a function node.
 fn extract_function_name(node: Node, source: &str, config: &LanguageConfig) -> String {
-    let mut cursor = node.walk();
+    if let Some(name) = node.child_by_field_name("name") {
+        return source[name.start_byte()..name.end_byte()].to_string();
+    }
 
-    for child in node.named_children(&mut cursor) {
-        if config.identifier_types.contains(&child.kind()) {
-            let start = child.start_byte();
-            let end = child.end_byte();
-            return source[start..end].to_string();
+    // C/C++ names sit inside the declarator: `int *geo::make(int n)`
+    if let Some(mut declarator) = node.child_by_field_name("declarator") {
+        while let Some(inner) = declarator.child_by_field_name("declarator") {
+            declarator = inner;
+        }
+        if node.kind() == "function_definition" {
+            return source[declarator.start_byte()..declarator.end_byte()].to_string();
         }
     }
 
-    "anonymous".to_string()
+    // Anonymous functions take the name they are bound to: `const f = () => {}`
+    if let Some(parent) = node.parent() {
+        let binding = match parent.kind() {
+            "variable_declarator" => parent.child_by_field_name("name"),
+            "pair" => parent.child_by_field_name("key"),
+            _ => None,
+        };
+        if let Some(binding) = binding {
+            return source[binding.start_byte()..binding.end_byte()].to_string();
+        }
+    }
+
+    match node.kind() {
+        "closure_expression" => "<closure>".to_string(),
+        "lambda"
+        | "lambda_expression"
+        | "arrow_function"
+        | "function_expression"
+        | "generator_function" => "<lambda>".to_string(),
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                if config.identifier_types.contains(&child.kind()) {
+                    let start = child.start_byte();
+                    let end = child.end_byte();
+                    return source[start..end].to_string();
+                }
+            }
+
+            "anonymous".to_string()
+        }
+    }
 }
 
 /// Finds the body block node of a function.
+///
+/// Lambda and closure bodies may be a single expression rather than a block.
 fn find_body_node<'a>(node: Node<'a>, config: &LanguageConfig) -> Option<Node<'a>> {
+    if let Some(body) = node.child_by_field_name("body") {
+        return Some(body);
+    }
+
     let mut cursor = node.walk();
 
     for child in node.named_children(&mut cursor) {
@@ -145,22 +263,15 @@ pub fn generate_basic_blocks<P: AsRef<Path>>(path: P) -> Result<Vec<BasicBlockRe
 
     let root_node = tree.root_node();
     let mut results = Vec::new();
-    let mut cursor = root_node.walk();
-
-    for node in root_node.named_children(&mut cursor) {
-        if node.kind() == config.get_function_node_type() {
-            let function_name = extract_function_name(node, &source, &config);
 
-            if let Some(body_node) = find_body_node(node, &config) {
-                let bb_graph = build_basic_blocks_from_block(body_node, &source);
-                let jsonl = bb_graph.to_jsonl();
+    for function in collect_functions(root_node, &source, &config) {
+        let bb_graph = build_basic_blocks_from_block(function.body, &source);
+        let jsonl = bb_graph.to_jsonl();
 
-                results.push(BasicBlockResult {
-                    function_name,
-                    jsonl,
-                });
-            }
-        }
+        results.push(BasicBlockResult {
+            function_name: function.name,
+            jsonl,
+        });
     }
 
     Ok(results)
@@ -169,6 +280,15 @@ pub fn generate_basic_blocks<P: AsRef<Path>>(path: P) -> Result<Vec<BasicBlockRe
 /// Generate language-neutral IR for all functions in a file.
 /// Implements CFG-11: Serialize to a language-neutral IR.
 pub fn generate_cfg_ir<P: AsRef<Path>>(path: P) -> Result<Vec<FunctionCFGIR>, NTreeError> {
+    generate_cfg_ir_with_options(path, &CfgOptions::default())
+}
+
+/// Generate language-neutral IR for all functions in a file, with optional
+/// CFG expansions.
+pub fn generate_cfg_ir_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &CfgOptions,
+) -> Result<Vec<FunctionCFGIR>, NTreeError> {
     let path_ref = path.as_ref();
     let source = read_file(path_ref)?;
     let config = detect_language_config(path_ref)?;
@@ -186,24 +306,44 @@ pub fn generate_cfg_ir<P: AsRef<Path>>(path: P) -> Result<Vec<FunctionCFGIR>, NT
     let source_file = path.as_ref().to_string_lossy().to_string();
     let root_node = tree.root_node();
     let mut results = Vec::new();
-    let mut cursor = root_node.walk();
-
-    for node in root_node.named_children(&mut cursor) {
-        if node.kind() == config.get_function_node_type() {
-            let function_name = extract_function_name(node, &source, &config);
 
-            if let Some(body_node) = find_body_node(node, &config) {
-                let cfg = build_cfg_from_block(body_node, &source);
-                let ir =
-                    CFGToIRConverter::convert_to_ir(&cfg, function_name, Some(source_file.clone()));
-                results.push(ir);
-            }
-        }
+    for function in collect_functions(root_node, &source, &config) {
+        let cfg = build_cfg_with_options(function.body, &source, options);
+        let ir = CFGToIRConverter::convert_to_ir(&cfg, function.name, Some(source_file.clone()));
+        results.push(ir.with_qualname(function.qualname));
     }
 
     Ok(results)
 }
 
+/// Compute a value from the syntax tree of each function in a file, given the
+/// function's name, node and body and the file's source. Values come in the
+/// order of the functions of [`generate_cfg_ir`].
+pub(crate) fn map_functions<P, T, F>(path: P, mut f: F) -> Result<Vec<T>, NTreeError>
+where
+    P: AsRef<Path>,
+    F: FnMut(&str, Node, Node, &str) -> T,
+{
+    let path_ref = path.as_ref();
+    let source = read_file(path_ref)?;
+    let config = detect_language_config(path_ref)?;
+
+    let mut parser = Parser::new();
+    parser
+        .set_language(&config.language)
+        .map_err(|e| NTreeError::ParseError(format!("Failed to set language: {:?}", e)))?;
+
+    let tree = match parser.parse(&source, None) {
+        Some(tree) => tree,
+        None => return Err(NTreeError::ParseError("Failed to parse file".to_string())),
+    };
+
+    Ok(collect_functions(tree.root_node(), &source, &config)
+        .into_iter()
+        .map(|function| f(&function.name, function.node, function.body, &source))
+        .collect())
+}
+
 /// Generate and serialize CFG IR to JSONL format.
 pub fn generate_cfg_ir_jsonl<P: AsRef<Path>>(path: P) -> Result<String, NTreeError> {
     let function_irs = generate_cfg_ir(path)?;
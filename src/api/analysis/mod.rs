@@ -4,9 +4,10 @@ pub mod incremental;
 pub mod interprocedural;
 pub mod options;
 
+pub use crate::extractors::CfgOptions;
 pub use cfg::{
-    generate_basic_blocks, generate_cfg_ir, generate_cfg_ir_jsonl, generate_cfgs, generate_cfgs_v2,
-    BasicBlockResult, CfgResult,
+    generate_basic_blocks, generate_cfg_ir, generate_cfg_ir_jsonl, generate_cfg_ir_with_options,
+    generate_cfgs, generate_cfgs_v2, generate_cfgs_with_options, BasicBlockResult, CfgResult,
 };
 pub use incremental::{
     IncrementalAnalysisOptions, IncrementalAnalyzer, IncrementalResult, PerformanceMetrics,
@@ -1,3 +1,6 @@
+use crate::extractors::CfgOptions;
+use std::path::PathBuf;
+
 /// Configuration options for source code analysis.
 #[derive(Debug, Clone, PartialEq)]
 pub struct AnalysisOptions {
@@ -21,6 +24,16 @@ pub struct AnalysisOptions {
     pub decision_tree_mapping: bool,
     /// Enable def-use chain analysis
     pub def_use_chains: bool,
+    /// Enable dominator, post-dominator and control dependence analysis
+    pub dominance_analysis: bool,
+    /// Enable static single assignment form construction
+    pub ssa_construction: bool,
+    /// Enable Halstead metrics and Maintainability Index computation
+    pub halstead_analysis: bool,
+    /// Directories searched for quoted C/C++ includes after the including file's directory
+    pub include_dirs: Vec<PathBuf>,
+    /// Split short-circuit conditions and ternaries into separate CFG condition nodes
+    pub short_circuit_expansion: bool,
 }
 
 impl Default for AnalysisOptions {
@@ -37,6 +50,11 @@ impl Default for AnalysisOptions {
             variable_lifecycle_tracking: true,
             decision_tree_mapping: true,
             def_use_chains: true,
+            dominance_analysis: true,
+            ssa_construction: true,
+            halstead_analysis: true,
+            include_dirs: Vec::new(),
+            short_circuit_expansion: false,
         }
     }
 }
@@ -55,6 +73,11 @@ impl AnalysisOptions {
             variable_lifecycle_tracking: false,
             decision_tree_mapping: false,
             def_use_chains: false,
+            dominance_analysis: false,
+            ssa_construction: false,
+            halstead_analysis: false,
+            include_dirs: Vec::new(),
+            short_circuit_expansion: false,
         }
     }
 
@@ -71,6 +94,11 @@ impl AnalysisOptions {
             variable_lifecycle_tracking: false,
             decision_tree_mapping: false,
             def_use_chains: false,
+            dominance_analysis: false,
+            ssa_construction: false,
+            halstead_analysis: false,
+            include_dirs: Vec::new(),
+            short_circuit_expansion: false,
         }
     }
 
@@ -85,5 +113,22 @@ impl AnalysisOptions {
             || self.variable_lifecycle_tracking
             || self.decision_tree_mapping
             || self.def_use_chains
+            || self.dominance_analysis
+            || self.ssa_construction
+            || self.halstead_analysis
+    }
+
+    /// Check if any analysis that runs on CFG IR is enabled.
+    pub(crate) fn needs_cfg_ir(&self) -> bool {
+        self.complexity_analysis
+            || self.dominance_analysis
+            || self.loop_analysis
+            || self.ssa_construction
+            || self.halstead_analysis
+    }
+
+    /// Options for the CFG builder.
+    pub fn cfg_options(&self) -> CfgOptions {
+        CfgOptions::new().with_short_circuit(self.short_circuit_expansion)
     }
 }
@@ -96,6 +96,44 @@ impl SourceCode {
         self
     }
 
+    /// Configure dominator, post-dominator and control dependence analysis
+    /// (enabled by default).
+    pub fn with_dominance_analysis(mut self, enabled: bool) -> Self {
+        self.options.dominance_analysis = enabled;
+        self
+    }
+
+    /// Configure static single assignment form construction (enabled by default).
+    pub fn with_ssa_construction(mut self, enabled: bool) -> Self {
+        self.options.ssa_construction = enabled;
+        self
+    }
+
+    /// Configure Halstead metrics and Maintainability Index computation
+    /// (enabled by default).
+    pub fn with_halstead_analysis(mut self, enabled: bool) -> Self {
+        self.options.halstead_analysis = enabled;
+        self
+    }
+
+    /// Configure short-circuit expansion of CFG conditions (disabled by default).
+    ///
+    /// Splits `&&`/`||` conditions and ternaries into one condition node per
+    /// operand, so cyclomatic complexity counts each of them as a decision.
+    pub fn with_short_circuit_expansion(mut self, enabled: bool) -> Self {
+        self.options.short_circuit_expansion = enabled;
+        self
+    }
+
+    /// Add a directory to search for quoted C/C++ includes.
+    ///
+    /// Directories are searched in the order they are added, after the
+    /// directory of the including file.
+    pub fn with_include_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.options.include_dirs.push(dir.as_ref().to_path_buf());
+        self
+    }
+
     /// Use minimal analysis configuration (only complexity and CFG).
     pub fn minimal(mut self) -> Self {
         self.options = AnalysisOptions::minimal();
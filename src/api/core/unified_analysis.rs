@@ -1,10 +1,10 @@
-use crate::analyzers::ComplexityResult;
+use crate::analyzers::{ComplexityResult, DominanceResult, LoopForest};
 use crate::api::analysis::analysis_runner::AnalysisRunner;
 use crate::api::analysis::AnalysisOptions;
 use crate::api::analysis::{BasicBlockResult, CfgResult};
 use crate::api::results::workspace_methods::{WorkspaceMethods, WorkspaceStats};
 use crate::core::NTreeError;
-use crate::models::FunctionSpan;
+use crate::models::{FunctionSSAIR, FunctionSpan, HalsteadResult};
 use crate::storage::{CallGraph, FileRecord, NameResolver, SymbolStore};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -16,6 +16,14 @@ pub struct AnalysisResult {
     pub(crate) complexity_data: Vec<ComplexityResult>,
     pub(crate) cfg_data: Vec<CfgResult>,
     pub(crate) basic_block_data: Vec<BasicBlockResult>,
+    #[serde(default)]
+    pub(crate) dominance_data: Vec<DominanceResult>,
+    #[serde(default)]
+    pub(crate) loop_data: Vec<LoopForest>,
+    #[serde(default)]
+    pub(crate) ssa_data: Vec<FunctionSSAIR>,
+    #[serde(default)]
+    pub(crate) halstead_data: Vec<HalsteadResult>,
     pub(crate) function_data: Vec<FunctionSpan>,
     // Data flow analysis results
     pub(crate) data_flow_graphs: Vec<crate::models::DataFlowGraph>,
@@ -32,6 +40,9 @@ pub struct AnalysisResult {
     // Call graph and resolution data
     pub(crate) call_graph: CallGraph,
     pub(crate) name_resolver: Option<NameResolver>,
+    // Include directories used to resolve quoted C/C++ includes
+    #[serde(default)]
+    pub(crate) include_dirs: Vec<PathBuf>,
 }
 
 impl AnalysisResult {
@@ -54,6 +65,10 @@ impl AnalysisResult {
             complexity_data: Vec::new(),
             cfg_data: Vec::new(),
             basic_block_data: Vec::new(),
+            dominance_data: Vec::new(),
+            loop_data: Vec::new(),
+            ssa_data: Vec::new(),
+            halstead_data: Vec::new(),
             function_data: Vec::new(),
             data_flow_graphs: Vec::new(),
             variable_lifecycles: crate::models::VariableLifecycleSet::new(),
@@ -67,23 +82,47 @@ impl AnalysisResult {
             is_workspace_mode: false,
             call_graph: CallGraph::new(),
             name_resolver: None,
+            include_dirs: options.include_dirs.clone(),
         };
 
         // Run single file analyses
         result.function_data = AnalysisRunner::run_function_extraction(&file_path)?;
 
         if options.cfg_generation {
-            result.cfg_data = AnalysisRunner::run_cfg_generation(&file_path)?;
-        }
-
-        if options.complexity_analysis {
-            result.complexity_data = AnalysisRunner::run_complexity_analysis(&file_path)?;
+            result.cfg_data =
+                AnalysisRunner::run_cfg_generation(&file_path, &options.cfg_options())?;
         }
 
         if options.basic_blocks {
             result.basic_block_data = AnalysisRunner::run_basic_block_generation(&file_path)?;
         }
 
+        // Analyses on CFG IR share one build of it
+        if options.needs_cfg_ir() {
+            let cfg_ir = AnalysisRunner::run_cfg_ir_generation(&file_path, &options.cfg_options())?;
+
+            if options.complexity_analysis {
+                result.complexity_data =
+                    AnalysisRunner::run_complexity_analysis(&file_path, &cfg_ir)?;
+            }
+
+            if options.dominance_analysis {
+                result.dominance_data = AnalysisRunner::run_dominance_analysis(&cfg_ir)?;
+            }
+
+            if options.loop_analysis {
+                result.loop_data = AnalysisRunner::run_loop_analysis(&cfg_ir)?;
+            }
+
+            if options.ssa_construction {
+                result.ssa_data = AnalysisRunner::run_ssa_construction(&cfg_ir)?;
+            }
+
+            if options.halstead_analysis {
+                result.halstead_data = AnalysisRunner::run_halstead_analysis(&file_path, &cfg_ir)?;
+            }
+        }
+
         // Data flow analyses for single file
         if options.data_flow_analysis {
             result.data_flow_graphs = AnalysisRunner::run_data_flow_analysis(&file_path)?;
@@ -99,6 +138,18 @@ impl AnalysisResult {
         // Extract symbols using language-specific extractors
         use crate::api::extractors::language_extractors::LanguageExtractors;
         LanguageExtractors::extract_symbols(&file_path, &mut result.symbol_store)?;
+        AnalysisRunner::record_function_facts(
+            &file_path,
+            &mut result.symbol_store,
+            &result.function_data,
+            &result.complexity_data,
+            &result.halstead_data,
+        );
+        result.call_graph = AnalysisRunner::run_call_graph_construction(
+            std::slice::from_ref(&file_path),
+            &result.symbol_store,
+            None,
+        )?;
         Ok(result)
     }
     /// Workspace analysis.
@@ -110,6 +161,10 @@ impl AnalysisResult {
             complexity_data: Vec::new(),
             cfg_data: Vec::new(),
             basic_block_data: Vec::new(),
+            dominance_data: Vec::new(),
+            loop_data: Vec::new(),
+            ssa_data: Vec::new(),
+            halstead_data: Vec::new(),
             function_data: Vec::new(),
             data_flow_graphs: Vec::new(),
             variable_lifecycles: crate::models::VariableLifecycleSet::new(),
@@ -123,6 +178,7 @@ impl AnalysisResult {
             is_workspace_mode: true,
             call_graph: CallGraph::new(),
             name_resolver: None,
+            include_dirs: options.include_dirs.clone(),
         };
 
         // Workspace analysis
@@ -134,23 +190,22 @@ impl AnalysisResult {
         result.file_records = files;
         result.files_by_language = by_lang;
         result.workspace_stats = Some(WorkspaceMethods::get_workspace_stats(&result.file_records));
+        result.name_resolver = Some(WorkspaceMethods::build_name_resolver(&result.file_records)?);
+        let file_paths: Vec<PathBuf> = result.file_records.iter().map(|f| f.path.clone()).collect();
+        result.call_graph = AnalysisRunner::run_call_graph_construction(
+            &file_paths,
+            &result.symbol_store,
+            result.name_resolver.as_ref(),
+        )?;
 
         // Run CFG generation for workspace (if enabled)
+        let cfg_options = options.cfg_options();
         if options.cfg_generation {
             for file_record in &result.file_records {
-                if let Ok(mut file_cfgs) = AnalysisRunner::run_cfg_generation(&file_record.path) {
-                    result.cfg_data.append(&mut file_cfgs);
-                }
-            }
-        }
-
-        // Run complexity analysis for workspace (if enabled)
-        if options.complexity_analysis {
-            for file_record in &result.file_records {
-                if let Ok(mut file_complexity) =
-                    AnalysisRunner::run_complexity_analysis(&file_record.path)
+                if let Ok(mut file_cfgs) =
+                    AnalysisRunner::run_cfg_generation(&file_record.path, &cfg_options)
                 {
-                    result.complexity_data.append(&mut file_complexity);
+                    result.cfg_data.append(&mut file_cfgs);
                 }
             }
         }
@@ -166,6 +221,66 @@ impl AnalysisResult {
             }
         }
 
+        // Run analyses on CFG IR for workspace, building it once per file
+        if options.needs_cfg_ir() {
+            for file_record in &result.file_records {
+                let Ok(cfg_ir) =
+                    AnalysisRunner::run_cfg_ir_generation(&file_record.path, &cfg_options)
+                else {
+                    continue;
+                };
+
+                let mut file_complexity = Vec::new();
+                if options.complexity_analysis {
+                    if let Ok(complexity) =
+                        AnalysisRunner::run_complexity_analysis(&file_record.path, &cfg_ir)
+                    {
+                        file_complexity = complexity;
+                    }
+                }
+
+                if options.dominance_analysis {
+                    if let Ok(mut file_dominance) = AnalysisRunner::run_dominance_analysis(&cfg_ir)
+                    {
+                        result.dominance_data.append(&mut file_dominance);
+                    }
+                }
+
+                if options.loop_analysis {
+                    if let Ok(mut file_loops) = AnalysisRunner::run_loop_analysis(&cfg_ir) {
+                        result.loop_data.append(&mut file_loops);
+                    }
+                }
+
+                if options.ssa_construction {
+                    if let Ok(mut file_ssa) = AnalysisRunner::run_ssa_construction(&cfg_ir) {
+                        result.ssa_data.append(&mut file_ssa);
+                    }
+                }
+
+                let mut file_halstead = Vec::new();
+                if options.halstead_analysis {
+                    if let Ok(halstead) =
+                        AnalysisRunner::run_halstead_analysis(&file_record.path, &cfg_ir)
+                    {
+                        file_halstead = halstead;
+                    }
+                }
+
+                let file_functions =
+                    AnalysisRunner::run_function_extraction(&file_record.path).unwrap_or_default();
+                AnalysisRunner::record_function_facts(
+                    &file_record.path,
+                    &mut result.symbol_store,
+                    &file_functions,
+                    &file_complexity,
+                    &file_halstead,
+                );
+                result.complexity_data.append(&mut file_complexity);
+                result.halstead_data.append(&mut file_halstead);
+            }
+        }
+
         // Workspace data flow analysis (if enabled)
         if let Some(workspace_data_flow) = WorkspaceMethods::analyze_workspace_data_flow(
             &workspace_path,
@@ -0,0 +1,134 @@
+use crate::analyzers::language_specific::{
+    c::CCallExtractor, cpp::CppCallExtractor, java::JavaCallExtractor,
+    javascript::JavaScriptCallExtractor, python::PythonCallExtractor, rust::RustCallExtractor,
+    typescript::TypeScriptCallExtractor,
+};
+use crate::core::{node_coordinates, NTreeError};
+use crate::language::SupportedLanguage;
+use crate::models::FunctionSpan;
+use crate::storage::{CallEdge, SymbolId, SymbolStore};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tree_sitter::Node;
+
+/// Signature shared by the language-specific call site extractors.
+type CallSiteExtractor = fn(Node, &str, &SymbolId) -> Result<Vec<CallEdge>, NTreeError>;
+
+/// Nodes JavaScript/TypeScript function symbols are recorded on; arrow and
+/// function expressions take the span of their declarator or object property.
+const JAVASCRIPT_FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "generator_function_declaration",
+    "method_definition",
+    "variable_declarator",
+    "pair",
+];
+
+/// Language-specific call site extraction dispatcher.
+pub struct CallExtractors;
+
+impl CallExtractors {
+    /// Extract unresolved call sites from every function in a file.
+    ///
+    /// Callers are the file's symbols in `symbol_store`, matched to function
+    /// nodes by span, so symbols must be extracted first.
+    pub fn extract_calls(
+        file_path: &PathBuf,
+        symbol_store: &SymbolStore,
+    ) -> Result<Vec<CallEdge>, NTreeError> {
+        // Explicit language routing - no catch-all patterns
+        match SupportedLanguage::from_path(file_path)? {
+            SupportedLanguage::Rust => Self::extract_with(
+                file_path,
+                symbol_store,
+                &["function_item"],
+                RustCallExtractor::extract_call_sites,
+            ),
+            SupportedLanguage::Python => Self::extract_with(
+                file_path,
+                symbol_store,
+                &["function_definition"],
+                PythonCallExtractor::extract_call_sites,
+            ),
+            SupportedLanguage::JavaScript => Self::extract_with(
+                file_path,
+                symbol_store,
+                JAVASCRIPT_FUNCTION_KINDS,
+                JavaScriptCallExtractor::extract_call_sites,
+            ),
+            SupportedLanguage::TypeScript => Self::extract_with(
+                file_path,
+                symbol_store,
+                JAVASCRIPT_FUNCTION_KINDS,
+                TypeScriptCallExtractor::extract_call_sites,
+            ),
+            SupportedLanguage::Java => Self::extract_with(
+                file_path,
+                symbol_store,
+                &["method_declaration", "constructor_declaration"],
+                JavaCallExtractor::extract_call_sites,
+            ),
+            SupportedLanguage::C => Self::extract_with(
+                file_path,
+                symbol_store,
+                &["function_definition"],
+                CCallExtractor::extract_call_sites,
+            ),
+            SupportedLanguage::Cpp => Self::extract_with(
+                file_path,
+                symbol_store,
+                &["function_definition"],
+                CppCallExtractor::extract_call_sites,
+            ),
+        }
+    }
+
+    /// Parse a file and run a call site extractor over each function body.
+    fn extract_with(
+        file_path: &PathBuf,
+        symbol_store: &SymbolStore,
+        function_kinds: &[&str],
+        extractor: CallSiteExtractor,
+    ) -> Result<Vec<CallEdge>, NTreeError> {
+        let root = match crate::create_tree_from_file(file_path) {
+            Ok(root) => root,
+            Err(_) => return Ok(Vec::new()),
+        };
+        let source = std::fs::read_to_string(file_path)?;
+        let callers: HashMap<(usize, usize, usize, usize), &SymbolId> = symbol_store
+            .get_file_symbols(file_path)
+            .into_iter()
+            .filter_map(|symbol| Some((FunctionSpan::parse_span(&symbol.span)?, &symbol.id)))
+            .collect();
+
+        let mut call_edges = Vec::new();
+        let mut functions = Vec::new();
+        Self::find_functions(root, function_kinds, &callers, &mut functions);
+        for (function, caller) in functions {
+            let body = function.child_by_field_name("body").unwrap_or(function);
+            call_edges.extend(extractor(body, &source, caller)?);
+        }
+        Ok(call_edges)
+    }
+
+    /// Find function nodes that have a symbol, without descending into them.
+    ///
+    /// Calls in nested functions and closures belong to the enclosing symbol.
+    fn find_functions<'t, 's>(
+        node: Node<'t>,
+        function_kinds: &[&str],
+        callers: &HashMap<(usize, usize, usize, usize), &'s SymbolId>,
+        functions: &mut Vec<(Node<'t>, &'s SymbolId)>,
+    ) {
+        if function_kinds.contains(&node.kind()) {
+            if let Some(caller) = callers.get(&node_coordinates(node)) {
+                functions.push((node, caller));
+                return;
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            Self::find_functions(child, function_kinds, callers, functions);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::core::NTreeError;
-use crate::storage::{ExportEdge, ImportEdge, SymbolStore};
+use crate::storage::{CallEdge, ExportEdge, ImportEdge, SymbolStore};
 use std::path::PathBuf;
 
 /// Clean language-aware extraction dispatcher.
@@ -22,4 +22,13 @@ impl LanguageExtractors {
         use super::dependency_extractors::DependencyExtractors;
         DependencyExtractors::extract_dependencies(file_path)
     }
+
+    /// Extract unresolved call sites from every function in a file.
+    pub fn extract_calls(
+        file_path: &PathBuf,
+        symbol_store: &SymbolStore,
+    ) -> Result<Vec<CallEdge>, NTreeError> {
+        use super::call_extractors::CallExtractors;
+        CallExtractors::extract_calls(file_path, symbol_store)
+    }
 }
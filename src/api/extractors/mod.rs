@@ -1,3 +1,4 @@
+pub mod call_extractors;
 pub mod dependency_extractors;
 pub mod language_extractors;
 pub mod symbol_extractors;
@@ -6,8 +6,9 @@ pub mod results;
 
 pub use analysis::{
     analyze_exceptional_control_flow, analyze_interprocedural_cfg, compute_program_reachability,
-    generate_basic_blocks, generate_cfg_ir, generate_cfg_ir_jsonl, generate_cfgs, generate_cfgs_v2,
-    generate_summary_edges, AnalysisOptions, BasicBlockResult, CfgResult,
+    generate_basic_blocks, generate_cfg_ir, generate_cfg_ir_jsonl, generate_cfg_ir_with_options,
+    generate_cfgs, generate_cfgs_v2, generate_cfgs_with_options, generate_summary_edges,
+    AnalysisOptions, BasicBlockResult, CfgOptions, CfgResult,
     IncrementalAnalysisOptions, IncrementalAnalyzer, IncrementalResult, InterproceduralOptions,
     InterproceduralResult, InterproceduralStats, PerformanceMetrics,
 };
@@ -15,5 +16,6 @@ pub use core::{AnalysisResult, SourceCode};
 pub use export::{functions_to_jsonl, items_to_jsonl};
 pub use results::{
     list_functions, list_top_level_items, BasicBlockResultSet, CfgResultSet, ComplexityResultSet,
-    FunctionResultSet, WorkspaceStats,
+    DominanceResultSet, FunctionResultSet, HalsteadResultSet, LoopResultSet, SsaResultSet,
+    WorkspaceStats,
 };
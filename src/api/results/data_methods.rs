@@ -1,7 +1,7 @@
 use crate::api::core::unified_analysis::AnalysisResult;
 use crate::api::extractors::language_extractors::LanguageExtractors;
 use crate::core::NTreeError;
-use crate::storage::DataSet;
+use crate::storage::{DataSet, ImportEdge, ModuleNormalizer};
 
 /// Data export methods for AnalysisResult.
 impl AnalysisResult {
@@ -29,7 +29,8 @@ impl AnalysisResult {
         // Extract import/export edges from files
         for file in &self.file_records {
             match LanguageExtractors::extract_dependencies(&file.path) {
-                Ok((imports, exports)) => {
+                Ok((mut imports, exports)) => {
+                    self.resolve_includes(&mut imports);
                     for import in imports {
                         dataset.add_import_edge(import);
                     }
@@ -44,6 +45,26 @@ impl AnalysisResult {
         Ok(dataset)
     }
 
+    /// Point quoted C/C++ includes that resolve to a header file at that file.
+    ///
+    /// Resolved includes then share a module ID with the header itself, so
+    /// header cycles show up in the dependency graph. Unresolved includes keep
+    /// their quotes.
+    fn resolve_includes(&self, imports: &mut [ImportEdge]) {
+        for import in imports.iter_mut() {
+            let header = import
+                .target_module
+                .strip_prefix('"')
+                .and_then(|target| target.strip_suffix('"'));
+            let resolved = header.and_then(|header| {
+                ModuleNormalizer::resolve_include(header, &import.source_file, &self.include_dirs)
+            });
+            if let Some(resolved) = resolved {
+                import.target_module = resolved.to_string_lossy().to_string();
+            }
+        }
+    }
+
     /// Export complete dataset to structured JSONL.
     pub fn to_dataset_jsonl(&self) -> Result<String, NTreeError> {
         let dataset = self.export_dataset()?;
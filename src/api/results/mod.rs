@@ -20,5 +20,8 @@ pub use data_flow_result_sets::{
 pub use function_results::{BasicBlockResultSet, FunctionResultSet};
 pub use functions::list_functions;
 pub use items::list_top_level_items;
-pub use result_sets::{CfgResultSet, ComplexityResultSet};
+pub use result_sets::{
+    CfgResultSet, ComplexityResultSet, DominanceResultSet, HalsteadResultSet, LoopResultSet,
+    SsaResultSet,
+};
 pub use workspace_methods::WorkspaceStats;
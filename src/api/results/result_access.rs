@@ -6,7 +6,10 @@ use super::data_flow_result_sets::{
     VariableLifecycleResultSet,
 };
 use super::function_results::{BasicBlockResultSet, FunctionResultSet};
-use super::result_sets::{CfgResultSet, ComplexityResultSet};
+use super::result_sets::{
+    CfgResultSet, ComplexityResultSet, DominanceResultSet, HalsteadResultSet, LoopResultSet,
+    SsaResultSet,
+};
 use super::symbol_methods::SymbolResultSet;
 use super::workspace_methods::WorkspaceStats;
 use crate::api::core::unified_analysis::AnalysisResult;
@@ -31,6 +34,34 @@ impl AnalysisResult {
         }
     }
 
+    /// Get dominator, post-dominator and control dependence results.
+    pub fn dominance(&self) -> DominanceResultSet<'_> {
+        DominanceResultSet {
+            data: &self.dominance_data,
+        }
+    }
+
+    /// Get natural loop and loop-nesting results.
+    pub fn loops(&self) -> LoopResultSet<'_> {
+        LoopResultSet {
+            data: &self.loop_data,
+        }
+    }
+
+    /// Get static single assignment forms.
+    pub fn ssa(&self) -> SsaResultSet<'_> {
+        SsaResultSet {
+            data: &self.ssa_data,
+        }
+    }
+
+    /// Get Halstead metrics and Maintainability Index results.
+    pub fn halstead(&self) -> HalsteadResultSet<'_> {
+        HalsteadResultSet {
+            data: &self.halstead_data,
+        }
+    }
+
     /// Get function information.
     pub fn functions(&self) -> FunctionResultSet {
         FunctionResultSet {
@@ -1,6 +1,8 @@
-use crate::analyzers::ComplexityResult;
+use crate::analyzers::{ComplexityResult, DominanceResult, LoopForest};
 use crate::api::analysis::CfgResult;
 use crate::core::NTreeError;
+use crate::models::FunctionSSAIR;
+use crate::models::HalsteadResult;
 
 /// Filtered view of complexity analysis results.
 #[derive(Debug)]
@@ -17,6 +19,14 @@ impl<'a> ComplexityResultSet<'a> {
             .collect()
     }
 
+    /// Filter complexity results by minimum cognitive complexity.
+    pub fn filter_by_cognitive(self, min_cognitive: u32) -> Vec<&'a ComplexityResult> {
+        self.data
+            .iter()
+            .filter(|result| result.cognitive >= min_cognitive)
+            .collect()
+    }
+
     /// Filter complexity results by function name pattern.
     pub fn filter_by_name(self, pattern: &str) -> Vec<&'a ComplexityResult> {
         self.data
@@ -124,3 +134,202 @@ impl<'a> CfgResultSet<'a> {
         self.data.is_empty()
     }
 }
+
+/// Filtered view of dominance and control dependence results.
+#[derive(Debug)]
+pub struct DominanceResultSet<'a> {
+    pub(crate) data: &'a [DominanceResult],
+}
+
+impl<'a> DominanceResultSet<'a> {
+    /// Filter dominance results by function name pattern.
+    pub fn filter_by_name(self, pattern: &str) -> Vec<&'a DominanceResult> {
+        self.data
+            .iter()
+            .filter(|result| result.function.contains(pattern))
+            .collect()
+    }
+
+    /// Get dominance results for a specific function by its qualified name,
+    /// e.g. `Parser::parse`.
+    pub fn for_function(self, qualname: &str) -> Option<&'a DominanceResult> {
+        self.data.iter().find(|result| result.qualname == qualname)
+    }
+
+    /// Export to JSONL format.
+    pub fn to_jsonl(&self) -> String {
+        self.data.iter().map(|result| result.to_jsonl()).collect()
+    }
+
+    /// Get all results as a slice.
+    pub fn all(&self) -> &'a [DominanceResult] {
+        self.data
+    }
+
+    /// Get number of results.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+/// Filtered view of loop-nesting forests.
+#[derive(Debug)]
+pub struct LoopResultSet<'a> {
+    pub(crate) data: &'a [LoopForest],
+}
+
+impl<'a> LoopResultSet<'a> {
+    /// Filter loop forests by function name pattern.
+    pub fn filter_by_name(self, pattern: &str) -> Vec<&'a LoopForest> {
+        self.data
+            .iter()
+            .filter(|result| result.function.contains(pattern))
+            .collect()
+    }
+
+    /// Get the loop forest of a specific function by its qualified name,
+    /// e.g. `Parser::parse`.
+    pub fn for_function(self, qualname: &str) -> Option<&'a LoopForest> {
+        self.data.iter().find(|result| result.qualname == qualname)
+    }
+
+    /// Get functions with loops entered other than through their header.
+    pub fn with_irreducible_flow(self) -> Vec<&'a LoopForest> {
+        self.data
+            .iter()
+            .filter(|result| !result.is_reducible())
+            .collect()
+    }
+
+    /// Export to JSONL format.
+    pub fn to_jsonl(&self) -> String {
+        self.data.iter().map(|result| result.to_jsonl()).collect()
+    }
+
+    /// Get all results as a slice.
+    pub fn all(&self) -> &'a [LoopForest] {
+        self.data
+    }
+
+    /// Get number of results.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+/// Filtered view of Halstead metrics and Maintainability Index results.
+#[derive(Debug)]
+pub struct HalsteadResultSet<'a> {
+    pub(crate) data: &'a [HalsteadResult],
+}
+
+impl<'a> HalsteadResultSet<'a> {
+    /// Filter Halstead results by function name pattern.
+    pub fn filter_by_name(self, pattern: &str) -> Vec<&'a HalsteadResult> {
+        self.data
+            .iter()
+            .filter(|result| result.function.contains(pattern))
+            .collect()
+    }
+
+    /// Get the Halstead results of a specific function by its qualified name,
+    /// e.g. `Parser::parse`.
+    pub fn for_function(self, qualname: &str) -> Option<&'a HalsteadResult> {
+        self.data.iter().find(|result| result.qualname == qualname)
+    }
+
+    /// Get results with a Maintainability Index below a threshold.
+    pub fn below_maintainability(self, max_index: f64) -> Vec<&'a HalsteadResult> {
+        self.data
+            .iter()
+            .filter(|result| result.maintainability_index < max_index)
+            .collect()
+    }
+
+    /// Export to JSONL format.
+    pub fn to_jsonl(&self) -> Result<String, NTreeError> {
+        let mut jsonl = String::new();
+        for result in self.data {
+            match serde_json::to_string(result) {
+                Ok(json) => {
+                    jsonl.push_str(&json);
+                    jsonl.push('\n');
+                }
+                Err(e) => {
+                    return Err(NTreeError::ParseError(format!(
+                        "JSON serialization failed: {}",
+                        e
+                    )))
+                }
+            }
+        }
+        Ok(jsonl)
+    }
+
+    /// Get all results as a slice.
+    pub fn all(&self) -> &'a [HalsteadResult] {
+        self.data
+    }
+
+    /// Get number of results.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+/// Filtered view of static single assignment forms.
+#[derive(Debug)]
+pub struct SsaResultSet<'a> {
+    pub(crate) data: &'a [FunctionSSAIR],
+}
+
+impl<'a> SsaResultSet<'a> {
+    /// Filter SSA forms by function name pattern.
+    pub fn filter_by_name(self, pattern: &str) -> Vec<&'a FunctionSSAIR> {
+        self.data
+            .iter()
+            .filter(|result| result.function_name.contains(pattern))
+            .collect()
+    }
+
+    /// Get the SSA form of a specific function by its qualified name, e.g.
+    /// `Parser::parse`.
+    pub fn for_function(self, qualname: &str) -> Option<&'a FunctionSSAIR> {
+        self.data.iter().find(|result| result.qualname == qualname)
+    }
+
+    /// Export to JSONL format.
+    pub fn to_jsonl(&self) -> String {
+        self.data.iter().map(|result| result.to_jsonl()).collect()
+    }
+
+    /// Get all results as a slice.
+    pub fn all(&self) -> &'a [FunctionSSAIR] {
+        self.data
+    }
+
+    /// Get number of results.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check if empty.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
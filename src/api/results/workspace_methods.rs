@@ -37,6 +37,24 @@ impl WorkspaceMethods {
         Ok((file_records, files_by_language))
     }
 
+    /// Build the cross-file name resolver from every file's imports and exports.
+    pub fn build_name_resolver(file_records: &[FileRecord]) -> Result<NameResolver, NTreeError> {
+        let mut imports = Vec::new();
+        let mut exports = Vec::new();
+
+        for file_record in file_records {
+            match LanguageExtractors::extract_dependencies(&file_record.path) {
+                Ok((mut file_imports, mut file_exports)) => {
+                    imports.append(&mut file_imports);
+                    exports.append(&mut file_exports);
+                }
+                Err(_) => continue, // Skip files with extraction errors
+            }
+        }
+
+        NameResolver::from_edges(&imports, &exports)
+    }
+
     /// Perform workspace-wide data flow analysis using existing infrastructure.
     pub fn analyze_workspace_data_flow(
         workspace_path: &PathBuf,
@@ -1,7 +1,9 @@
 pub mod error;
 pub mod parser;
 pub mod reader;
+pub mod span;
 
 pub use error::NTreeError;
 pub use parser::create_tree_from_file;
 pub use reader::read_file;
+pub use span::{format_node_span, node_coordinates};
@@ -0,0 +1,15 @@
+use crate::models::FunctionSpan;
+use tree_sitter::Node;
+
+/// 0-based `(start_line, start_col, end_line, end_col)` of a node.
+pub fn node_coordinates(node: Node) -> (usize, usize, usize, usize) {
+    let start = node.start_position();
+    let end = node.end_position();
+    (start.row, start.column, end.row, end.column)
+}
+
+/// Formats the span of a node as `"line:col–line:col"` using 1-based coordinates.
+pub fn format_node_span(node: Node) -> String {
+    let (start_line, start_col, end_line, end_col) = node_coordinates(node);
+    FunctionSpan::format_span(start_line, start_col, end_line, end_col)
+}
@@ -1,37 +1,60 @@
-use super::super::core::CfgContext;
-use super::super::statements::{process_break, process_continue, process_match, process_while};
+use super::super::core::{retag_branch_edges, CfgContext};
+use super::super::processors::{
+    handle_loop_expression, has_value_expressions, is_loop_node, process_statement,
+};
+use super::super::statements::{
+    is_labeled_block, process_break, process_continue, process_goto, process_match,
+};
+use crate::analyzers::language_specific::RustEarlyExitAnalyzer;
 use crate::models::ControlFlowGraph;
 use tree_sitter::Node;
 
+/// Position of a statement within an if branch.
+#[derive(Debug, Clone, Copy)]
+pub struct BranchPosition<'a> {
+    /// Condition node the branch hangs off
+    pub cond_id: usize,
+    /// Last node processed in the branch
+    pub current: usize,
+    /// Whether the statement is the first of the branch
+    pub first: bool,
+    /// "true" for then branch, "false" for else branch
+    pub edge_type: &'a str,
+}
+
 /// Handle control flow expressions in branches with proper edge connections.
 pub fn handle_control_flow_expression(
     cfg: &mut ControlFlowGraph,
     ctx: &mut CfgContext,
     stmt: Node,
     source: &str,
-    cond_id: usize,
-    current: usize,
-    first: bool,
-    _edge_type: &str, // "true" for then branch, "false" for else branch
+    position: BranchPosition,
 ) -> Option<(usize, bool)> {
+    let BranchPosition {
+        cond_id,
+        current,
+        first,
+        edge_type,
+    } = position;
     let entry = if first { cond_id } else { current };
+    let edges_before = cfg.edges.len();
 
-    match stmt.kind() {
-        "break_expression" => {
+    let exit = match stmt.kind() {
+        "break_expression" | "break_statement" => {
             let _exits = process_break(cfg, ctx, stmt, source, entry);
             None // Terminated by break
         }
-        "continue_expression" => {
+        "continue_expression" | "continue_statement" => {
             let _exits = process_continue(cfg, ctx, stmt, source, entry);
             None // Terminated by continue
         }
-        "while_expression" => {
-            let exits = process_while(cfg, ctx, stmt, source, entry);
-            if exits.is_empty() {
-                None // Terminated
-            } else {
-                Some((exits[0], false))
-            }
+        "goto_statement" => {
+            let _exits = process_goto(cfg, ctx, stmt, source, entry);
+            None // Terminated by goto
+        }
+        _ if is_loop_node(stmt) => {
+            // None when the loop never completes
+            handle_loop_expression(cfg, ctx, stmt, source, entry).map(|exit| (exit, false))
         }
         "match_expression" => {
             let exits = process_match(cfg, ctx, stmt, source, entry);
@@ -41,6 +64,48 @@ pub fn handle_control_flow_expression(
                 Some((exits[0], false))
             }
         }
+        _ if is_compound_statement(stmt) || has_rust_early_exit(stmt, source) => {
+            // None when every path through the statement jumps away
+            process_statement(cfg, ctx, stmt, source, entry).map(|exit| (exit, false))
+        }
         _ => None, // Not a control flow expression
+    };
+
+    // The branch is entered along the condition's true or false edge
+    if first {
+        retag_branch_edges(cfg, cond_id, edges_before, edge_type);
+    }
+    exit
+}
+
+/// Check for a statement with its own control flow, processed like it is
+/// outside a branch.
+pub fn is_compound_statement(stmt: Node) -> bool {
+    matches!(
+        stmt.kind(),
+        "if_statement"
+            | "try_statement"
+            | "try_with_resources_statement"
+            | "with_statement"
+            | "match_statement"
+            | "switch_statement"
+            | "labeled_statement"
+            | "assert_statement"
+            | "compound_statement"
+            | "statement_block"
+            | "block"
+    )
+}
+
+/// Check for a Rust statement with exits of its own: a `?`, a `let ... else`,
+/// a labeled block, or control flow nested in the expression it evaluates.
+pub fn has_rust_early_exit(stmt: Node, source: &str) -> bool {
+    match stmt.kind() {
+        "let_declaration" if stmt.child_by_field_name("alternative").is_some() => true,
+        "expression_statement" if stmt.named_child(0).is_some_and(is_labeled_block) => true,
+        _ => {
+            has_value_expressions(stmt)
+                || RustEarlyExitAnalyzer::contains_try_operator(stmt, source)
+        }
     }
 }
@@ -4,5 +4,7 @@ pub mod nested_if_handler;
 pub mod process_else;
 pub mod process_if;
 pub mod process_then;
+pub mod short_circuit;
 
 pub use process_if::process_if;
+pub use short_circuit::expand_short_circuits;
@@ -1,4 +1,4 @@
-use super::super::core::CfgContext;
+use super::super::core::{node_span, CfgContext};
 use super::process_if::process_if_with_edge_kind;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
@@ -24,10 +24,11 @@ pub fn handle_nested_if(
 
     if exits.len() > 1 {
         let join_id = ctx.alloc_id();
-        cfg.add_node(CfgNode::new(join_id, "join".to_string()));
+        cfg.add_node(CfgNode::new(join_id, "join".to_string()).with_span(node_span(if_node)));
         for exit in &exits {
             if *exit != join_id {
-                cfg.add_edge(CfgEdge::new(*exit, join_id, "next".to_string()));
+                let kind = ctx.join_edge_kind(cfg, *exit);
+                cfg.add_edge(CfgEdge::new(*exit, join_id, kind.to_string()));
             }
         }
         *current = join_id;
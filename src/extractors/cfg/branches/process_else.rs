@@ -1,6 +1,14 @@
-use super::super::core::{get_statement_text, is_statement_node, CfgContext};
-use super::control_flow_handler::handle_control_flow_expression;
+use super::super::core::{
+    body_statements, get_statement_text, is_statement_node, node_span, unwrap_jump_statement,
+    CfgContext, Jump,
+};
+use super::super::processors::is_loop_node;
+use super::super::statements::{process_comprehensions, process_suspension_points};
+use super::control_flow_handler::{
+    handle_control_flow_expression, has_rust_early_exit, is_compound_statement, BranchPosition,
+};
 use super::process_if::process_if;
+use crate::analyzers::language_specific::RustEarlyExitAnalyzer;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
 
@@ -12,16 +20,24 @@ pub fn process_else_branch(
     source: &str,
     cond_id: usize,
 ) -> Vec<usize> {
-    let mut cursor = else_node.walk();
+    // Java has no else clause; the alternative is the statement itself, as
+    // is a Python `elif` continuing the chain
+    let branches = match else_node.kind() {
+        "else_clause" => {
+            let mut cursor = else_node.walk();
+            else_node.named_children(&mut cursor).collect()
+        }
+        _ => vec![else_node],
+    };
 
-    for child in else_node.named_children(&mut cursor) {
+    for child in branches {
         match child.kind() {
-            "block" => {
-                return process_else_block(cfg, ctx, child, source, cond_id);
-            }
-            "if_expression" => {
+            "if_expression" | "if_statement" | "elif_clause" => {
                 return process_else_if(cfg, ctx, child, source, cond_id);
             }
+            _ if is_statement_node(child) => {
+                return process_else_block(cfg, ctx, child, source, cond_id);
+            }
             _ => {}
         }
     }
@@ -37,29 +53,66 @@ fn process_else_block(
     source: &str,
     cond_id: usize,
 ) -> Vec<usize> {
-    let mut block_cursor = block.walk();
     let mut first = true;
     let mut current = cond_id;
 
-    for stmt in block.named_children(&mut block_cursor) {
+    for stmt in body_statements(block) {
         if !is_statement_node(stmt) {
             continue;
         }
+        let stmt = unwrap_jump_statement(stmt);
 
         // Handle control flow expressions
+        let position = BranchPosition {
+            cond_id,
+            current,
+            first,
+            edge_type: "false",
+        };
         if let Some((new_current, new_first)) =
-            handle_control_flow_expression(cfg, ctx, stmt, source, cond_id, current, first, "false")
+            handle_control_flow_expression(cfg, ctx, stmt, source, position)
         {
             current = new_current;
             first = new_first;
             continue;
-        } else if matches!(stmt.kind(), "break_expression" | "continue_expression") {
+        } else if matches!(
+            stmt.kind(),
+            "break_expression"
+                | "break_statement"
+                | "continue_expression"
+                | "continue_statement"
+                | "goto_statement"
+        ) || is_loop_node(stmt)
+            || is_compound_statement(stmt)
+            || has_rust_early_exit(stmt, source)
+        {
             return vec![]; // Terminated
         }
 
+        // Awaits, yields and comprehensions run before the statement completes
+        let (entry, edge_kind) = if first {
+            (cond_id, "false")
+        } else {
+            (current, "next")
+        };
+        if let Some((_, last)) = process_suspension_points(cfg, ctx, stmt, source, entry, edge_kind)
+        {
+            current = last;
+            first = false;
+        }
+        let (entry, edge_kind) = if first {
+            (cond_id, "false")
+        } else {
+            (current, "next")
+        };
+        if let Some((_, last)) = process_comprehensions(cfg, ctx, stmt, source, entry, edge_kind) {
+            current = last;
+            first = false;
+        }
+
         let text = get_statement_text(stmt, source);
         let node_id = ctx.alloc_id();
-        cfg.add_node(CfgNode::new(node_id, text.clone()));
+        cfg.add_node(CfgNode::new(node_id, text.clone()).with_span(node_span(stmt)));
 
         if first {
             cfg.add_edge(CfgEdge::new(cond_id, node_id, "false".to_string()));
@@ -70,6 +123,20 @@ fn process_else_block(
 
         // Check for return
         if stmt.kind() == "return_expression" || text.starts_with("return") {
+            ctx.connect_jump(cfg, node_id, Jump::Return);
+            return vec![]; // Terminated
+        }
+
+        // Check for raise/throw and Rust panics
+        if matches!(stmt.kind(), "raise_statement" | "throw_statement")
+            || RustEarlyExitAnalyzer::is_panic_macro(stmt, source)
+        {
+            ctx.connect_jump(cfg, node_id, Jump::Throw);
+            return vec![]; // Terminated
+        }
+
+        // Check for `std::process::exit` and Rust functions returning `!`
+        if RustEarlyExitAnalyzer::is_diverging_call(stmt, source, &ctx.diverging_functions) {
             cfg.add_edge(CfgEdge::new(node_id, ctx.exit_id, "exit".to_string()));
             return vec![]; // Terminated
         }
@@ -94,7 +161,7 @@ fn process_else_if(
 ) -> Vec<usize> {
     // else if - treat as nested if
     let else_entry = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(else_entry, "else-entry".to_string()));
+    cfg.add_node(CfgNode::new(else_entry, "else-entry".to_string()).with_span(node_span(if_node)));
     cfg.add_edge(CfgEdge::new(cond_id, else_entry, "false".to_string()));
 
     process_if(cfg, ctx, if_node, source, else_entry)
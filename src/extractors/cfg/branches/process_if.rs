@@ -1,4 +1,4 @@
-use super::super::core::{get_if_condition, get_if_parts, CfgContext};
+use super::super::core::{get_if_condition, get_if_parts, node_span, CfgContext};
 use super::process_else::process_else_branch;
 use super::process_then::process_then_branch;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
@@ -27,8 +27,16 @@ pub fn process_if_with_edge_kind(
     // Create condition node
     let condition = get_if_condition(if_node, source);
 
+    let condition_node = if_node.child_by_field_name("condition");
+
     let cond_id = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(cond_id, format!("if ({})", condition)));
+    cfg.add_node(
+        CfgNode::new(cond_id, format!("if ({})", condition))
+            .with_span(node_span(condition_node.unwrap_or(if_node))),
+    );
+    if let Some(condition_node) = condition_node {
+        ctx.conditions.push((cond_id, node_span(condition_node)));
+    }
     cfg.add_edge(CfgEdge::new(entry, cond_id, edge_kind.to_string()));
 
     let mut exits = Vec::new();
@@ -1,6 +1,14 @@
-use super::super::core::{get_statement_text, is_statement_node, CfgContext};
-use super::control_flow_handler::handle_control_flow_expression;
+use super::super::core::{
+    body_statements, get_statement_text, is_statement_node, node_span, unwrap_jump_statement,
+    CfgContext, Jump,
+};
+use super::super::processors::is_loop_node;
+use super::super::statements::{process_comprehensions, process_suspension_points};
+use super::control_flow_handler::{
+    handle_control_flow_expression, has_rust_early_exit, is_compound_statement, BranchPosition,
+};
 use super::nested_if_handler::{handle_expression_if, handle_nested_if};
+use crate::analyzers::language_specific::RustEarlyExitAnalyzer;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
 
@@ -12,14 +20,14 @@ pub fn process_then_branch(
     source: &str,
     cond_id: usize,
 ) -> Vec<usize> {
-    let mut cursor = block.walk();
     let mut first = true;
     let mut current = cond_id;
 
-    for child in block.named_children(&mut cursor) {
+    for child in body_statements(block) {
         if !is_statement_node(child) {
             continue;
         }
+        let child = unwrap_jump_statement(child);
 
         // Handle control flow expressions
         if child.kind() == "if_expression" {
@@ -28,27 +36,65 @@ pub fn process_then_branch(
         }
 
         // Handle other control flow expressions
+        let position = BranchPosition {
+            cond_id,
+            current,
+            first,
+            edge_type: "true",
+        };
         if let Some((new_current, new_first)) =
-            handle_control_flow_expression(cfg, ctx, child, source, cond_id, current, first, "true")
+            handle_control_flow_expression(cfg, ctx, child, source, position)
         {
             current = new_current;
             first = new_first;
             continue;
-        } else if matches!(child.kind(), "break_expression" | "continue_expression") {
+        } else if matches!(
+            child.kind(),
+            "break_expression"
+                | "break_statement"
+                | "continue_expression"
+                | "continue_statement"
+                | "goto_statement"
+        ) || is_loop_node(child)
+            || is_compound_statement(child)
+            || has_rust_early_exit(child, source)
+        {
             return vec![]; // Terminated
         }
 
         // Check if expression_statement contains control flow
-        if child.kind() == "expression_statement" {
-            if handle_expression_if(cfg, ctx, child, source, cond_id, &mut current, &mut first) {
-                continue;
-            }
+        if child.kind() == "expression_statement"
+            && handle_expression_if(cfg, ctx, child, source, cond_id, &mut current, &mut first)
+        {
+            continue;
+        }
+
+        // Awaits, yields and comprehensions run before the statement completes
+        let (entry, edge_kind) = if first {
+            (cond_id, "true")
+        } else {
+            (current, "next")
+        };
+        if let Some((_, last)) =
+            process_suspension_points(cfg, ctx, child, source, entry, edge_kind)
+        {
+            current = last;
+            first = false;
+        }
+        let (entry, edge_kind) = if first {
+            (cond_id, "true")
+        } else {
+            (current, "next")
+        };
+        if let Some((_, last)) = process_comprehensions(cfg, ctx, child, source, entry, edge_kind) {
+            current = last;
+            first = false;
         }
 
         // Regular statement processing
         let text = get_statement_text(child, source);
         let node_id = ctx.alloc_id();
-        cfg.add_node(CfgNode::new(node_id, text.clone()));
+        cfg.add_node(CfgNode::new(node_id, text.clone()).with_span(node_span(child)));
 
         if first {
             cfg.add_edge(CfgEdge::new(cond_id, node_id, "true".to_string()));
@@ -59,6 +105,20 @@ pub fn process_then_branch(
 
         // Check for return
         if child.kind() == "return_expression" || text.starts_with("return") {
+            ctx.connect_jump(cfg, node_id, Jump::Return);
+            return vec![]; // Terminated
+        }
+
+        // Check for raise/throw and Rust panics
+        if matches!(child.kind(), "raise_statement" | "throw_statement")
+            || RustEarlyExitAnalyzer::is_panic_macro(child, source)
+        {
+            ctx.connect_jump(cfg, node_id, Jump::Throw);
+            return vec![]; // Terminated
+        }
+
+        // Check for `std::process::exit` and Rust functions returning `!`
+        if RustEarlyExitAnalyzer::is_diverging_call(child, source, &ctx.diverging_functions) {
             cfg.add_edge(CfgEdge::new(node_id, ctx.exit_id, "exit".to_string()));
             return vec![]; // Terminated
         }
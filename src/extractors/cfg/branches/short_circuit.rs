@@ -0,0 +1,389 @@
+use super::super::core::{
+    get_statement_text, is_nested_scope, node_span, CfgContext, LabelNormalizer,
+};
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Where control goes once a condition operand has been tested.
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    /// The operand at this index, in evaluation order
+    Operand(usize),
+    /// The successors taken when the whole condition holds
+    Holds,
+    /// The successors taken when the whole condition fails
+    Fails,
+}
+
+/// Successors `(node, edge kind)` of a whole condition.
+struct ConditionExits {
+    holds: Vec<(usize, String)>,
+    fails: Vec<(usize, String)>,
+}
+
+/// Split short-circuit conditions and ternaries into one condition node per
+/// operand.
+///
+/// A condition node testing `a && b` keeps its incoming edges and passes
+/// control to `condition(a)`, whose true edge tests `condition(b)` and whose
+/// false edge leaves the condition. A do-while condition holds along its back edge. A statement using `c ? x : y` first tests
+/// `c`, then evaluates one of the two values.
+pub fn expand_short_circuits(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    body: Node,
+    source: &str,
+) {
+    for (condition_id, span) in ctx.conditions.clone() {
+        let condition = match body.descendant_for_byte_range(span.start_byte, span.end_byte) {
+            Some(node) => unwrap_condition(node),
+            None => continue,
+        };
+        if operands(condition).is_some() {
+            expand_condition(cfg, ctx, condition_id, condition, source);
+        }
+    }
+
+    // Regular statement nodes are labelled with their own text
+    let statements: Vec<(usize, Node)> = cfg
+        .nodes
+        .iter()
+        .filter(|node| {
+            !ctx.suspension_points.contains(&node.cfg_node)
+                && !ctx.conditions.iter().any(|(id, _)| *id == node.cfg_node)
+        })
+        .filter_map(|node| {
+            let span = node.span?;
+            let stmt = body.descendant_for_byte_range(span.start_byte, span.end_byte)?;
+            (node.label == get_statement_text(stmt, source)).then_some((node.cfg_node, stmt))
+        })
+        .collect();
+
+    for (statement_id, stmt) in statements {
+        let mut ternaries = Vec::new();
+        collect_ternaries(stmt, &mut ternaries);
+        if let Some((first, exits)) = add_ternaries(cfg, ctx, &ternaries, source) {
+            // The ternaries are evaluated before the statement completes
+            for edge in cfg.edges.iter_mut().filter(|edge| edge.to == statement_id) {
+                edge.to = first;
+            }
+            for exit in exits {
+                cfg.add_edge(CfgEdge::new(exit, statement_id, "next".to_string()));
+            }
+        }
+    }
+}
+
+/// Replace the true/false edges of a condition node with a chain of operand tests.
+fn expand_condition(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    condition_id: usize,
+    condition: Node,
+    source: &str,
+) {
+    let (holds, fails): (Vec<&CfgEdge>, Vec<&CfgEdge>) = cfg
+        .edges
+        .iter()
+        .filter(|edge| edge.from == condition_id)
+        .partition(|edge| matches!(edge.kind.as_str(), "true" | "back"));
+    if holds.is_empty() {
+        return; // Branches not modelled as true/false, leave the condition whole
+    }
+    let exits = ConditionExits {
+        holds: holds
+            .iter()
+            .map(|edge| (edge.to, edge.kind.clone()))
+            .collect(),
+        fails: fails
+            .iter()
+            .map(|edge| (edge.to, edge.kind.clone()))
+            .collect(),
+    };
+    cfg.edges.retain(|edge| edge.from != condition_id);
+
+    let operand_ids = add_operand_nodes(cfg, ctx, condition, source);
+    cfg.add_edge(CfgEdge::new(
+        condition_id,
+        operand_ids[0],
+        "next".to_string(),
+    ));
+    connect_operands(
+        cfg,
+        condition,
+        &operand_ids,
+        &mut 0,
+        (Target::Holds, Target::Fails),
+        &exits,
+    );
+}
+
+/// Add the ternaries of a statement in evaluation order and return the first
+/// node and the nodes that continue to the statement.
+fn add_ternaries(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    ternaries: &[Node],
+    source: &str,
+) -> Option<(usize, Vec<usize>)> {
+    let mut first = None;
+    let mut exits: Vec<usize> = Vec::new();
+    for &ternary in ternaries {
+        let (ternary_first, ternary_exits) = match add_ternary(cfg, ctx, ternary, source) {
+            Some(ternary) => ternary,
+            None => continue, // Incomplete expression
+        };
+        for &exit in &exits {
+            cfg.add_edge(CfgEdge::new(exit, ternary_first, "next".to_string()));
+        }
+        first.get_or_insert(ternary_first);
+        exits = ternary_exits;
+    }
+    first.map(|first| (first, exits))
+}
+
+fn add_ternary(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    ternary: Node,
+    source: &str,
+) -> Option<(usize, Vec<usize>)> {
+    let (condition, consequence, alternative) = ternary_parts(ternary)?;
+    let condition = unwrap_condition(condition);
+
+    let operand_ids = add_operand_nodes(cfg, ctx, condition, source);
+    let (true_first, true_id) = add_ternary_value(cfg, ctx, "true", consequence, source);
+    let (false_first, false_id) = add_ternary_value(cfg, ctx, "false", alternative, source);
+
+    let exits = ConditionExits {
+        holds: vec![(true_first, "true".to_string())],
+        fails: vec![(false_first, "false".to_string())],
+    };
+    connect_operands(
+        cfg,
+        condition,
+        &operand_ids,
+        &mut 0,
+        (Target::Holds, Target::Fails),
+        &exits,
+    );
+    Some((operand_ids[0], vec![true_id, false_id]))
+}
+
+/// Add the node for the value one branch of a ternary selects, after any
+/// ternaries nested in it, and return the branch's first node and the value node.
+fn add_ternary_value(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    branch: &str,
+    value: Node,
+    source: &str,
+) -> (usize, usize) {
+    let mut nested = Vec::new();
+    collect_ternaries(value, &mut nested);
+    let nested = add_ternaries(cfg, ctx, &nested, source);
+
+    let value_id = ctx.alloc_id();
+    let label = LabelNormalizer::ternary_value_label(branch, &expression_text(value, source));
+    cfg.add_node(CfgNode::new(value_id, label).with_span(node_span(value)));
+
+    match nested {
+        Some((first, exits)) => {
+            for exit in exits {
+                cfg.add_edge(CfgEdge::new(exit, value_id, "next".to_string()));
+            }
+            (first, value_id)
+        }
+        None => (value_id, value_id),
+    }
+}
+
+/// Add one condition node per operand of a condition, in evaluation order.
+fn add_operand_nodes(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    condition: Node,
+    source: &str,
+) -> Vec<usize> {
+    let mut leaves = Vec::new();
+    collect_operands(condition, &mut leaves);
+    leaves
+        .into_iter()
+        .map(|leaf| {
+            let operand_id = ctx.alloc_id();
+            let label = LabelNormalizer::condition_label(&expression_text(leaf, source));
+            cfg.add_node(CfgNode::new(operand_id, label).with_span(node_span(leaf)));
+            operand_id
+        })
+        .collect()
+}
+
+/// Connect each operand of `expr`, starting at operand index `next`, to the
+/// targets taken when it is true and when it is false.
+fn connect_operands(
+    cfg: &mut ControlFlowGraph,
+    expr: Node,
+    operand_ids: &[usize],
+    next: &mut usize,
+    (when_true, when_false): (Target, Target),
+    exits: &ConditionExits,
+) {
+    let (is_and, parts) = match operands(expr) {
+        Some(operands) => operands,
+        None => {
+            let operand_id = operand_ids[*next];
+            *next += 1;
+            connect_target(cfg, operand_id, when_true, "true", operand_ids, exits);
+            connect_target(cfg, operand_id, when_false, "false", operand_ids, exits);
+            return;
+        }
+    };
+
+    let last = parts.len() - 1;
+    for (index, part) in parts.into_iter().enumerate() {
+        // The next operand is only tested while the result is still undecided
+        let following = Target::Operand(*next + count_operands(part));
+        let targets = match (index == last, is_and) {
+            (true, _) => (when_true, when_false),
+            (false, true) => (following, when_false),
+            (false, false) => (when_true, following),
+        };
+        connect_operands(cfg, part, operand_ids, next, targets, exits);
+    }
+}
+
+fn connect_target(
+    cfg: &mut ControlFlowGraph,
+    operand_id: usize,
+    target: Target,
+    edge_kind: &str,
+    operand_ids: &[usize],
+    exits: &ConditionExits,
+) {
+    let successors = match target {
+        Target::Operand(index) => {
+            cfg.add_edge(CfgEdge::new(
+                operand_id,
+                operand_ids[index],
+                edge_kind.to_string(),
+            ));
+            return;
+        }
+        Target::Holds => &exits.holds,
+        Target::Fails => &exits.fails,
+    };
+    for (to, kind) in successors {
+        cfg.add_edge(CfgEdge::new(operand_id, *to, kind.clone()));
+    }
+}
+
+/// Split a short-circuit expression into its operator, `true` for and, and
+/// its operands.
+fn operands(expr: Node) -> Option<(bool, Vec<Node>)> {
+    match expr.kind() {
+        // `a && b` in C-like languages and Rust, `a and b` in Python
+        "binary_expression" | "boolean_operator" => {
+            let is_and = match expr.child_by_field_name("operator")?.kind() {
+                "&&" | "and" => true,
+                "||" | "or" => false,
+                _ => return None,
+            };
+            let left = expr.child_by_field_name("left")?;
+            let right = expr.child_by_field_name("right")?;
+            Some((
+                is_and,
+                vec![unwrap_condition(left), unwrap_condition(right)],
+            ))
+        }
+        // Rust `let Some(x) = a && b`
+        "let_chain" => {
+            let mut cursor = expr.walk();
+            let parts: Vec<Node> = expr
+                .named_children(&mut cursor)
+                .map(unwrap_condition)
+                .collect();
+            (!parts.is_empty()).then_some((true, parts))
+        }
+        _ => None,
+    }
+}
+
+fn count_operands(expr: Node) -> usize {
+    match operands(expr) {
+        Some((_, parts)) => parts.into_iter().map(count_operands).sum(),
+        None => 1,
+    }
+}
+
+fn collect_operands<'a>(expr: Node<'a>, leaves: &mut Vec<Node<'a>>) {
+    match operands(expr) {
+        Some((_, parts)) => {
+            for part in parts {
+                collect_operands(part, leaves);
+            }
+        }
+        None => leaves.push(expr),
+    }
+}
+
+/// Strip the parentheses and wrappers around a tested expression.
+fn unwrap_condition(mut expr: Node) -> Node {
+    loop {
+        let inner = match expr.kind() {
+            "parenthesized_expression" | "expression_statement" => expr.named_child(0),
+            // C++ `if (init; value)`
+            "condition_clause" => expr.child_by_field_name("value"),
+            _ => None,
+        };
+        match inner {
+            Some(inner) => expr = inner,
+            None => return expr,
+        }
+    }
+}
+
+/// Collect the outermost ternaries in a node, outside nested functions.
+fn collect_ternaries<'a>(node: Node<'a>, ternaries: &mut Vec<Node<'a>>) {
+    if is_nested_scope(node) {
+        return;
+    }
+    if is_ternary(node) {
+        ternaries.push(node);
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_ternaries(child, ternaries);
+    }
+}
+
+fn is_ternary(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        // Python and C/C++ `conditional_expression`, Java and JavaScript `ternary_expression`
+        "conditional_expression" | "ternary_expression"
+    )
+}
+
+/// Split a ternary into its condition, consequence and alternative.
+fn ternary_parts(ternary: Node) -> Option<(Node, Node, Node)> {
+    if let (Some(condition), Some(consequence), Some(alternative)) = (
+        ternary.child_by_field_name("condition"),
+        ternary.child_by_field_name("consequence"),
+        ternary.child_by_field_name("alternative"),
+    ) {
+        return Some((condition, consequence, alternative));
+    }
+
+    // Python `consequence if condition else alternative`
+    Some((
+        ternary.named_child(1)?,
+        ternary.named_child(0)?,
+        ternary.named_child(2)?,
+    ))
+}
+
+fn expression_text(expr: Node, source: &str) -> String {
+    let text = get_statement_text(expr, source);
+    text.trim_end_matches(';').to_string()
+}
@@ -1,3 +1,7 @@
+use super::LabelNormalizer;
+use crate::models::{CfgEdge, CfgNode, CfgSpan, ControlFlowGraph};
+use std::collections::{HashMap, HashSet};
+
 /// Represents a loop context for break/continue handling.
 #[derive(Debug, Clone)]
 pub struct LoopContext {
@@ -5,6 +9,50 @@ pub struct LoopContext {
     pub condition_id: usize,
     /// Node ID where the loop exits (for break)
     pub after_id: usize,
+    /// Whether this is a switch, which `break` exits but `continue` passes through
+    pub is_switch: bool,
+    /// Whether this is a Rust labeled block, which only a labeled `break` exits
+    pub is_labeled_block: bool,
+    /// Label naming the loop, such as Java `outer:` or Rust `'outer:`
+    pub label: Option<String>,
+}
+
+/// A transfer of control out of the current statement sequence.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Jump {
+    /// `return` to the caller
+    Return,
+    /// `raise`/`throw` of an exception
+    Throw,
+    /// `break` out of the loop at this index of the loop stack
+    Break(usize),
+    /// `continue` of the loop at this index of the loop stack
+    Continue(usize),
+}
+
+impl Jump {
+    /// Edge kind used for this jump.
+    pub fn edge_kind(&self) -> &'static str {
+        match self {
+            Jump::Return => "exit",
+            Jump::Throw => "exception",
+            Jump::Break(_) => "break",
+            Jump::Continue(_) => "continue",
+        }
+    }
+}
+
+/// Represents an enclosing try statement for exception and finally routing.
+#[derive(Debug, Clone)]
+pub struct TryContext {
+    /// Handler nodes an exception raised in the guarded block is caught by
+    pub handler_ids: Vec<usize>,
+    /// Node ID of the finally block, which every jump out of the try runs first
+    pub finally_id: Option<usize>,
+    /// Number of loops enclosing the try statement
+    pub loop_depth: usize,
+    /// Jumps that entered the finally block and continue once it completes
+    pub pending_jumps: Vec<Jump>,
 }
 
 /// Context for CFG building that tracks IDs and loop control.
@@ -13,6 +61,20 @@ pub struct CfgContext {
     pub exit_id: usize,
     /// Stack of active loop contexts for break/continue handling
     pub loop_stack: Vec<LoopContext>,
+    /// Stack of enclosing try statements for throw and finally handling
+    pub try_stack: Vec<TryContext>,
+    /// Label of the loop about to be pushed
+    pub loop_label: Option<String>,
+    /// Nodes of C/C++ goto labels, created on first use
+    pub goto_labels: HashMap<String, usize>,
+    /// Nodes of goto labels whose definition has been seen
+    pub defined_goto_labels: HashSet<usize>,
+    /// Await, yield and async iteration nodes, whose normal successors resume them
+    pub suspension_points: Vec<usize>,
+    /// Branch and loop condition nodes, with the span of the condition they test
+    pub conditions: Vec<(usize, CfgSpan)>,
+    /// Names of the Rust functions declared to return `!`, whose calls never return
+    pub diverging_functions: Vec<String>,
 }
 
 impl CfgContext {
@@ -21,6 +83,13 @@ impl CfgContext {
             next_id: 0,
             exit_id: 9999, // High number to avoid conflicts
             loop_stack: Vec::new(),
+            try_stack: Vec::new(),
+            loop_label: None,
+            goto_labels: HashMap::new(),
+            defined_goto_labels: HashSet::new(),
+            suspension_points: Vec::new(),
+            conditions: Vec::new(),
+            diverging_functions: Vec::new(),
         }
     }
 
@@ -30,11 +99,39 @@ impl CfgContext {
         id
     }
 
-    /// Push a new loop context onto the stack.
+    /// Push a new loop context onto the stack, named by any pending loop label.
     pub fn push_loop(&mut self, condition_id: usize, after_id: usize) {
+        let label = self.loop_label.take();
         self.loop_stack.push(LoopContext {
             condition_id,
             after_id,
+            is_switch: false,
+            is_labeled_block: false,
+            label,
+        });
+    }
+
+    /// Push a switch context, which `break` exits like a loop.
+    pub fn push_switch(&mut self, after_id: usize) {
+        let label = self.loop_label.take();
+        self.loop_stack.push(LoopContext {
+            condition_id: after_id,
+            after_id,
+            is_switch: true,
+            is_labeled_block: false,
+            label,
+        });
+    }
+
+    /// Push a Rust labeled block, which `break 'label value` exits.
+    pub fn push_labeled_block(&mut self, after_id: usize) {
+        let label = self.loop_label.take();
+        self.loop_stack.push(LoopContext {
+            condition_id: after_id,
+            after_id,
+            is_switch: true,
+            is_labeled_block: true,
+            label,
         });
     }
 
@@ -47,4 +144,113 @@ impl CfgContext {
     pub fn current_loop(&self) -> Option<&LoopContext> {
         self.loop_stack.last()
     }
+
+    /// Index of the innermost actual loop, skipping switches, for continue.
+    pub fn innermost_loop(&self) -> Option<usize> {
+        self.loop_stack
+            .iter()
+            .rposition(|loop_ctx| !loop_ctx.is_switch)
+    }
+
+    /// Index of the innermost loop or switch, skipping labeled blocks, for an
+    /// unlabeled break.
+    pub fn innermost_breakable(&self) -> Option<usize> {
+        self.loop_stack
+            .iter()
+            .rposition(|loop_ctx| !loop_ctx.is_labeled_block)
+    }
+
+    /// Index of the loop a labeled `break`/`continue` targets.
+    pub fn labeled_loop(&self, label: &str) -> Option<usize> {
+        self.loop_stack
+            .iter()
+            .rposition(|loop_ctx| loop_ctx.label.as_deref() == Some(label))
+    }
+
+    /// Edge kind from an exit of a branching construct to its join: a
+    /// condition leaves along its true edge when the then branch is empty,
+    /// and along its false edge when there is no else branch.
+    pub fn join_edge_kind(&self, cfg: &ControlFlowGraph, exit: usize) -> &'static str {
+        let is_condition = self
+            .conditions
+            .iter()
+            .any(|(condition_id, _)| *condition_id == exit);
+        if !is_condition {
+            return "next";
+        }
+        let has_true_edge = cfg
+            .edges
+            .iter()
+            .any(|edge| edge.from == exit && edge.kind == "true");
+        if has_true_edge {
+            "false"
+        } else {
+            "true"
+        }
+    }
+
+    /// Node of a goto label, created when the first goto or the label is seen.
+    pub fn goto_label(&mut self, cfg: &mut ControlFlowGraph, name: &str) -> usize {
+        if let Some(&label_id) = self.goto_labels.get(name) {
+            return label_id;
+        }
+        let label_id = self.alloc_id();
+        cfg.add_node(CfgNode::new(label_id, LabelNormalizer::goto_label(name)));
+        self.goto_labels.insert(name.to_string(), label_id);
+        label_id
+    }
+
+    /// Push a try statement whose guarded block is about to be processed.
+    pub fn push_try(&mut self, handler_ids: Vec<usize>, finally_id: Option<usize>) {
+        self.try_stack.push(TryContext {
+            handler_ids,
+            finally_id,
+            loop_depth: self.loop_stack.len(),
+            pending_jumps: Vec::new(),
+        });
+    }
+
+    /// Pop the most recent try context from the stack.
+    pub fn pop_try(&mut self) -> Option<TryContext> {
+        self.try_stack.pop()
+    }
+
+    /// Nodes a jump transfers control to.
+    ///
+    /// A throw is caught by the handlers of the innermost guarded block. Any
+    /// jump that leaves a try statement with a finally block enters the finally
+    /// block first and is recorded so it can continue once the block completes.
+    pub fn jump_targets(&mut self, jump: Jump) -> Vec<usize> {
+        for try_ctx in self.try_stack.iter_mut().rev() {
+            if jump == Jump::Throw && !try_ctx.handler_ids.is_empty() {
+                return try_ctx.handler_ids.clone();
+            }
+            let leaves_try = match jump {
+                Jump::Break(index) | Jump::Continue(index) => index < try_ctx.loop_depth,
+                Jump::Return | Jump::Throw => true,
+            };
+            if !leaves_try {
+                break;
+            }
+            if let Some(finally_id) = try_ctx.finally_id {
+                if !try_ctx.pending_jumps.contains(&jump) {
+                    try_ctx.pending_jumps.push(jump);
+                }
+                return vec![finally_id];
+            }
+        }
+
+        match jump {
+            Jump::Return | Jump::Throw => vec![self.exit_id],
+            Jump::Break(index) => vec![self.loop_stack[index].after_id],
+            Jump::Continue(index) => vec![self.loop_stack[index].condition_id],
+        }
+    }
+
+    /// Add the edges for a jump leaving `from`.
+    pub fn connect_jump(&mut self, cfg: &mut ControlFlowGraph, from: usize, jump: Jump) {
+        for target in self.jump_targets(jump) {
+            cfg.add_edge(CfgEdge::new(from, target, jump.edge_kind().to_string()));
+        }
+    }
 }
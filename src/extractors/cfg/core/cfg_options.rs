@@ -0,0 +1,21 @@
+/// Options controlling how the CFG builder models a function.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CfgOptions {
+    /// Split `&&`/`||`/`and`/`or` conditions, `?:` ternaries and Rust `let`
+    /// chains into one condition node per operand, with true/false edges in
+    /// evaluation order
+    pub short_circuit: bool,
+}
+
+impl CfgOptions {
+    /// Create options with every optional expansion disabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Configure short-circuit condition expansion (disabled by default).
+    pub fn with_short_circuit(mut self, enabled: bool) -> Self {
+        self.short_circuit = enabled;
+        self
+    }
+}
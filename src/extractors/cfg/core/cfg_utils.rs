@@ -1,3 +1,4 @@
+use crate::models::{CfgSpan, ControlFlowGraph};
 use tree_sitter::Node;
 
 /// Get if condition text.
@@ -8,7 +9,7 @@ pub fn get_if_condition(if_node: Node, source: &str) -> String {
     // Skip 'if' keyword and look for the condition expression
     for child in if_node.children(&mut cursor) {
         // The condition is typically a binary expression or identifier after 'if'
-        if child.kind() != "if" && child.kind() != "block" && child.kind() != "else_clause" {
+        if !matches!(child.kind(), "if" | "elif" | "block" | "else_clause") {
             let start = child.start_byte();
             let end = child.end_byte();
             return source[start..end].trim().to_string();
@@ -40,14 +41,26 @@ pub fn get_if_parts(if_node: Node) -> (Option<Node>, Option<Node>) {
     let mut then_block = None;
     let mut else_part = None;
 
+    // C, Java and JavaScript branches may be single statements rather than blocks
+    if let Some(consequence) = if_node.child_by_field_name("consequence") {
+        then_block = Some(consequence);
+        else_part = if_node.child_by_field_name("alternative");
+    }
+
+    // A Python `elif` is followed by the rest of its if statement's chain
+    if if_node.kind() == "elif_clause" {
+        else_part = std::iter::successors(if_node.next_named_sibling(), |node| {
+            node.next_named_sibling()
+        })
+        .find(|node| matches!(node.kind(), "elif_clause" | "else_clause"));
+    }
+
     for child in if_node.named_children(&mut cursor) {
         match child.kind() {
-            "block" => {
-                if then_block.is_none() {
-                    then_block = Some(child);
-                }
+            "block" if then_block.is_none() => {
+                then_block = Some(child);
             }
-            "else_clause" => {
+            "else_clause" if else_part.is_none() => {
                 else_part = Some(child);
             }
             _ => {}
@@ -57,6 +70,71 @@ pub fn get_if_parts(if_node: Node) -> (Option<Node>, Option<Node>) {
     (then_block, else_part)
 }
 
+/// Get the statements of a branch or loop body, which is either a block or a
+/// single statement such as C's `if (err) goto cleanup;`.
+pub fn body_statements(body: Node) -> Vec<Node> {
+    match body.kind() {
+        "block" | "compound_statement" | "statement_block" => {
+            let mut cursor = body.walk();
+            body.named_children(&mut cursor).collect()
+        }
+        _ => vec![body],
+    }
+}
+
+/// Unwrap a Rust `break;` or `continue;` statement to its jump expression.
+pub fn unwrap_jump_statement(stmt: Node) -> Node {
+    if stmt.kind() != "expression_statement" {
+        return stmt;
+    }
+    match stmt.named_child(0) {
+        Some(expr) if matches!(expr.kind(), "break_expression" | "continue_expression") => expr,
+        _ => stmt,
+    }
+}
+
+/// Check if a node starts a function or class, whose body runs separately.
+pub fn is_nested_scope(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        "function_definition"
+            | "function_declaration"
+            | "function_expression"
+            | "function_item"
+            | "generator_function"
+            | "generator_function_declaration"
+            | "method_definition"
+            | "arrow_function"
+            | "lambda"
+            | "lambda_expression"
+            | "closure_expression"
+            | "class_definition"
+            | "class_declaration"
+    )
+}
+
+/// Relabel the `next` edges leaving `from` that were added at or after edge
+/// index `since`, for statements entered along a branch edge such as `true`.
+pub fn retag_branch_edges(cfg: &mut ControlFlowGraph, from: usize, since: usize, kind: &str) {
+    for edge in cfg.edges.iter_mut().skip(since) {
+        if edge.from == from && edge.kind == "next" {
+            edge.kind = kind.to_string();
+        }
+    }
+}
+
+/// Get the source span of a node.
+pub fn node_span(node: Node) -> CfgSpan {
+    let start = node.start_position();
+    let end = node.end_position();
+    CfgSpan::new(
+        node.start_byte(),
+        node.end_byte(),
+        (start.row, start.column),
+        (end.row, end.column),
+    )
+}
+
 /// Get statement text.
 pub fn get_statement_text(node: Node, source: &str) -> String {
     let start = node.start_byte();
@@ -99,20 +177,43 @@ pub fn is_statement_node(node: Node) -> bool {
             | "macro_invocation"
             | "try_expression"
             | "assignment_expression"
+            | "await_expression"
         // Python statement types
             | "if_statement"
             | "try_statement"
             | "while_statement"
             | "for_statement"
             | "with_statement"
+            | "match_statement"
+            | "assert_statement"
             | "import_statement"
             | "return_statement"
+            | "raise_statement"
+            | "break_statement"
+            | "continue_statement"
         // JavaScript/TypeScript statement types
             | "function_declaration"
             | "variable_declaration"
+            | "lexical_declaration"
+            | "switch_statement"
+            | "statement_block"
+            | "throw_statement"
+            | "for_in_statement"
+            | "do_statement"
+            | "labeled_statement"
         // Java statement types
             | "method_declaration"
+            | "try_with_resources_statement"
+            | "local_variable_declaration"
+            | "switch_expression"
+            | "yield_statement"
+            | "block"
+            | "enhanced_for_statement"
         // C/C++ statement types
             | "function_definition"
+            | "declaration"
+            | "compound_statement"
+            | "goto_statement"
+            | "for_range_loop"
     )
 }
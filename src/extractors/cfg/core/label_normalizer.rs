@@ -32,6 +32,16 @@ impl LabelNormalizer {
         format!("while_loop(cond: {})", condition)
     }
 
+    /// Generate normalized label for a do-while loop, whose condition runs after the body.
+    pub fn do_while_loop_label(condition: &str) -> String {
+        format!("do_while_loop(cond: {})", condition)
+    }
+
+    /// Generate normalized label for a Rust `loop`, which only exits through `break`.
+    pub fn infinite_loop_label() -> String {
+        "loop_expr".to_string()
+    }
+
     /// Generate normalized label for a match expression.
     pub fn match_label(expr: &str) -> String {
         format!("match_expr(value: {})", expr)
@@ -55,13 +65,119 @@ impl LabelNormalizer {
         format!("after_{}", loop_type)
     }
 
+    /// Generate normalized label for a Python loop `else:` block, run when the loop is exhausted.
+    pub fn loop_else_label(loop_type: &str) -> String {
+        format!("{}_else", loop_type)
+    }
+
+    /// Generate normalized label for a Rust labeled block, which `break 'label` leaves.
+    pub fn labeled_block_label(label: &str) -> String {
+        format!("labeled_block(label: {})", label)
+    }
+
+    /// Generate normalized label for a Rust `let ... else`, which tests its pattern.
+    pub fn let_else_label(pattern: &str, value: &str) -> String {
+        format!("let_else(pattern: {}, value: {})", pattern, value)
+    }
+
+    /// Generate normalized label for a C/C++ goto target.
+    pub fn goto_label(name: &str) -> String {
+        format!("label(name: {})", name)
+    }
+
+    /// Generate normalized label for a switch statement or expression.
+    pub fn switch_label(expr: &str) -> String {
+        format!("switch_stmt(value: {})", expr)
+    }
+
+    /// Generate normalized label for a switch case; `None` is the default case.
+    pub fn case_label(value: Option<&str>) -> String {
+        match value {
+            Some(value) => format!("case(value: {})", value),
+            None => "case(default)".to_string(),
+        }
+    }
+
     /// Generate normalized label for match arms.
     pub fn match_arm_label(pattern: &str) -> String {
         format!("match_arm(pattern: {})", pattern)
     }
 
+    /// Generate normalized label for a Python `case` guard.
+    pub fn case_guard_label(condition: &str) -> String {
+        format!("case_guard(cond: {})", condition)
+    }
+
     /// Generate normalized label for control flow joins.
     pub fn join_label(join_type: &str) -> String {
         format!("{}_join", join_type)
     }
+
+    /// Generate normalized labels for try statement structural nodes.
+    pub fn try_label() -> String {
+        "try_stmt".to_string()
+    }
+
+    pub fn catch_label(exception_type: Option<&str>) -> String {
+        format!("catch_clause(type: {})", exception_type.unwrap_or("*"))
+    }
+
+    pub fn try_else_label() -> String {
+        "try_else".to_string()
+    }
+
+    pub fn finally_label() -> String {
+        "finally_block".to_string()
+    }
+
+    /// Generate normalized label for one operand of a short-circuit condition.
+    pub fn condition_label(condition: &str) -> String {
+        format!("condition({})", condition)
+    }
+
+    /// Generate normalized label for the value a ternary selects on one branch.
+    pub fn ternary_value_label(branch: &str, value: &str) -> String {
+        format!("ternary_{}(value: {})", branch, value)
+    }
+
+    /// Generate normalized labels for suspension points.
+    pub fn await_label(value: &str) -> String {
+        format!("await(value: {})", value)
+    }
+
+    pub fn yield_label(value: Option<&str>) -> String {
+        match value {
+            Some(value) => format!("yield(value: {})", value),
+            None => "yield".to_string(),
+        }
+    }
+
+    pub fn yield_from_label(value: &str) -> String {
+        format!("yield_from(value: {})", value)
+    }
+
+    pub fn async_for_label(iterable: &str) -> String {
+        format!("async_for_next(iter: {})", iterable)
+    }
+
+    pub fn async_with_label(phase: &str, value: &str) -> String {
+        format!("async_with_{}(value: {})", phase, value)
+    }
+
+    /// Generate normalized label for entering or leaving a Python context manager.
+    pub fn with_label(phase: &str, value: &str) -> String {
+        format!("with_{}(value: {})", phase, value)
+    }
+
+    /// Generate normalized labels for the implicit loop of a comprehension.
+    pub fn comprehension_label(iterable: &str, pattern: &str) -> String {
+        format!(
+            "comprehension(cond: {}.has_next, pattern: {})",
+            iterable, pattern
+        )
+    }
+
+    pub fn comprehension_filter_label(condition: &str) -> String {
+        format!("comprehension_filter(cond: {})", condition)
+    }
 }
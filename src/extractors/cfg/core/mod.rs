@@ -1,8 +1,13 @@
 /// Core utilities for CFG processing.
 pub mod cfg_context;
+pub mod cfg_options;
 pub mod cfg_utils;
 pub mod label_normalizer;
 
-pub use cfg_context::CfgContext;
-pub use cfg_utils::{get_if_condition, get_if_parts, get_statement_text, is_statement_node};
+pub use cfg_context::{CfgContext, Jump};
+pub use cfg_options::CfgOptions;
+pub use cfg_utils::{
+    body_statements, get_if_condition, get_if_parts, get_statement_text, is_nested_scope,
+    is_statement_node, node_span, retag_branch_edges, unwrap_jump_statement,
+};
 pub use label_normalizer::LabelNormalizer;
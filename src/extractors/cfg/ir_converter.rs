@@ -14,23 +14,30 @@ impl CFGToIRConverter {
 
         // Convert nodes
         for node in &cfg.nodes {
-            let node_ir = CFGNodeIR::new(
+            let span = node.span.map(|span| span.format()).unwrap_or_default();
+            let mut node_ir = CFGNodeIR::new(
                 function_name.clone(),
                 format!("N{}", node.cfg_node),
                 node.label.clone(),
-                format!("{}:{}", node.cfg_node, node.cfg_node), // Simple span for now
+                span,
             );
+            if let Some(span) = node.span {
+                node_ir = node_ir.with_location(span);
+            }
             ir.add_node(node_ir);
         }
 
         // Convert edges
         for edge in &cfg.edges {
-            let edge_ir = CFGEdgeIR::new(
+            let mut edge_ir = CFGEdgeIR::new(
                 function_name.clone(),
                 format!("N{}", edge.from),
                 format!("N{}", edge.to),
                 edge.kind.clone(),
             );
+            if let Some(span) = edge.span {
+                edge_ir = edge_ir.with_location(span);
+            }
             ir.add_edge(edge_ir);
         }
 
@@ -1,3 +1,4 @@
+use crate::analyzers::language_specific::RustEarlyExitAnalyzer;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
 
@@ -7,23 +8,43 @@ pub mod ir_converter;
 pub mod processors;
 mod statements;
 
+pub use core::CfgOptions;
+
+use branches::expand_short_circuits;
 use core::CfgContext;
-use processors::process_block;
+use processors::process_body;
+use statements::{mark_pattern_failures, mark_resume_edges, resolve_goto_labels};
 
-/// Builds a Control Flow Graph from a function body block with if/else support.
+/// Builds a Control Flow Graph from a function body with if/else support.
+///
+/// The body is usually a block, but a lambda or closure body may be a single expression.
 pub fn build_cfg_from_block(block_node: Node, source: &str) -> ControlFlowGraph {
+    build_cfg_with_options(block_node, source, &CfgOptions::default())
+}
+
+/// Builds a Control Flow Graph from a function body, with optional expansions.
+pub fn build_cfg_with_options(
+    block_node: Node,
+    source: &str,
+    options: &CfgOptions,
+) -> ControlFlowGraph {
     let mut cfg = ControlFlowGraph::new();
     let mut ctx = CfgContext::new();
 
+    // Calls to Rust functions returning `!` anywhere in the file never return
+    let mut root = block_node;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+    ctx.diverging_functions = RustEarlyExitAnalyzer::diverging_functions(root, source);
+
     // Add ENTRY node
     let entry_id = ctx.alloc_id();
     cfg.add_node(CfgNode::new(entry_id, "ENTRY".to_string()));
 
     // Process block and get exit points
-    let exits = process_block(&mut cfg, &mut ctx, block_node, source, entry_id);
-
-    // Add EXIT node
-    cfg.add_node(CfgNode::new(ctx.exit_id, "EXIT".to_string()));
+    let exits = process_body(&mut cfg, &mut ctx, block_node, source, entry_id);
+    resolve_goto_labels(&mut cfg, &ctx);
 
     // Connect exit points to EXIT
     for exit in exits {
@@ -32,5 +53,16 @@ pub fn build_cfg_from_block(block_node: Node, source: &str) -> ControlFlowGraph
         }
     }
 
+    if options.short_circuit {
+        expand_short_circuits(&mut cfg, &mut ctx, block_node, source);
+    }
+
+    // Add EXIT node, after any nodes the expansions added
+    cfg.add_node(CfgNode::new(ctx.exit_id, "EXIT".to_string()));
+
+    mark_resume_edges(&mut cfg, &ctx);
+    mark_pattern_failures(&mut cfg, block_node);
+    cfg.attach_edge_spans();
+
     cfg
 }
@@ -1,4 +1,4 @@
-use super::super::core::{get_statement_text, CfgContext};
+use super::super::core::{get_statement_text, node_span, CfgContext};
 use crate::models::{BasicBlock, BasicBlockEdge, BasicBlockGraph};
 use tree_sitter::Node;
 
@@ -41,18 +41,14 @@ impl BasicBlockBuilder {
             Some(block) => {
                 block.add_statement(stmt_text);
                 // Update span to include this statement
-                let start = stmt.start_byte();
-                let end = stmt.end_byte();
-                block.span = format!("{}:{}", start, end);
+                block.extend_location(node_span(stmt));
             }
             None => {
                 // Start a new block if none exists
                 let _block_id = self.start_block();
                 if let Some(block) = &mut self.current_block {
                     block.add_statement(stmt_text);
-                    let start = stmt.start_byte();
-                    let end = stmt.end_byte();
-                    block.span = format!("{}:{}", start, end);
+                    block.extend_location(node_span(stmt));
                 }
             }
         }
@@ -1,4 +1,5 @@
 use super::super::core::is_statement_node;
+use super::super::statements::contains_suspension_point;
 use super::basic_block_builder::BasicBlockBuilder;
 use super::terminator_handler::process_terminator;
 use crate::models::BasicBlockGraph;
@@ -36,6 +37,8 @@ fn process_block_statements(
     mut current_block_id: usize,
 ) -> usize {
     let mut cursor = block.walk();
+    // Block suspended at an await or yield, which the next block resumes
+    let mut suspended_block = None;
 
     for child in block.named_children(&mut cursor) {
         if !is_statement_node(child) {
@@ -54,9 +57,18 @@ fn process_block_statements(
             // Start new block if none exists
             if builder.current_block_id().is_none() {
                 current_block_id = builder.start_block();
+                if let Some(suspended_id) = suspended_block.take() {
+                    builder.add_edge(suspended_id, current_block_id, "resume".to_string());
+                }
             }
 
             builder.add_statement(child, source);
+
+            // A suspension point ends its block
+            if contains_suspension_point(child) {
+                builder.finish_current_block();
+                suspended_block = Some(current_block_id);
+            }
         }
     }
 
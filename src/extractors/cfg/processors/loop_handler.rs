@@ -1,5 +1,5 @@
-use super::super::core::CfgContext;
-use super::super::statements::{process_for, process_while};
+use super::super::core::{node_span, CfgContext};
+use super::super::statements::{process_do_while, process_for, process_loop, process_while};
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
 
@@ -11,23 +11,59 @@ pub fn handle_loop_expression(
     source: &str,
     current: usize,
 ) -> Option<usize> {
-    match loop_node.kind() {
-        "while_expression" => {
-            let exits = process_while(cfg, ctx, loop_node, source, current);
-            handle_loop_exits(exits)
+    // Rust loops carry their own `'label:`
+    let mut cursor = loop_node.walk();
+    if let Some(label) = loop_node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "label")
+    {
+        ctx.loop_label = Some(source[label.start_byte()..label.end_byte()].to_string());
+    }
+
+    let exits = match loop_node.kind() {
+        "while_expression" | "while_statement" => {
+            process_while(cfg, ctx, loop_node, source, current)
         }
-        "for_expression" => {
+        "for_expression"
+        | "for_statement"
+        | "for_in_statement"
+        | "enhanced_for_statement"
+        | "for_range_loop" => {
             let (exits, _for_ir) = process_for(cfg, ctx, loop_node, source, current);
-            handle_loop_exits(exits)
+            exits
         }
-        _ => None,
-    }
+        "do_statement" => process_do_while(cfg, ctx, loop_node, source, current),
+        "loop_expression" => process_loop(cfg, ctx, loop_node, source, current),
+        _ => vec![],
+    };
+    // A loop that failed to parse never pushed its label
+    ctx.loop_label = None;
+    handle_loop_exits(exits)
+}
+
+/// Check whether a node is a loop `handle_loop_expression` can process.
+pub fn is_loop_node(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        "while_expression"
+            | "while_statement"
+            | "for_expression"
+            | "for_statement"
+            | "for_in_statement"
+            | "enhanced_for_statement"
+            | "for_range_loop"
+            | "do_statement"
+            | "loop_expression"
+    )
 }
 
 /// Handle if expression with joining logic.
+///
+/// The join node takes the span of `construct`, the statement whose branches it merges.
 pub fn handle_if_with_join(
     cfg: &mut ControlFlowGraph,
     ctx: &mut CfgContext,
+    construct: Node,
     exits: Vec<usize>,
     current: usize,
 ) -> usize {
@@ -38,11 +74,12 @@ pub fn handle_if_with_join(
     // Create join node if we have branches that need joining
     if exits.len() > 1 || (exits.len() == 1 && exits[0] != current) {
         let join_id = ctx.alloc_id();
-        cfg.add_node(CfgNode::new(join_id, "join".to_string()));
+        cfg.add_node(CfgNode::new(join_id, "join".to_string()).with_span(node_span(construct)));
 
         for exit in &exits {
             if *exit != join_id {
-                cfg.add_edge(CfgEdge::new(*exit, join_id, "next".to_string()));
+                let kind = ctx.join_edge_kind(cfg, *exit);
+                cfg.add_edge(CfgEdge::new(*exit, join_id, kind.to_string()));
             }
         }
         join_id
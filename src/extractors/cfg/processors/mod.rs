@@ -7,4 +7,6 @@ pub mod process_expression;
 pub mod terminator_handler;
 
 pub use basic_block_processor::build_basic_blocks_from_block;
-pub use process_block::process_block;
+pub use loop_handler::{handle_loop_expression, is_loop_node};
+pub use process_block::{process_block, process_body, process_statement};
+pub use process_expression::has_value_expressions;
@@ -1,11 +1,15 @@
 use super::super::branches::process_if;
-use super::super::core::{get_statement_text, is_statement_node, CfgContext};
+use super::super::core::{get_statement_text, is_statement_node, node_span, CfgContext, Jump};
 use super::super::statements::{
-    process_break, process_continue, process_match, process_panic_expression,
-    process_try_expression,
+    is_goto_label, process_break, process_comprehensions, process_continue, process_goto,
+    process_goto_target, process_labeled_statement, process_let_else, process_match,
+    process_match_statement, process_panic_expression, process_suspension_points, process_switch,
+    process_try, process_try_expression, process_with, suspension_scope,
 };
 use super::loop_handler::{handle_if_with_join, handle_loop_expression};
-use super::process_expression::handle_expression_statement;
+use super::process_expression::{
+    handle_expression_statement, has_value_expressions, process_value_expressions,
+};
 use crate::analyzers::language_specific::RustEarlyExitAnalyzer;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
@@ -18,149 +22,330 @@ pub fn process_block(
     source: &str,
     entry: usize,
 ) -> Vec<usize> {
-    let mut current = entry;
+    let mut current = Some(entry);
     let mut cursor = block.walk();
 
     for child in block.named_children(&mut cursor) {
-        if !is_statement_node(child) {
+        // A Rust tail expression may branch before producing the block's value
+        if !is_statement_node(child) && !has_value_expressions(child) {
             continue;
         }
 
-        match child.kind() {
-            // Rust control flow
-            "if_expression" => {
-                let exits = process_if(cfg, ctx, child, source, current);
-                if exits.is_empty() {
-                    return vec![];
-                }
-                current = handle_if_with_join(cfg, ctx, exits, current);
-            }
-            "while_expression" | "for_expression" => {
-                if let Some(new_current) = handle_loop_expression(cfg, ctx, child, source, current)
-                {
-                    current = new_current;
-                } else {
-                    return vec![]; // Loop terminated
-                }
-            }
-            // Python control flow
-            "if_statement" => {
-                let exits = process_if(cfg, ctx, child, source, current);
-                if exits.is_empty() {
-                    return vec![];
-                }
-                current = handle_if_with_join(cfg, ctx, exits, current);
-            }
-            "try_statement" => {
-                // Process try/except as branching control flow
-                let try_id = ctx.alloc_id();
-                cfg.add_node(CfgNode::new(try_id, "try".to_string()));
-                cfg.add_edge(CfgEdge::new(current, try_id, "next".to_string()));
+        current = match current {
+            Some(current) => process_statement(cfg, ctx, child, source, current),
+            // Code after a jump is reachable only through a C/C++ goto label
+            None if is_goto_label(child) => process_goto_target(cfg, ctx, child, source)
+                .first()
+                .copied(),
+            None => None,
+        };
+    }
 
-                let except_id = ctx.alloc_id();
-                cfg.add_node(CfgNode::new(except_id, "except".to_string()));
-                cfg.add_edge(CfgEdge::new(try_id, except_id, "exception".to_string()));
+    current.into_iter().collect()
+}
 
-                // Both try and except paths merge after
-                let join_id = ctx.alloc_id();
-                cfg.add_node(CfgNode::new(join_id, "join".to_string()));
-                cfg.add_edge(CfgEdge::new(try_id, join_id, "success".to_string()));
-                cfg.add_edge(CfgEdge::new(except_id, join_id, "next".to_string()));
+/// Process a loop or branch body, which is either a block or a single statement.
+pub fn process_body(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    body: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    match body.kind() {
+        "block" | "compound_statement" | "statement_block" | "constructor_body" => {
+            process_block(cfg, ctx, body, source, entry)
+        }
+        _ => match process_statement(cfg, ctx, body, source, entry) {
+            Some(exit) => vec![exit],
+            None => vec![],
+        },
+    }
+}
 
-                current = join_id;
+/// Process a single statement and return its exit point, or `None` when the
+/// path terminates.
+pub fn process_statement(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    stmt: Node,
+    source: &str,
+    current: usize,
+) -> Option<usize> {
+    let mut current = current;
+
+    // Awaits, yields and comprehensions run before the statement completes
+    if let Some(scope) = suspension_scope(stmt) {
+        if let Some((_, last)) = process_suspension_points(cfg, ctx, scope, source, current, "next")
+        {
+            current = last;
+        }
+        if let Some((_, last)) = process_comprehensions(cfg, ctx, scope, source, current, "next") {
+            current = last;
+        }
+    }
+
+    // Java switch expressions run before the statement that uses their value
+    if let Some(switch) = find_switch_expression(stmt) {
+        let exits = process_switch(cfg, ctx, switch, source, current);
+        current = *exits.first()?;
+    }
+
+    // Rust control flow nested in an expression produces its value first
+    let value_entry = current;
+    current = process_value_expressions(cfg, ctx, stmt, source, current)?;
+    let has_value_flow = current != value_entry;
+
+    match stmt.kind() {
+        // Rust control flow
+        "if_expression" => {
+            let exits = process_if(cfg, ctx, stmt, source, current);
+            if exits.is_empty() {
+                return None;
             }
-            "while_statement" | "for_statement" => {
-                if let Some(new_current) = handle_loop_expression(cfg, ctx, child, source, current)
-                {
-                    current = new_current;
-                } else {
-                    return vec![]; // Loop terminated
-                }
+            current = handle_if_with_join(cfg, ctx, stmt, exits, current);
+        }
+        "while_expression" | "for_expression" | "loop_expression" => {
+            if let Some(new_current) = handle_loop_expression(cfg, ctx, stmt, source, current) {
+                current = new_current;
+            } else {
+                return None; // Loop terminated
             }
-            "match_expression" => {
-                let exits = process_match(cfg, ctx, child, source, current);
-                if exits.is_empty() {
-                    return vec![];
-                } else if !exits.is_empty() {
-                    current = exits[0];
-                }
+        }
+        // Python control flow
+        "if_statement" => {
+            let exits = process_if(cfg, ctx, stmt, source, current);
+            if exits.is_empty() {
+                return None;
             }
-            "break_expression" => {
-                let exits = process_break(cfg, ctx, child, source, current);
-                return exits; // Path terminated
-            }
-            "continue_expression" => {
-                let exits = process_continue(cfg, ctx, child, source, current);
-                return exits; // Path terminated
-            }
-            "try_expression" => {
-                let (exits, _early_exit_ir) =
-                    process_try_expression(cfg, ctx, child, source, current);
-                if exits.is_empty() {
-                    return vec![]; // Path terminated
-                } else if !exits.is_empty() {
-                    current = exits[0];
-                }
+            current = handle_if_with_join(cfg, ctx, stmt, exits, current);
+        }
+        // Python, Java, JavaScript/TypeScript and C++ exception handling
+        "try_statement" | "try_with_resources_statement" => {
+            let exits = process_try(cfg, ctx, stmt, source, current);
+            match exits.len() {
+                0 => return None,
+                1 => current = exits[0],
+                _ => current = handle_if_with_join(cfg, ctx, stmt, exits, current),
             }
-            "macro_invocation" => {
-                // Check if it's a panic! macro
-                let text = get_statement_text(child, source);
-                if text.starts_with("panic!") {
-                    let (exits, _early_exit_ir) =
-                        process_panic_expression(cfg, ctx, child, source, current);
-                    return exits; // Path terminated by panic
-                } else {
-                    // Regular macro - treat as statement
-                    let node_id = ctx.alloc_id();
-                    cfg.add_node(CfgNode::new(node_id, text));
-                    cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
-                    current = node_id;
-                }
+        }
+        // Python context managers enter and exit around the body
+        "with_statement" => {
+            let exits = process_with(cfg, ctx, stmt, source, current);
+            if exits.is_empty() {
+                return None;
             }
-            "return_expression" => {
-                let text = get_statement_text(child, source);
+            current = exits[0];
+        }
+        "match_statement" => {
+            let exits = process_match_statement(cfg, ctx, stmt, source, current);
+            if exits.is_empty() {
+                return None;
+            }
+            current = exits[0];
+        }
+        // C, C++, Java and JavaScript/TypeScript switches
+        "switch_statement" | "switch_expression" => {
+            let exits = process_switch(cfg, ctx, stmt, source, current);
+            if exits.is_empty() {
+                return None;
+            }
+            current = exits[0];
+        }
+        // Nested C/C++, Java and JavaScript/TypeScript blocks
+        "compound_statement" | "statement_block" | "block" => {
+            let exits = process_block(cfg, ctx, stmt, source, current);
+            if exits.is_empty() {
+                return None;
+            }
+            current = exits[0];
+        }
+        // Python and Java assertions raise when their condition fails
+        "assert_statement" => {
+            let text = get_statement_text(stmt, source);
+            let node_id = ctx.alloc_id();
+            cfg.add_node(CfgNode::new(node_id, text).with_span(node_span(stmt)));
+            cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
+            ctx.connect_jump(cfg, node_id, Jump::Throw);
+            current = node_id;
+        }
+        "raise_statement" | "throw_statement" => {
+            let text = get_statement_text(stmt, source);
+            let node_id = ctx.alloc_id();
+            cfg.add_node(CfgNode::new(node_id, text).with_span(node_span(stmt)));
+            cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
+            ctx.connect_jump(cfg, node_id, Jump::Throw);
+            return None; // Path terminated
+        }
+        "while_statement"
+        | "for_statement"
+        | "for_in_statement"
+        | "enhanced_for_statement"
+        | "for_range_loop"
+        | "do_statement" => {
+            if let Some(new_current) = handle_loop_expression(cfg, ctx, stmt, source, current) {
+                current = new_current;
+            } else {
+                return None; // Loop terminated
+            }
+        }
+        // C/C++ goto targets and Java/JavaScript loop labels
+        "labeled_statement" => {
+            let exits = process_labeled_statement(cfg, ctx, stmt, source, current);
+            if exits.is_empty() {
+                return None;
+            }
+            current = exits[0];
+        }
+        "goto_statement" => {
+            let _exits = process_goto(cfg, ctx, stmt, source, current);
+            return None; // Path terminated
+        }
+        // Rust `let ... else` diverges when its pattern fails
+        "let_declaration" if stmt.child_by_field_name("alternative").is_some() => {
+            let exits = process_let_else(cfg, ctx, stmt, source, current);
+            current = exits[0];
+        }
+        "match_expression" => {
+            let exits = process_match(cfg, ctx, stmt, source, current);
+            if exits.is_empty() {
+                return None;
+            } else if !exits.is_empty() {
+                current = exits[0];
+            }
+        }
+        "break_expression" | "break_statement" => {
+            let _exits = process_break(cfg, ctx, stmt, source, current);
+            return None; // Path terminated
+        }
+        // Java `yield` leaves the enclosing switch expression with its value
+        "yield_statement" => {
+            let text = get_statement_text(stmt, source);
+            let node_id = ctx.alloc_id();
+            cfg.add_node(CfgNode::new(node_id, text).with_span(node_span(stmt)));
+            cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
+            if let Some(index) = ctx
+                .loop_stack
+                .iter()
+                .rposition(|loop_ctx| loop_ctx.is_switch)
+            {
+                ctx.connect_jump(cfg, node_id, Jump::Break(index));
+            }
+            return None; // Path terminated
+        }
+        "continue_expression" | "continue_statement" => {
+            let _exits = process_continue(cfg, ctx, stmt, source, current);
+            return None; // Path terminated
+        }
+        "try_expression" => {
+            let (exits, _early_exit_ir) = process_try_expression(cfg, ctx, stmt, source, current);
+            if exits.is_empty() {
+                return None; // Path terminated
+            } else if !exits.is_empty() {
+                current = exits[0];
+            }
+        }
+        "macro_invocation" => {
+            // Check if it's a panic! macro
+            let text = get_statement_text(stmt, source);
+            if RustEarlyExitAnalyzer::is_panic_macro(stmt, source) {
+                let (_exits, _early_exit_ir) =
+                    process_panic_expression(cfg, ctx, stmt, source, current);
+                return None; // Path terminated by panic
+            } else {
+                // Regular macro - treat as statement
                 let node_id = ctx.alloc_id();
-                cfg.add_node(CfgNode::new(node_id, text));
+                cfg.add_node(CfgNode::new(node_id, text).with_span(node_span(stmt)));
                 cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
-                cfg.add_edge(CfgEdge::new(node_id, ctx.exit_id, "exit".to_string()));
-                return vec![]; // Path terminated
+                current = node_id;
             }
-            _ => {
-                // Check for Rust early-exit constructs first
-                if RustEarlyExitAnalyzer::contains_early_exit(child, source) {
-                    if RustEarlyExitAnalyzer::contains_try_operator(child, source) {
-                        let (exits, _early_exit_ir) =
-                            process_try_expression(cfg, ctx, child, source, current);
-                        if exits.is_empty() {
-                            return vec![]; // Path terminated
-                        } else if !exits.is_empty() {
-                            current = exits[0];
-                        }
-                    } else if RustEarlyExitAnalyzer::is_panic_macro(child, source) {
-                        let (_exits, _early_exit_ir) =
-                            process_panic_expression(cfg, ctx, child, source, current);
-                        return vec![]; // Path terminated by panic
+        }
+        "return_expression" | "return_statement" => {
+            let text = get_statement_text(stmt, source);
+            let node_id = ctx.alloc_id();
+            cfg.add_node(CfgNode::new(node_id, text).with_span(node_span(stmt)));
+            cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
+            ctx.connect_jump(cfg, node_id, Jump::Return);
+            return None; // Path terminated
+        }
+        _ => {
+            // Check for Rust early-exit constructs first, unless their `?`
+            // operators were expanded with the value
+            if !has_value_flow && RustEarlyExitAnalyzer::contains_early_exit(stmt, source) {
+                if RustEarlyExitAnalyzer::contains_try_operator(stmt, source) {
+                    let (exits, _early_exit_ir) =
+                        process_try_expression(cfg, ctx, stmt, source, current);
+                    if exits.is_empty() {
+                        return None; // Path terminated
+                    } else if !exits.is_empty() {
+                        current = exits[0];
                     }
-                } else if child.kind() == "expression_statement" {
-                    if let Some(new_current) =
-                        handle_expression_statement(cfg, ctx, child, source, current)
-                    {
-                        if new_current == usize::MAX {
-                            return vec![];
-                        }
-                        current = new_current;
+                } else if RustEarlyExitAnalyzer::is_panic_macro(stmt, source) {
+                    let (_exits, _early_exit_ir) =
+                        process_panic_expression(cfg, ctx, stmt, source, current);
+                    return None; // Path terminated by panic
+                }
+            } else if RustEarlyExitAnalyzer::is_diverging_call(
+                stmt,
+                source,
+                &ctx.diverging_functions,
+            ) {
+                // `std::process::exit` and functions returning `!` never return
+                let text = get_statement_text(stmt, source);
+                let node_id = ctx.alloc_id();
+                cfg.add_node(CfgNode::new(node_id, text).with_span(node_span(stmt)));
+                cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
+                cfg.add_edge(CfgEdge::new(node_id, ctx.exit_id, "exit".to_string()));
+                return None; // Path terminated
+            } else if stmt.kind() == "expression_statement" {
+                if let Some(new_current) =
+                    handle_expression_statement(cfg, ctx, stmt, source, current)
+                {
+                    if new_current == usize::MAX {
+                        return None;
                     }
-                } else {
-                    // Regular statement
-                    let text = get_statement_text(child, source);
-                    let node_id = ctx.alloc_id();
-                    cfg.add_node(CfgNode::new(node_id, text));
-                    cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
-                    current = node_id;
+                    current = new_current;
                 }
+            } else {
+                // Regular statement
+                let text = get_statement_text(stmt, source);
+                let node_id = ctx.alloc_id();
+                cfg.add_node(CfgNode::new(node_id, text).with_span(node_span(stmt)));
+                cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
+                current = node_id;
             }
         }
     }
 
-    vec![current]
+    Some(current)
+}
+
+/// Find a Java switch expression whose value a statement uses, such as
+/// `int y = switch (x) { ... };` or `return switch (x) { ... };`.
+fn find_switch_expression(stmt: Node) -> Option<Node> {
+    match stmt.kind() {
+        "local_variable_declaration"
+        | "expression_statement"
+        | "return_statement"
+        | "yield_statement"
+        | "throw_statement" => find_nested_switch(stmt),
+        _ => None,
+    }
+}
+
+fn find_nested_switch(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "switch_expression" => return Some(child),
+            // Switches inside lambdas and anonymous classes run later, if at all
+            "lambda_expression" | "class_body" => {}
+            _ => {
+                if let Some(switch) = find_nested_switch(child) {
+                    return Some(switch);
+                }
+            }
+        }
+    }
+    None
 }
@@ -1,9 +1,14 @@
 use super::super::branches::process_if;
-use super::super::core::{get_statement_text, CfgContext};
+use super::super::core::{
+    get_statement_text, is_nested_scope, is_statement_node, node_span, CfgContext, Jump,
+    LabelNormalizer,
+};
 use super::super::statements::{
-    process_break, process_continue, process_for, process_match, process_panic_expression,
-    process_try_expression, process_while,
+    is_labeled_block, process_break, process_continue, process_labeled_block, process_match,
+    process_panic_expression, process_try_expression,
 };
+use super::loop_handler::handle_loop_expression;
+use crate::analyzers::language_specific::RustEarlyExitAnalyzer;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
 
@@ -27,10 +32,13 @@ pub fn handle_expression_statement(
                 }
                 if exits.len() > 1 || (exits.len() == 1 && exits[0] != current) {
                     let join_id = ctx.alloc_id();
-                    cfg.add_node(CfgNode::new(join_id, "join".to_string()));
+                    cfg.add_node(
+                        CfgNode::new(join_id, "join".to_string()).with_span(node_span(child)),
+                    );
                     for exit in &exits {
                         if *exit != join_id {
-                            cfg.add_edge(CfgEdge::new(*exit, join_id, "next".to_string()));
+                            let kind = ctx.join_edge_kind(cfg, *exit);
+                            cfg.add_edge(CfgEdge::new(*exit, join_id, kind.to_string()));
                         }
                     }
                     return Some(join_id);
@@ -39,17 +47,14 @@ pub fn handle_expression_statement(
                 }
                 return Some(current);
             }
-            "while_expression" => {
-                let exits = process_while(cfg, ctx, child, source, current);
-                if exits.is_empty() {
-                    return Some(usize::MAX); // Signal termination
-                } else if !exits.is_empty() {
-                    return Some(exits[0]);
-                }
-                return Some(current);
+            "while_expression" | "for_expression" | "loop_expression" => {
+                return match handle_loop_expression(cfg, ctx, child, source, current) {
+                    Some(exit) => Some(exit),
+                    None => Some(usize::MAX), // Signal termination
+                };
             }
-            "for_expression" => {
-                let (exits, _for_ir) = process_for(cfg, ctx, child, source, current);
+            "match_expression" => {
+                let exits = process_match(cfg, ctx, child, source, current);
                 if exits.is_empty() {
                     return Some(usize::MAX); // Signal termination
                 } else if !exits.is_empty() {
@@ -57,14 +62,12 @@ pub fn handle_expression_statement(
                 }
                 return Some(current);
             }
-            "match_expression" => {
-                let exits = process_match(cfg, ctx, child, source, current);
+            "block" if is_labeled_block(child) => {
+                let exits = process_labeled_block(cfg, ctx, child, source, current);
                 if exits.is_empty() {
                     return Some(usize::MAX); // Signal termination
-                } else if !exits.is_empty() {
-                    return Some(exits[0]);
                 }
-                return Some(current);
+                return Some(exits[0]);
             }
             "break_expression" => {
                 let _exits = process_break(cfg, ctx, child, source, current);
@@ -91,11 +94,11 @@ pub fn handle_expression_statement(
     let text = get_statement_text(stmt, source);
     if text.starts_with("return") {
         let node_id = ctx.alloc_id();
-        cfg.add_node(CfgNode::new(node_id, text));
+        cfg.add_node(CfgNode::new(node_id, text).with_span(node_span(stmt)));
         cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
-        cfg.add_edge(CfgEdge::new(node_id, ctx.exit_id, "exit".to_string()));
+        ctx.connect_jump(cfg, node_id, Jump::Return);
         return Some(usize::MAX); // Signal termination
-    } else if text.starts_with("panic!") {
+    } else if RustEarlyExitAnalyzer::is_panic_macro(stmt, source) {
         // Handle panic! in expression statement
         let (_exits, _early_exit_ir) = process_panic_expression(cfg, ctx, stmt, source, current);
         return Some(usize::MAX); // Signal termination
@@ -105,8 +108,129 @@ pub fn handle_expression_statement(
     } else {
         // Regular expression statement
         let node_id = ctx.alloc_id();
-        cfg.add_node(CfgNode::new(node_id, text));
+        cfg.add_node(CfgNode::new(node_id, text).with_span(node_span(stmt)));
         cfg.add_edge(CfgEdge::new(current, node_id, "next".to_string()));
         return Some(node_id);
     }
 }
+
+/// Expand the Rust control flow nested in the expression a statement
+/// evaluates, in evaluation order, and return the node the statement
+/// continues from, or `None` when every path diverges.
+///
+/// In `let x = if c { a } else { b };` the branches meet at an
+/// `if_value_join` that produces the value bound to `x`. A `match`, a `loop`
+/// breaking with a value and a labeled block produce theirs at their own
+/// join, and a `?` in argument position may return before the call is made.
+pub fn process_value_expressions(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    stmt: Node,
+    source: &str,
+    entry: usize,
+) -> Option<usize> {
+    let mut expressions = Vec::new();
+    if let Some(scope) = value_scope(stmt) {
+        collect_value_expressions(scope, &mut expressions);
+    }
+
+    let mut current = entry;
+    for expr in expressions {
+        current = match expr.kind() {
+            "if_expression" => {
+                let exits = process_if(cfg, ctx, expr, source, current);
+                if exits.is_empty() {
+                    return None; // Every branch diverges
+                }
+                let join_id = ctx.alloc_id();
+                cfg.add_node(
+                    CfgNode::new(join_id, LabelNormalizer::join_label("if_value"))
+                        .with_span(node_span(expr)),
+                );
+                for exit in exits {
+                    let kind = ctx.join_edge_kind(cfg, exit);
+                    cfg.add_edge(CfgEdge::new(exit, join_id, kind.to_string()));
+                }
+                join_id
+            }
+            "match_expression" => *process_match(cfg, ctx, expr, source, current).first()?,
+            "try_expression" => {
+                let (exits, _early_exit_ir) =
+                    process_try_expression(cfg, ctx, expr, source, current);
+                *exits.first()?
+            }
+            "block" => *process_labeled_block(cfg, ctx, expr, source, current).first()?,
+            _ => handle_loop_expression(cfg, ctx, expr, source, current)?,
+        };
+    }
+    Some(current)
+}
+
+/// Check whether a statement evaluates Rust control flow nested in an expression.
+pub fn has_value_expressions(stmt: Node) -> bool {
+    let mut expressions = Vec::new();
+    if let Some(scope) = value_scope(stmt) {
+        collect_value_expressions(scope, &mut expressions);
+    }
+    !expressions.is_empty()
+}
+
+/// Find the expression whose value a Rust statement or tail expression uses.
+/// Control flow statements, and the `?` ending an expression statement, are
+/// processed as statements.
+fn value_scope(stmt: Node) -> Option<Node> {
+    match stmt.kind() {
+        "let_declaration" if stmt.child_by_field_name("alternative").is_none() => {
+            stmt.child_by_field_name("value")
+        }
+        "expression_statement" => match stmt.named_child(0)? {
+            expr if is_value_expression(expr) => None,
+            expr if expr.kind() == "try_expression" => expr.named_child(0),
+            expr => Some(expr),
+        },
+        "return_expression" | "try_expression" => stmt.named_child(0),
+        "assignment_expression" => stmt.child_by_field_name("right"),
+        _ if is_tail_expression(stmt) => Some(stmt),
+        _ => None,
+    }
+}
+
+/// Check for the expression ending a Rust block, which produces its value.
+fn is_tail_expression(node: Node) -> bool {
+    node.parent().is_some_and(|parent| parent.kind() == "block")
+        && node.next_named_sibling().is_none()
+        && !is_statement_node(node)
+}
+
+/// Collect the outermost control flow expressions in post-order, so a `?`
+/// comes after the expressions in its operand.
+fn collect_value_expressions<'a>(node: Node<'a>, expressions: &mut Vec<Node<'a>>) {
+    // Closures and async blocks run later, if at all
+    if is_nested_scope(node) || node.kind() == "async_block" {
+        return;
+    }
+    if is_value_expression(node) {
+        expressions.push(node);
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_value_expressions(child, expressions);
+    }
+
+    if node.kind() == "try_expression" {
+        expressions.push(node);
+    }
+}
+
+fn is_value_expression(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        "if_expression"
+            | "match_expression"
+            | "while_expression"
+            | "for_expression"
+            | "loop_expression"
+    ) || is_labeled_block(node)
+}
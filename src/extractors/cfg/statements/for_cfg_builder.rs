@@ -1,5 +1,7 @@
-use super::super::core::{CfgContext, LabelNormalizer};
-use super::super::processors::process_block;
+use super::super::core::{node_span, CfgContext, LabelNormalizer};
+use super::super::processors::process_body;
+use super::process_loop_else::process_loop_else;
+use super::process_suspend::{add_suspension_point, is_async};
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph, ForLoopIR};
 use tree_sitter::Node;
 
@@ -7,35 +9,51 @@ use tree_sitter::Node;
 pub fn build_for_loop_cfg(
     cfg: &mut ControlFlowGraph,
     ctx: &mut CfgContext,
-    _for_node: Node,
+    for_node: Node,
     body: Node,
     source: &str,
     entry: usize,
     for_ir: &ForLoopIR,
 ) -> Vec<usize> {
+    // Python `async for` and JavaScript `for await` await the next item on every iteration
+    let anext_id = is_async(for_node).then(|| {
+        let iterable = for_ir.iter_expr.as_deref().unwrap_or("");
+        let label = LabelNormalizer::async_for_label(iterable);
+        add_suspension_point(cfg, ctx, label, for_node, entry, "next")
+    });
+
     // Create for loop condition node with normalized label
     let condition_id = ctx.alloc_id();
     let condition_text = LabelNormalizer::for_loop_label(for_ir);
 
-    cfg.add_node(CfgNode::new(condition_id, condition_text));
-    cfg.add_edge(CfgEdge::new(entry, condition_id, "next".to_string()));
+    cfg.add_node(CfgNode::new(condition_id, condition_text).with_span(node_span(for_node)));
+    // Only a C-style loop tests a condition; iteration loops test for a next item
+    if let Some(condition) = for_node.child_by_field_name("condition") {
+        ctx.conditions.push((condition_id, node_span(condition)));
+    }
+    cfg.add_edge(CfgEdge::new(
+        anext_id.unwrap_or(entry),
+        condition_id,
+        "next".to_string(),
+    ));
+    let loop_head = anext_id.unwrap_or(condition_id);
 
     // Create after-loop node
     let after_id = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(
-        after_id,
-        LabelNormalizer::loop_after_label("for_loop"),
-    ));
+    cfg.add_node(
+        CfgNode::new(after_id, LabelNormalizer::loop_after_label("for_loop"))
+            .with_span(node_span(for_node)),
+    );
 
     // Push loop context for break/continue handling
-    ctx.push_loop(condition_id, after_id);
+    ctx.push_loop(loop_head, after_id);
 
     // Create body start node
     let body_start_id = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(
-        body_start_id,
-        LabelNormalizer::loop_body_label("for_loop"),
-    ));
+    cfg.add_node(
+        CfgNode::new(body_start_id, LabelNormalizer::loop_body_label("for_loop"))
+            .with_span(node_span(body)),
+    );
 
     // Connect condition to body start (true branch)
     cfg.add_edge(CfgEdge::new(
@@ -45,7 +63,7 @@ pub fn build_for_loop_cfg(
     ));
 
     // Process body
-    let body_exits = process_block(cfg, ctx, body, source, body_start_id);
+    let body_exits = process_body(cfg, ctx, body, source, body_start_id);
 
     // Pop loop context
     ctx.pop_loop();
@@ -53,18 +71,22 @@ pub fn build_for_loop_cfg(
     // Add back edges from body exits to condition
     for &exit in &body_exits {
         if exit != after_id {
-            cfg.add_edge(CfgEdge::new(exit, condition_id, "back".to_string()));
+            cfg.add_edge(CfgEdge::new(exit, loop_head, "back".to_string()));
         }
     }
 
-    // Connect condition to after_loop (false branch)
-    cfg.add_edge(CfgEdge::new(condition_id, after_id, "false".to_string()));
+    // Connect condition to after_loop (false branch), through a Python `else:` block
+    process_loop_else(cfg, ctx, for_node, source, condition_id, after_id);
 
     vec![after_id]
 }
 
 /// Extract the body block from a for loop node.
 pub fn extract_for_body(for_node: Node) -> Option<Node> {
+    if let Some(body) = for_node.child_by_field_name("body") {
+        return Some(body);
+    }
+
     let mut cursor = for_node.walk();
 
     for child in for_node.named_children(&mut cursor) {
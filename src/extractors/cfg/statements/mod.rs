@@ -1,14 +1,42 @@
 /// Statement processors for CFG construction.
 pub mod for_cfg_builder;
 pub mod process_break_continue;
+pub mod process_comprehension;
+pub mod process_do_while;
 pub mod process_early_exit;
 pub mod process_for;
+pub mod process_label;
+pub mod process_labeled_block;
+pub mod process_let_else;
+pub mod process_loop;
+pub mod process_loop_else;
 pub mod process_match;
 pub mod process_match_arm;
+pub mod process_match_statement;
+pub mod process_suspend;
+pub mod process_switch;
+pub mod process_try;
 pub mod process_while;
+pub mod process_with;
 
 pub use process_break_continue::{process_break, process_continue};
+pub use process_comprehension::process_comprehensions;
+pub use process_do_while::process_do_while;
 pub use process_early_exit::{process_panic_expression, process_try_expression};
 pub use process_for::process_for;
+pub use process_label::{
+    is_goto_label, process_goto, process_goto_target, process_labeled_statement,
+    resolve_goto_labels,
+};
+pub use process_labeled_block::{is_labeled_block, process_labeled_block};
+pub use process_let_else::{mark_pattern_failures, process_let_else};
+pub use process_loop::process_loop;
 pub use process_match::process_match;
+pub use process_match_statement::process_match_statement;
+pub use process_suspend::{
+    contains_suspension_point, mark_resume_edges, process_suspension_points, suspension_scope,
+};
+pub use process_switch::process_switch;
+pub use process_try::process_try;
 pub use process_while::process_while;
+pub use process_with::process_with;
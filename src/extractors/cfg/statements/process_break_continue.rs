@@ -1,4 +1,4 @@
-use super::super::core::{CfgContext, LabelNormalizer};
+use super::super::core::{node_span, CfgContext, Jump, LabelNormalizer};
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
 
@@ -7,22 +7,24 @@ use tree_sitter::Node;
 pub fn process_break(
     cfg: &mut ControlFlowGraph,
     ctx: &mut CfgContext,
-    _break_node: Node,
-    _source: &str,
+    break_node: Node,
+    source: &str,
     entry: usize,
 ) -> Vec<usize> {
     // Create break node
     let break_id = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(break_id, LabelNormalizer::break_label()));
+    cfg.add_node(
+        CfgNode::new(break_id, LabelNormalizer::break_label()).with_span(node_span(break_node)),
+    );
     cfg.add_edge(CfgEdge::new(entry, break_id, "next".to_string()));
 
-    // Connect to current loop's after node
-    if let Some(loop_ctx) = ctx.current_loop() {
-        cfg.add_edge(CfgEdge::new(
-            break_id,
-            loop_ctx.after_id,
-            "break".to_string(),
-        ));
+    // Connect to the labeled or current loop's after node, through any finally blocks
+    let target = match jump_label(break_node, source) {
+        Some(label) => ctx.labeled_loop(&label),
+        None => ctx.innermost_breakable(),
+    };
+    if let Some(index) = target {
+        ctx.connect_jump(cfg, break_id, Jump::Break(index));
     }
 
     // Return empty vector as this path is terminated
@@ -34,24 +36,42 @@ pub fn process_break(
 pub fn process_continue(
     cfg: &mut ControlFlowGraph,
     ctx: &mut CfgContext,
-    _continue_node: Node,
-    _source: &str,
+    continue_node: Node,
+    source: &str,
     entry: usize,
 ) -> Vec<usize> {
     // Create continue node
     let continue_id = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(continue_id, LabelNormalizer::continue_label()));
+    cfg.add_node(
+        CfgNode::new(continue_id, LabelNormalizer::continue_label())
+            .with_span(node_span(continue_node)),
+    );
     cfg.add_edge(CfgEdge::new(entry, continue_id, "next".to_string()));
 
-    // Connect to current loop's condition node
-    if let Some(loop_ctx) = ctx.current_loop() {
-        cfg.add_edge(CfgEdge::new(
-            continue_id,
-            loop_ctx.condition_id,
-            "continue".to_string(),
-        ));
+    // Connect to the labeled or current loop's condition node, through any finally blocks
+    let target = match jump_label(continue_node, source) {
+        Some(label) => ctx.labeled_loop(&label),
+        None => ctx.innermost_loop(),
+    };
+    if let Some(index) = target {
+        ctx.connect_jump(cfg, continue_id, Jump::Continue(index));
     }
 
     // Return empty vector as this path is terminated
     vec![]
 }
+
+/// Extract the loop label of a `break`/`continue`: Rust `'outer`, JavaScript's
+/// `label` field, or Java's identifier.
+fn jump_label(jump_node: Node, source: &str) -> Option<String> {
+    let mut cursor = jump_node.walk();
+    let label = jump_node
+        .named_children(&mut cursor)
+        .find(|child| match child.kind() {
+            "label" | "statement_identifier" => true,
+            // A Rust `break value` carries an expression, not a label
+            "identifier" => jump_node.kind().ends_with("_statement"),
+            _ => false,
+        });
+    label.map(|label| source[label.start_byte()..label.end_byte()].to_string())
+}
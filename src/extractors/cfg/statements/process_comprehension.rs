@@ -0,0 +1,148 @@
+use super::super::core::{
+    get_statement_text, is_nested_scope, node_span, CfgContext, LabelNormalizer,
+};
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process the Python comprehensions and generator expressions evaluated by
+/// a node, in evaluation order, and return the first and last nodes.
+///
+/// Each comprehension is an implicit loop: a header per `for` clause tests
+/// whether its iterable has a next item, an `if` clause filter skips back to
+/// the innermost header, and the body produces one element per iteration.
+pub fn process_comprehensions(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    node: Node,
+    source: &str,
+    entry: usize,
+    edge_kind: &str,
+) -> Option<(usize, usize)> {
+    let mut comprehensions = Vec::new();
+    collect_comprehensions(node, &mut comprehensions);
+
+    let mut first = None;
+    let mut current = entry;
+    let mut edge_kind = edge_kind;
+    for comprehension in comprehensions {
+        let (comprehension_first, after_id) =
+            match process_comprehension(cfg, ctx, comprehension, source, current, edge_kind) {
+                Some(nodes) => nodes,
+                None => continue, // Incomplete expression
+            };
+        first.get_or_insert(comprehension_first);
+        current = after_id;
+        edge_kind = "next";
+    }
+    first.map(|first| (first, current))
+}
+
+/// Build the implicit loop of one comprehension and return its first header
+/// and the node after it.
+fn process_comprehension(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    comprehension: Node,
+    source: &str,
+    entry: usize,
+    edge_kind: &str,
+) -> Option<(usize, usize)> {
+    let body = comprehension.child_by_field_name("body")?;
+
+    // Label the clauses first, so an incomplete expression adds no nodes
+    let mut clauses = Vec::new();
+    let mut cursor = comprehension.walk();
+    for clause in comprehension.named_children(&mut cursor) {
+        let label = match clause.kind() {
+            "for_in_clause" => {
+                let pattern = clause.child_by_field_name("left")?;
+                let iterable = clause.child_by_field_name("right")?;
+                LabelNormalizer::comprehension_label(
+                    &expression_text(iterable, source),
+                    &expression_text(pattern, source),
+                )
+            }
+            "if_clause" => {
+                let condition = clause.named_child(0)?;
+                LabelNormalizer::comprehension_filter_label(&expression_text(condition, source))
+            }
+            _ => continue,
+        };
+        clauses.push((clause, label));
+    }
+    if clauses.first()?.0.kind() != "for_in_clause" {
+        return None;
+    }
+
+    let mut headers: Vec<usize> = Vec::new();
+    let mut current = entry;
+    let mut edge_kind = edge_kind.to_string();
+
+    for (clause, label) in clauses {
+        let clause_id = ctx.alloc_id();
+        cfg.add_node(CfgNode::new(clause_id, label).with_span(node_span(clause)));
+        cfg.add_edge(CfgEdge::new(current, clause_id, edge_kind));
+
+        // An exhausted inner loop resumes the enclosing one, and a
+        // filtered-out item moves on to the next one
+        if let Some(&innermost) = headers.last() {
+            cfg.add_edge(CfgEdge::new(clause_id, innermost, "false".to_string()));
+        }
+        if clause.kind() == "for_in_clause" {
+            headers.push(clause_id);
+        }
+        current = clause_id;
+        edge_kind = "true".to_string();
+    }
+
+    let (&outermost, &innermost) = (headers.first()?, headers.last()?);
+
+    // Create body node, producing one element per iteration
+    let body_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(body_id, LabelNormalizer::loop_body_label("comprehension"))
+            .with_span(node_span(body)),
+    );
+    cfg.add_edge(CfgEdge::new(current, body_id, edge_kind));
+    cfg.add_edge(CfgEdge::new(body_id, innermost, "back".to_string()));
+
+    // The comprehension completes once its outermost iterable is exhausted
+    let after_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(after_id, LabelNormalizer::loop_after_label("comprehension"))
+            .with_span(node_span(comprehension)),
+    );
+    cfg.add_edge(CfgEdge::new(outermost, after_id, "false".to_string()));
+
+    Some((outermost, after_id))
+}
+
+/// Collect the outermost comprehensions in a node, outside nested functions.
+fn collect_comprehensions<'a>(node: Node<'a>, comprehensions: &mut Vec<Node<'a>>) {
+    if is_nested_scope(node) {
+        return;
+    }
+    if is_comprehension(node) {
+        comprehensions.push(node);
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_comprehensions(child, comprehensions);
+    }
+}
+
+fn is_comprehension(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        "list_comprehension"
+            | "set_comprehension"
+            | "dictionary_comprehension"
+            | "generator_expression"
+    )
+}
+
+fn expression_text(expr: Node, source: &str) -> String {
+    let text = get_statement_text(expr, source);
+    text.trim_end_matches(';').to_string()
+}
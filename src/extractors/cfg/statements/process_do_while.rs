@@ -0,0 +1,76 @@
+use super::super::core::{get_statement_text, node_span, CfgContext, LabelNormalizer};
+use super::super::processors::process_body;
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process a do-while loop and return exit points.
+///
+/// The body runs once before the condition is first evaluated; `continue`
+/// jumps to the condition rather than the top of the body.
+pub fn process_do_while(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    do_node: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let (body, condition) = match (
+        do_node.child_by_field_name("body"),
+        do_node.child_by_field_name("condition"),
+    ) {
+        (Some(body), Some(condition)) => (body, condition),
+        _ => return vec![entry], // Incomplete loop, fallback
+    };
+
+    // Create body start node, entered unconditionally
+    let body_start_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(
+            body_start_id,
+            LabelNormalizer::loop_body_label("do_while_loop"),
+        )
+        .with_span(node_span(body)),
+    );
+    cfg.add_edge(CfgEdge::new(entry, body_start_id, "next".to_string()));
+
+    // Create condition node with normalized label
+    let condition = match condition.kind() {
+        "parenthesized_expression" => condition.named_child(0).unwrap_or(condition),
+        _ => condition,
+    };
+    let condition_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(
+            condition_id,
+            LabelNormalizer::do_while_loop_label(&get_statement_text(condition, source)),
+        )
+        .with_span(node_span(condition)),
+    );
+    ctx.conditions.push((condition_id, node_span(condition)));
+
+    // Create after-loop node (where false branch and breaks go)
+    let after_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(after_id, LabelNormalizer::loop_after_label("do_while_loop"))
+            .with_span(node_span(do_node)),
+    );
+
+    // Push loop context for break/continue handling
+    ctx.push_loop(condition_id, after_id);
+    let body_exits = process_body(cfg, ctx, body, source, body_start_id);
+    ctx.pop_loop();
+
+    for &exit in &body_exits {
+        cfg.add_edge(CfgEdge::new(exit, condition_id, "next".to_string()));
+    }
+
+    // Back edge to the body (true branch) and exit (false branch)
+    cfg.add_edge(CfgEdge::new(
+        condition_id,
+        body_start_id,
+        "back".to_string(),
+    ));
+    cfg.add_edge(CfgEdge::new(condition_id, after_id, "false".to_string()));
+
+    vec![after_id]
+}
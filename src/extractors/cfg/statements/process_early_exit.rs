@@ -1,4 +1,4 @@
-use super::super::core::CfgContext;
+use super::super::core::{node_span, CfgContext};
 use crate::analyzers::EarlyExitNormalizer;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph, EarlyExitIR};
 use tree_sitter::Node;
@@ -54,7 +54,7 @@ pub fn process_panic_expression(
 fn process_try_expression_cfg(
     cfg: &mut ControlFlowGraph,
     ctx: &mut CfgContext,
-    _try_node: Node,
+    try_node: Node,
     _source: &str,
     entry: usize,
     early_exit_ir: &EarlyExitIR,
@@ -62,12 +62,12 @@ fn process_try_expression_cfg(
     // Create try expression node
     let try_id = ctx.alloc_id();
     let try_label = format!("try_expr({})", early_exit_ir.trigger_expr);
-    cfg.add_node(CfgNode::new(try_id, try_label));
+    cfg.add_node(CfgNode::new(try_id, try_label).with_span(node_span(try_node)));
     cfg.add_edge(CfgEdge::new(entry, try_id, "next".to_string()));
 
     // Create continuation block for the "ok" path
     let ok_block_id = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(ok_block_id, "try_ok".to_string()));
+    cfg.add_node(CfgNode::new(ok_block_id, "try_ok".to_string()).with_span(node_span(try_node)));
 
     // Add two edges from try expression:
     // 1. Conditional edge to EXIT (error path)
@@ -83,7 +83,7 @@ fn process_try_expression_cfg(
 fn process_panic_expression_cfg(
     cfg: &mut ControlFlowGraph,
     ctx: &mut CfgContext,
-    _panic_node: Node,
+    panic_node: Node,
     _source: &str,
     entry: usize,
     early_exit_ir: &EarlyExitIR,
@@ -91,7 +91,7 @@ fn process_panic_expression_cfg(
     // Create panic node
     let panic_id = ctx.alloc_id();
     let panic_label = format!("panic_expr({})", early_exit_ir.trigger_expr);
-    cfg.add_node(CfgNode::new(panic_id, panic_label));
+    cfg.add_node(CfgNode::new(panic_id, panic_label).with_span(node_span(panic_node)));
     cfg.add_edge(CfgEdge::new(entry, panic_id, "next".to_string()));
 
     // Add exceptional edge to EXIT (or synthetic unwind)
@@ -0,0 +1,136 @@
+use super::super::core::{get_statement_text, node_span, CfgContext};
+use super::super::processors::{is_loop_node, process_body};
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process a labeled statement and return exit points.
+///
+/// A C/C++ label is a goto target that the preceding statement falls into.
+/// A Java or JavaScript label names the loop or switch it prefixes, so a
+/// labeled `break`/`continue` can reach past the innermost loop.
+pub fn process_labeled_statement(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    labeled_node: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let (name, body) = match label_parts(labeled_node, source) {
+        Some(parts) => parts,
+        None => return vec![entry], // No labeled statement found, fallback
+    };
+
+    if is_goto_label(labeled_node) {
+        let label_id = goto_target(cfg, ctx, labeled_node, &name);
+        cfg.add_edge(CfgEdge::new(entry, label_id, "next".to_string()));
+        return process_body(cfg, ctx, body, source, label_id);
+    }
+
+    // A label on anything but a loop or switch names nothing we track
+    if is_loop_node(body) || body.kind() == "switch_statement" {
+        ctx.loop_label = Some(name);
+    }
+    let exits = process_body(cfg, ctx, body, source, entry);
+    ctx.loop_label = None;
+    exits
+}
+
+/// Resume processing at a goto label that follows a return, break or goto,
+/// where the label is reachable only through a goto.
+pub fn process_goto_target(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    labeled_node: Node,
+    source: &str,
+) -> Vec<usize> {
+    match label_parts(labeled_node, source) {
+        Some((name, body)) => {
+            let label_id = goto_target(cfg, ctx, labeled_node, &name);
+            process_body(cfg, ctx, body, source, label_id)
+        }
+        None => vec![],
+    }
+}
+
+/// Process a goto statement and return empty exit points (path terminated).
+pub fn process_goto(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    goto_node: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let goto_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(goto_id, get_statement_text(goto_node, source))
+            .with_span(node_span(goto_node)),
+    );
+    cfg.add_edge(CfgEdge::new(entry, goto_id, "next".to_string()));
+
+    // The label may come later in the function, so its node is created on demand
+    if let Some(label) = goto_node.child_by_field_name("label") {
+        let label_id = ctx.goto_label(cfg, &source[label.start_byte()..label.end_byte()]);
+        cfg.add_edge(CfgEdge::new(goto_id, label_id, "goto".to_string()));
+    }
+
+    vec![]
+}
+
+/// Send gotos whose label is never defined in the function to EXIT along
+/// `unresolved_goto` edges, dropping the label nodes they created.
+pub fn resolve_goto_labels(cfg: &mut ControlFlowGraph, ctx: &CfgContext) {
+    let undefined: Vec<usize> = ctx
+        .goto_labels
+        .values()
+        .copied()
+        .filter(|label_id| !ctx.defined_goto_labels.contains(label_id))
+        .collect();
+    if undefined.is_empty() {
+        return;
+    }
+
+    cfg.nodes.retain(|node| !undefined.contains(&node.cfg_node));
+    for edge in &mut cfg.edges {
+        if undefined.contains(&edge.to) {
+            edge.to = ctx.exit_id;
+            edge.kind = "unresolved_goto".to_string();
+        }
+    }
+}
+
+/// Get the node of a goto label at its definition.
+fn goto_target(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    labeled_node: Node,
+    name: &str,
+) -> usize {
+    let label_id = ctx.goto_label(cfg, name);
+    ctx.defined_goto_labels.insert(label_id);
+
+    // An earlier goto may have created the node before its label was seen
+    if let Some(node) = cfg.nodes.iter_mut().find(|node| node.cfg_node == label_id) {
+        node.span = Some(node_span(labeled_node));
+    }
+    label_id
+}
+
+/// Check whether a labeled statement is a C/C++ goto target. JavaScript labels
+/// have a `body` field and Java labels have neither field.
+pub fn is_goto_label(labeled_node: Node) -> bool {
+    labeled_node.kind() == "labeled_statement"
+        && labeled_node.child_by_field_name("label").is_some()
+        && labeled_node.child_by_field_name("body").is_none()
+}
+
+/// Extract the label name and the statement it labels.
+fn label_parts<'a>(labeled_node: Node<'a>, source: &str) -> Option<(String, Node<'a>)> {
+    let mut cursor = labeled_node.walk();
+    let mut children = labeled_node.named_children(&mut cursor);
+    let label = children.next()?;
+    let body = children.find(|child| child.kind() != "comment")?;
+    Some((
+        source[label.start_byte()..label.end_byte()].to_string(),
+        body,
+    ))
+}
@@ -0,0 +1,70 @@
+use super::super::core::{node_span, CfgContext, LabelNormalizer};
+use super::super::processors::process_block;
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process a Rust labeled block `'a: { ... }` and return exit points.
+///
+/// A `break 'a value` leaves the block early, for the node after it, where
+/// the end of the block also goes. Unlabeled breaks still exit the enclosing
+/// loop.
+pub fn process_labeled_block(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    block: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let label = match block_label(block, source) {
+        Some(label) => label,
+        None => return process_block(cfg, ctx, block, source, entry),
+    };
+
+    let block_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(block_id, LabelNormalizer::labeled_block_label(&label))
+            .with_span(node_span(block)),
+    );
+    cfg.add_edge(CfgEdge::new(entry, block_id, "next".to_string()));
+
+    // Create after-block node (where the block's breaks go)
+    let after_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(after_id, LabelNormalizer::loop_after_label("labeled_block"))
+            .with_span(node_span(block)),
+    );
+
+    ctx.loop_label = Some(label);
+    ctx.push_labeled_block(after_id);
+    let exits = process_block(cfg, ctx, block, source, block_id);
+    ctx.pop_loop();
+
+    for &exit in &exits {
+        cfg.add_edge(CfgEdge::new(exit, after_id, "next".to_string()));
+    }
+
+    // A block every path jumps out of without breaking never completes
+    if cfg.edges.iter().any(|edge| edge.to == after_id) {
+        vec![after_id]
+    } else {
+        vec![]
+    }
+}
+
+/// Check whether a node is a Rust block with a `'label:`.
+pub fn is_labeled_block(node: Node) -> bool {
+    node.kind() == "block" && label_node(node).is_some()
+}
+
+fn block_label(block: Node, source: &str) -> Option<String> {
+    let label = label_node(block)?;
+    Some(source[label.start_byte()..label.end_byte()].to_string())
+}
+
+fn label_node(block: Node) -> Option<Node> {
+    let mut cursor = block.walk();
+    let label = block
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "label");
+    label
+}
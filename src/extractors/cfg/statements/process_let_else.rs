@@ -0,0 +1,73 @@
+use super::super::core::{
+    get_statement_text, node_span, retag_branch_edges, CfgContext, LabelNormalizer,
+};
+use super::super::processors::process_block;
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process a Rust `let ... else` and return exit points.
+///
+/// The let node tests its pattern and continues when it matches. Otherwise
+/// the else block runs, which must diverge, so it never rejoins the code after
+/// the let.
+pub fn process_let_else(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    let_node: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let (pattern, value, else_block) = match (
+        let_node.child_by_field_name("pattern"),
+        let_node.child_by_field_name("value"),
+        let_node.child_by_field_name("alternative"),
+    ) {
+        (Some(pattern), Some(value), Some(else_block)) => (pattern, value, else_block),
+        _ => return vec![entry], // Incomplete statement, fallback
+    };
+
+    let let_id = ctx.alloc_id();
+    let label = LabelNormalizer::let_else_label(
+        &expression_text(pattern, source),
+        &expression_text(value, source),
+    );
+    cfg.add_node(CfgNode::new(let_id, label).with_span(node_span(let_node)));
+    cfg.add_edge(CfgEdge::new(entry, let_id, "next".to_string()));
+
+    // The else block is entered when the pattern fails to match
+    let edges_before = cfg.edges.len();
+    let else_exits = process_block(cfg, ctx, else_block, source, let_id);
+    retag_branch_edges(cfg, let_id, edges_before, "no_match");
+
+    // The block must diverge, through calls not recognized as diverging if not seen here
+    for exit in else_exits {
+        cfg.add_edge(CfgEdge::new(exit, ctx.exit_id, "exit".to_string()));
+    }
+
+    vec![let_id]
+}
+
+/// Turn the `false` edges leaving Rust `if let` and `while let` pattern tests
+/// into `no_match` edges, matching the else branch of a `let ... else`.
+pub fn mark_pattern_failures(cfg: &mut ControlFlowGraph, body: Node) {
+    let pattern_tests: Vec<usize> = cfg
+        .nodes
+        .iter()
+        .filter_map(|node| {
+            let span = node.span?;
+            let test = body.descendant_for_byte_range(span.start_byte, span.end_byte)?;
+            (test.kind() == "let_condition").then_some(node.cfg_node)
+        })
+        .collect();
+
+    for edge in &mut cfg.edges {
+        if edge.kind == "false" && pattern_tests.contains(&edge.from) {
+            edge.kind = "no_match".to_string();
+        }
+    }
+}
+
+fn expression_text(expr: Node, source: &str) -> String {
+    let text = get_statement_text(expr, source);
+    text.trim_end_matches(';').to_string()
+}
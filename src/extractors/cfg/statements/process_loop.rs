@@ -0,0 +1,49 @@
+use super::super::core::{node_span, CfgContext, LabelNormalizer};
+use super::super::processors::process_block;
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process a Rust `loop` expression and return exit points.
+///
+/// The loop has no condition, so only a `break` reaches the code after it.
+pub fn process_loop(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    loop_node: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let body = match loop_node.child_by_field_name("body") {
+        Some(node) => node,
+        None => return vec![entry], // No body found, fallback
+    };
+
+    // Create loop header node, where the body starts and continues go
+    let header_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(header_id, LabelNormalizer::infinite_loop_label()).with_span(node_span(body)),
+    );
+    cfg.add_edge(CfgEdge::new(entry, header_id, "next".to_string()));
+
+    // Create after-loop node (where breaks go)
+    let after_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(after_id, LabelNormalizer::loop_after_label("loop"))
+            .with_span(node_span(loop_node)),
+    );
+
+    ctx.push_loop(header_id, after_id);
+    let body_exits = process_block(cfg, ctx, body, source, header_id);
+    ctx.pop_loop();
+
+    for &exit in &body_exits {
+        cfg.add_edge(CfgEdge::new(exit, header_id, "back".to_string()));
+    }
+
+    // A loop nothing breaks out of never completes
+    if cfg.edges.iter().any(|edge| edge.to == after_id) {
+        vec![after_id]
+    } else {
+        vec![]
+    }
+}
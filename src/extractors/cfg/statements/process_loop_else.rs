@@ -0,0 +1,44 @@
+use super::super::core::{node_span, CfgContext, LabelNormalizer};
+use super::super::processors::process_block;
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Connect a loop condition's false edge to the code after the loop.
+///
+/// A Python `for`/`while` loop with an `else:` block runs it when the loop is
+/// exhausted, before reaching the code after the loop. A `break` skips it.
+pub fn process_loop_else(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    loop_node: Node,
+    source: &str,
+    condition_id: usize,
+    after_id: usize,
+) {
+    let else_body = loop_node
+        .child_by_field_name("alternative")
+        .filter(|alternative| alternative.kind() == "else_clause")
+        .and_then(|alternative| alternative.child_by_field_name("body"));
+    let else_body = match else_body {
+        Some(body) => body,
+        None => {
+            cfg.add_edge(CfgEdge::new(condition_id, after_id, "false".to_string()));
+            return;
+        }
+    };
+
+    let loop_type = match loop_node.kind() {
+        "while_statement" => "while_loop",
+        _ => "for_loop",
+    };
+    let else_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(else_id, LabelNormalizer::loop_else_label(loop_type))
+            .with_span(node_span(else_body)),
+    );
+    cfg.add_edge(CfgEdge::new(condition_id, else_id, "false".to_string()));
+
+    for exit in process_block(cfg, ctx, else_body, source, else_id) {
+        cfg.add_edge(CfgEdge::new(exit, after_id, "next".to_string()));
+    }
+}
@@ -1,4 +1,4 @@
-use super::super::core::{get_statement_text, CfgContext, LabelNormalizer};
+use super::super::core::{get_statement_text, node_span, CfgContext, LabelNormalizer};
 use super::process_match_arm::process_match_arm;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
@@ -42,15 +42,18 @@ pub fn process_match(
     // Create match dispatch node
     let dispatch_id = ctx.alloc_id();
     let expr_text = get_statement_text(expr, source);
-    cfg.add_node(CfgNode::new(
-        dispatch_id,
-        LabelNormalizer::match_label(&expr_text),
-    ));
+    cfg.add_node(
+        CfgNode::new(dispatch_id, LabelNormalizer::match_label(&expr_text))
+            .with_span(node_span(expr)),
+    );
     cfg.add_edge(CfgEdge::new(entry, dispatch_id, "next".to_string()));
 
     // Create join node
     let join_id = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(join_id, LabelNormalizer::join_label("match")));
+    cfg.add_node(
+        CfgNode::new(join_id, LabelNormalizer::join_label("match"))
+            .with_span(node_span(match_node)),
+    );
 
     // Process each match arm
     let mut arm_exits = Vec::new();
@@ -1,5 +1,8 @@
-use super::super::core::{get_statement_text, CfgContext, LabelNormalizer};
-use super::super::processors::process_block;
+use super::super::core::{
+    get_statement_text, is_statement_node, node_span, CfgContext, LabelNormalizer,
+};
+use super::super::processors::{process_block, process_statement};
+use crate::analyzers::language_specific::RustEarlyExitAnalyzer;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
 
@@ -38,25 +41,43 @@ pub fn process_match_arm(
         Some(body_node) => {
             // Create arm start node
             let arm_start_id = ctx.alloc_id();
-            cfg.add_node(CfgNode::new(
-                arm_start_id,
-                LabelNormalizer::match_arm_label(&pattern_text),
-            ));
+            cfg.add_node(
+                CfgNode::new(
+                    arm_start_id,
+                    LabelNormalizer::match_arm_label(&pattern_text),
+                )
+                .with_span(node_span(arm_node)),
+            );
             cfg.add_edge(CfgEdge::new(dispatch_id, arm_start_id, pattern_text));
 
             // Process the arm body
-            let exits = process_block(cfg, ctx, body_node, source, arm_start_id);
-            exits
+            process_block(cfg, ctx, body_node, source, arm_start_id)
         }
         None => {
             // Expression arm (no block)
             let arm_id = ctx.alloc_id();
-            cfg.add_node(CfgNode::new(
-                arm_id,
-                LabelNormalizer::match_arm_label(&pattern_text),
-            ));
+            cfg.add_node(
+                CfgNode::new(arm_id, LabelNormalizer::match_arm_label(&pattern_text))
+                    .with_span(node_span(arm_node)),
+            );
             cfg.add_edge(CfgEdge::new(dispatch_id, arm_id, pattern_text));
-            vec![arm_id]
+
+            // An arm that returns, breaks or branches is processed like a statement
+            match arm_node.child_by_field_name("value") {
+                Some(value) if is_arm_statement(value, source, ctx) => {
+                    process_statement(cfg, ctx, value, source, arm_id)
+                        .into_iter()
+                        .collect()
+                }
+                _ => vec![arm_id],
+            }
         }
     }
 }
+
+/// Check whether an expression arm's value has control flow of its own.
+fn is_arm_statement(value: Node, source: &str, ctx: &CfgContext) -> bool {
+    is_statement_node(value)
+        || matches!(value.kind(), "break_expression" | "continue_expression")
+        || RustEarlyExitAnalyzer::is_diverging_call(value, source, &ctx.diverging_functions)
+}
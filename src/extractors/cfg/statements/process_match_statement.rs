@@ -0,0 +1,145 @@
+use super::super::core::{
+    get_statement_text, node_span, retag_branch_edges, CfgContext, LabelNormalizer,
+};
+use super::super::processors::process_block;
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process a Python `match` statement and return exit points.
+///
+/// Creates a dispatch node with one edge per case pattern, tried in order. A
+/// case whose guard fails moves on to the next case. Without an irrefutable
+/// case such as `case _:`, a subject matching no case skips the statement.
+pub fn process_match_statement(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    match_node: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let (subject, body) = match (
+        match_node.child_by_field_name("subject"),
+        match_node.child_by_field_name("body"),
+    ) {
+        (Some(subject), Some(body)) => (subject, body),
+        _ => return vec![entry], // Incomplete statement, fallback
+    };
+
+    // Create match dispatch node
+    let dispatch_id = ctx.alloc_id();
+    let subject_text = expression_text(subject, source);
+    cfg.add_node(
+        CfgNode::new(dispatch_id, LabelNormalizer::match_label(&subject_text))
+            .with_span(node_span(subject)),
+    );
+    cfg.add_edge(CfgEdge::new(entry, dispatch_id, "next".to_string()));
+
+    // Create join node where the cases meet
+    let join_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(join_id, LabelNormalizer::join_label("match"))
+            .with_span(node_span(match_node)),
+    );
+
+    let mut cursor = body.walk();
+    let cases: Vec<Node> = body
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "case_clause")
+        .collect();
+
+    let mut exits = Vec::new();
+    let mut failed_guard = None;
+    let mut irrefutable = false;
+
+    for case in cases {
+        let pattern = case_pattern(case, source);
+        let case_id = ctx.alloc_id();
+        cfg.add_node(
+            CfgNode::new(case_id, LabelNormalizer::match_arm_label(&pattern))
+                .with_span(node_span(case)),
+        );
+        cfg.add_edge(CfgEdge::new(dispatch_id, case_id, pattern.clone()));
+
+        // The previous case matched but its guard failed
+        if let Some(guard_id) = failed_guard.take() {
+            cfg.add_edge(CfgEdge::new(guard_id, case_id, "false".to_string()));
+        }
+
+        let guard = case
+            .child_by_field_name("guard")
+            .and_then(|guard| guard.named_child(0));
+        let body_entry = match guard {
+            Some(guard) => {
+                let guard_id = ctx.alloc_id();
+                let condition = expression_text(guard, source);
+                cfg.add_node(
+                    CfgNode::new(guard_id, LabelNormalizer::case_guard_label(&condition))
+                        .with_span(node_span(guard)),
+                );
+                cfg.add_edge(CfgEdge::new(case_id, guard_id, "next".to_string()));
+                failed_guard = Some(guard_id);
+                guard_id
+            }
+            None => {
+                irrefutable |= is_irrefutable(&pattern);
+                case_id
+            }
+        };
+
+        match case.child_by_field_name("consequence") {
+            Some(consequence) => {
+                let edges_before = cfg.edges.len();
+                exits.extend(process_block(cfg, ctx, consequence, source, body_entry));
+                if body_entry != case_id {
+                    retag_branch_edges(cfg, body_entry, edges_before, "true");
+                }
+            }
+            None => exits.push(body_entry),
+        }
+
+        // Later cases are unreachable once one always matches
+        if irrefutable {
+            break;
+        }
+    }
+
+    let is_reachable = !exits.is_empty() || !irrefutable || failed_guard.is_some();
+    if let Some(guard_id) = failed_guard {
+        cfg.add_edge(CfgEdge::new(guard_id, join_id, "false".to_string()));
+    }
+    if !irrefutable {
+        cfg.add_edge(CfgEdge::new(dispatch_id, join_id, "no_match".to_string()));
+    }
+    for &exit in &exits {
+        cfg.add_edge(CfgEdge::new(exit, join_id, "next".to_string()));
+    }
+
+    if is_reachable {
+        vec![join_id]
+    } else {
+        vec![] // Every case returns, raises or jumps
+    }
+}
+
+/// Text of a case's patterns; `case 1, 2:` matches a two-element sequence.
+fn case_pattern(case: Node, source: &str) -> String {
+    let mut cursor = case.walk();
+    let patterns: Vec<String> = case
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "case_pattern")
+        .map(|pattern| expression_text(pattern, source))
+        .collect();
+    patterns.join(", ")
+}
+
+/// Check for a wildcard `_` or a bare capture pattern, which match any subject.
+fn is_irrefutable(pattern: &str) -> bool {
+    let is_name = pattern.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && pattern.chars().all(|c| c.is_alphanumeric() || c == '_');
+    is_name && !matches!(pattern, "None" | "True" | "False")
+}
+
+fn expression_text(expr: Node, source: &str) -> String {
+    let text = get_statement_text(expr, source);
+    text.trim_end_matches(';').to_string()
+}
@@ -0,0 +1,177 @@
+use super::super::core::{
+    get_statement_text, is_nested_scope, node_span, CfgContext, LabelNormalizer,
+};
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process the `await` and `yield` expressions evaluated by a node, in
+/// evaluation order, and return the first and last suspension points.
+///
+/// Each suspension point has a `suspend` edge to EXIT, where control returns
+/// to the caller or executor. Its normal successors become `resume` edges
+/// once the CFG is built, see [`mark_resume_edges`].
+pub fn process_suspension_points(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    node: Node,
+    source: &str,
+    entry: usize,
+    edge_kind: &str,
+) -> Option<(usize, usize)> {
+    let mut suspensions = Vec::new();
+    collect_suspensions(node, &mut suspensions);
+
+    let mut first = None;
+    let mut current = entry;
+    let mut edge_kind = edge_kind;
+    for suspension in suspensions {
+        let label = suspension_label(suspension, source);
+        current = add_suspension_point(cfg, ctx, label, suspension, current, edge_kind);
+        first.get_or_insert(current);
+        edge_kind = "next";
+    }
+    first.map(|first| (first, current))
+}
+
+/// Create a suspension point reached from `entry` over an `edge_kind` edge.
+pub fn add_suspension_point(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    label: String,
+    node: Node,
+    entry: usize,
+    edge_kind: &str,
+) -> usize {
+    let suspension_id = ctx.alloc_id();
+    cfg.add_node(CfgNode::new(suspension_id, label).with_span(node_span(node)));
+    cfg.add_edge(CfgEdge::new(entry, suspension_id, edge_kind.to_string()));
+    mark_suspension_point(cfg, ctx, suspension_id);
+    suspension_id
+}
+
+/// Make a node a suspension point, with a `suspend` edge to EXIT.
+pub fn mark_suspension_point(cfg: &mut ControlFlowGraph, ctx: &mut CfgContext, node_id: usize) {
+    cfg.add_edge(CfgEdge::new(node_id, ctx.exit_id, "suspend".to_string()));
+    ctx.suspension_points.push(node_id);
+}
+
+/// Turn the `next` edges leaving suspension points into `resume` edges.
+pub fn mark_resume_edges(cfg: &mut ControlFlowGraph, ctx: &CfgContext) {
+    for edge in &mut cfg.edges {
+        if edge.kind == "next" && ctx.suspension_points.contains(&edge.from) {
+            edge.kind = "resume".to_string();
+        }
+    }
+}
+
+/// Find the part of a statement evaluated before control enters it, whose
+/// suspension points run first. Loop conditions, which run on every
+/// iteration, and compound bodies are handled by their own processors.
+pub fn suspension_scope(stmt: Node) -> Option<Node> {
+    match stmt.kind() {
+        "if_statement" | "if_expression" => stmt.child_by_field_name("condition"),
+        "switch_statement" | "switch_expression" => stmt
+            .child_by_field_name("condition")
+            .or_else(|| stmt.child_by_field_name("value")),
+        "match_expression" => stmt.child_by_field_name("value"),
+        "match_statement" => stmt.child_by_field_name("subject"),
+        // The iterable is evaluated once, before the first iteration
+        "for_statement" | "for_in_statement" => stmt.child_by_field_name("right"),
+        "for_expression" | "enhanced_for_statement" => stmt.child_by_field_name("value"),
+        "while_statement"
+        | "while_expression"
+        | "loop_expression"
+        | "do_statement"
+        | "try_statement"
+        | "try_with_resources_statement"
+        | "with_statement"
+        | "labeled_statement"
+        | "block"
+        | "compound_statement"
+        | "statement_block" => None,
+        // Rust control flow wrapped in an expression statement
+        "expression_statement" => match stmt.named_child(0) {
+            Some(expr) if is_control_flow(expr) => suspension_scope(expr),
+            _ => Some(stmt),
+        },
+        _ => Some(stmt),
+    }
+}
+
+/// Check whether a node contains an `await` or `yield` outside nested functions.
+pub fn contains_suspension_point(node: Node) -> bool {
+    let mut suspensions = Vec::new();
+    collect_suspensions(node, &mut suspensions);
+    !suspensions.is_empty()
+}
+
+/// Check whether a Python `for`/`with` or JavaScript `for await` is asynchronous.
+pub fn is_async(node: Node) -> bool {
+    let mut cursor = node.walk();
+    let is_async = node
+        .children(&mut cursor)
+        .any(|child| !child.is_named() && matches!(child.kind(), "async" | "await"));
+    is_async
+}
+
+fn is_control_flow(expr: Node) -> bool {
+    matches!(
+        expr.kind(),
+        "if_expression"
+            | "match_expression"
+            | "while_expression"
+            | "for_expression"
+            | "loop_expression"
+    )
+}
+
+/// Collect suspension expressions in post-order, so an `await` nested in
+/// another's operand comes first.
+fn collect_suspensions<'a>(node: Node<'a>, suspensions: &mut Vec<Node<'a>>) {
+    // Nested functions and async blocks suspend on their own
+    if is_nested_scope(node) || node.kind() == "async_block" {
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_suspensions(child, suspensions);
+    }
+
+    if is_suspension(node) {
+        suspensions.push(node);
+    }
+}
+
+fn is_suspension(node: Node) -> bool {
+    matches!(
+        node.kind(),
+        // Python `await`/`yield`, JavaScript/TypeScript and Rust expressions
+        "await" | "yield" | "await_expression" | "yield_expression"
+    )
+}
+
+fn suspension_label(node: Node, source: &str) -> String {
+    let value = node
+        .named_child(0)
+        .map(|value| get_statement_text(value, source))
+        .map(|value| value.trim_end_matches(';').to_string());
+    match node.kind() {
+        "await" | "await_expression" => {
+            LabelNormalizer::await_label(value.as_deref().unwrap_or(""))
+        }
+        _ if is_delegating_yield(node) => {
+            LabelNormalizer::yield_from_label(value.as_deref().unwrap_or(""))
+        }
+        _ => LabelNormalizer::yield_label(value.as_deref()),
+    }
+}
+
+/// Check for Python `yield from` or JavaScript `yield*`.
+fn is_delegating_yield(node: Node) -> bool {
+    let mut cursor = node.walk();
+    let is_delegating = node
+        .children(&mut cursor)
+        .any(|child| !child.is_named() && matches!(child.kind(), "from" | "*"));
+    is_delegating
+}
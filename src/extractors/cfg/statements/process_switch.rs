@@ -0,0 +1,196 @@
+use super::super::core::{node_span, CfgContext, LabelNormalizer};
+use super::super::processors::process_block;
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// A `case`/`default` label and the node holding its statements.
+struct SwitchCase<'a> {
+    /// Case value text, or `None` for `default`
+    value: Option<String>,
+    /// Node whose children are the case's statements
+    body: Node<'a>,
+    /// The whole case clause
+    clause: Node<'a>,
+    /// Java `case X ->` rules never fall through
+    is_rule: bool,
+}
+
+/// Process a switch statement or expression and return exit points.
+///
+/// Creates a dispatch node with one edge per case label. A case that ends
+/// without `break` falls through into the next case. There are no exit
+/// points when no case leaves the switch.
+pub fn process_switch(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    switch_node: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let body = match switch_node.child_by_field_name("body") {
+        Some(node) => node,
+        None => return vec![entry], // No body found, fallback
+    };
+    let condition = switch_node
+        .child_by_field_name("condition")
+        .or_else(|| switch_node.child_by_field_name("value"));
+    let value = condition
+        .map(|condition| switch_value(condition, source))
+        .unwrap_or_default();
+    let value_node = condition.unwrap_or(switch_node);
+
+    // Create switch dispatch node
+    let dispatch_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(dispatch_id, LabelNormalizer::switch_label(&value))
+            .with_span(node_span(value_node)),
+    );
+    cfg.add_edge(CfgEdge::new(entry, dispatch_id, "next".to_string()));
+
+    // Create after-switch node (where breaks go)
+    let after_id = ctx.alloc_id();
+    cfg.add_node(
+        CfgNode::new(after_id, LabelNormalizer::loop_after_label("switch"))
+            .with_span(node_span(switch_node)),
+    );
+
+    ctx.push_switch(after_id);
+
+    let cases = switch_cases(body, source);
+    let mut fallthrough = Vec::new();
+
+    for case in &cases {
+        let case_id = ctx.alloc_id();
+        cfg.add_node(
+            CfgNode::new(case_id, LabelNormalizer::case_label(case.value.as_deref()))
+                .with_span(node_span(case.clause)),
+        );
+        let edge_kind = case.value.clone().unwrap_or_else(|| "default".to_string());
+        cfg.add_edge(CfgEdge::new(dispatch_id, case_id, edge_kind));
+
+        // The previous case ran off its end without a break
+        for &exit in &fallthrough {
+            cfg.add_edge(CfgEdge::new(exit, case_id, "fallthrough".to_string()));
+        }
+
+        let exits = process_block(cfg, ctx, case.body, source, case_id);
+        if case.is_rule {
+            for &exit in &exits {
+                cfg.add_edge(CfgEdge::new(exit, after_id, "next".to_string()));
+            }
+            fallthrough = Vec::new();
+        } else {
+            fallthrough = exits;
+        }
+    }
+
+    ctx.pop_loop();
+
+    // The last case falls out of the switch
+    for &exit in &fallthrough {
+        cfg.add_edge(CfgEdge::new(exit, after_id, "next".to_string()));
+    }
+
+    // Without a default, a value matching no case skips the switch
+    if cases.iter().all(|case| case.value.is_some()) {
+        cfg.add_edge(CfgEdge::new(dispatch_id, after_id, "no_match".to_string()));
+    }
+
+    // Every case returns, throws or jumps elsewhere
+    if !cfg.edges.iter().any(|edge| edge.to == after_id) {
+        cfg.nodes.retain(|node| node.cfg_node != after_id);
+        return vec![];
+    }
+
+    vec![after_id]
+}
+
+/// Collect the cases of a C/C++ `compound_statement`, JavaScript `switch_body`,
+/// or Java `switch_block`.
+fn switch_cases<'a>(body: Node<'a>, source: &str) -> Vec<SwitchCase<'a>> {
+    let mut cases = Vec::new();
+    let mut cursor = body.walk();
+
+    for child in body.named_children(&mut cursor) {
+        match child.kind() {
+            // C/C++ `case X:` / `default:` and JavaScript `case X:`
+            "case_statement" | "switch_case" => cases.push(SwitchCase {
+                value: child
+                    .child_by_field_name("value")
+                    .map(|value| node_text(value, source)),
+                body: child,
+                clause: child,
+                is_rule: false,
+            }),
+            "switch_default" => cases.push(SwitchCase {
+                value: None,
+                body: child,
+                clause: child,
+                is_rule: false,
+            }),
+            // Java `case X:` groups and `case X ->` rules
+            "switch_block_statement_group" | "switch_rule" => {
+                let mut label_cursor = child.walk();
+                let labels: Vec<String> = child
+                    .named_children(&mut label_cursor)
+                    .filter(|label| label.kind() == "switch_label")
+                    .map(|label| node_text(label, source))
+                    .collect();
+                let value = if labels.iter().any(|label| label.ends_with("default")) {
+                    None
+                } else {
+                    Some(
+                        labels
+                            .iter()
+                            .map(|label| label.trim_start_matches("case").trim())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    )
+                };
+
+                // A rule's body is a block, an expression, or a throw
+                let is_rule = child.kind() == "switch_rule";
+                let mut body_cursor = child.walk();
+                let rule_block = child
+                    .named_children(&mut body_cursor)
+                    .find(|rule_body| is_rule && rule_body.kind() == "block");
+
+                cases.push(SwitchCase {
+                    value,
+                    body: rule_block.unwrap_or(child),
+                    clause: child,
+                    is_rule,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    cases
+}
+
+/// Text of the switched-on value without its parentheses.
+fn switch_value(condition: Node, source: &str) -> String {
+    // C++ `switch (init; value)`
+    let value = match condition.kind() {
+        "condition_clause" => condition.child_by_field_name("value").unwrap_or(condition),
+        _ => condition,
+    };
+    let text = node_text(value, source);
+    match text
+        .strip_prefix('(')
+        .and_then(|text| text.strip_suffix(')'))
+    {
+        Some(inner) => inner.trim().to_string(),
+        None => text,
+    }
+}
+
+fn node_text(node: Node, source: &str) -> String {
+    source
+        .get(node.start_byte()..node.end_byte())
+        .unwrap_or("")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,197 @@
+use super::super::core::{node_span, CfgContext, Jump, LabelNormalizer};
+use super::super::processors::process_block;
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process a try statement and return exit points.
+///
+/// Builds the guarded block, one handler per `except`/`catch` clause, Python's
+/// `else:` block, and a `finally` block that runs on the normal path and on
+/// every return, break, continue, or throw out of the try.
+pub fn process_try(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    try_node: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let body = match try_node.child_by_field_name("body") {
+        Some(node) => node,
+        None => return vec![entry], // No guarded block found, fallback
+    };
+
+    let mut handlers = Vec::new();
+    let mut else_body = None;
+    let mut finally_body = None;
+    let mut cursor = try_node.walk();
+
+    for child in try_node.named_children(&mut cursor) {
+        match child.kind() {
+            "except_clause" | "except_group_clause" | "catch_clause" => handlers.push(child),
+            "else_clause" => else_body = clause_body(child),
+            "finally_clause" => finally_body = clause_body(child),
+            _ => {}
+        }
+    }
+
+    // Create try node
+    let try_id = ctx.alloc_id();
+    cfg.add_node(CfgNode::new(try_id, LabelNormalizer::try_label()).with_span(node_span(try_node)));
+    cfg.add_edge(CfgEdge::new(entry, try_id, "next".to_string()));
+
+    // Handler nodes exist before the guarded block so throws inside it can reach them
+    let mut handler_ids = Vec::new();
+    let mut has_catch_all = false;
+    for &handler in &handlers {
+        let exception_type = exception_type(handler, source);
+        let handler_id = ctx.alloc_id();
+        cfg.add_node(
+            CfgNode::new(
+                handler_id,
+                LabelNormalizer::catch_label(exception_type.as_deref()),
+            )
+            .with_span(node_span(handler)),
+        );
+        let edge_kind = match &exception_type {
+            Some(exception_type) => format!("exception({})", exception_type),
+            None => {
+                has_catch_all = true;
+                "exception".to_string()
+            }
+        };
+        cfg.add_edge(CfgEdge::new(try_id, handler_id, edge_kind));
+        handler_ids.push(handler_id);
+    }
+
+    let finally_id = finally_body.map(|finally_node| {
+        let finally_id = ctx.alloc_id();
+        cfg.add_node(
+            CfgNode::new(finally_id, LabelNormalizer::finally_label())
+                .with_span(node_span(finally_node)),
+        );
+        finally_id
+    });
+
+    // Process the guarded block
+    ctx.push_try(handler_ids.clone(), finally_id);
+    let mut exits = process_block(cfg, ctx, body, source, try_id);
+
+    // Neither the else block nor the handlers are guarded by the handlers
+    if let Some(try_ctx) = ctx.try_stack.last_mut() {
+        try_ctx.handler_ids.clear();
+    }
+
+    // The else block runs only when the guarded block completes normally
+    if let Some(else_node) = else_body {
+        if !exits.is_empty() {
+            let else_id = ctx.alloc_id();
+            cfg.add_node(
+                CfgNode::new(else_id, LabelNormalizer::try_else_label())
+                    .with_span(node_span(else_node)),
+            );
+            for &exit in &exits {
+                cfg.add_edge(CfgEdge::new(exit, else_id, "next".to_string()));
+            }
+            exits = process_block(cfg, ctx, else_node, source, else_id);
+        }
+    }
+
+    for (&handler, &handler_id) in handlers.iter().zip(&handler_ids) {
+        match clause_body(handler) {
+            Some(handler_body) => {
+                exits.extend(process_block(cfg, ctx, handler_body, source, handler_id))
+            }
+            None => exits.push(handler_id),
+        }
+    }
+
+    let try_ctx = ctx.pop_try();
+
+    let (finally_node, finally_id, mut try_ctx) = match (finally_body, finally_id, try_ctx) {
+        (Some(node), Some(id), Some(try_ctx)) => (node, id, try_ctx),
+        _ => return exits,
+    };
+
+    // An exception no handler catches runs the finally block, then propagates
+    if !has_catch_all {
+        cfg.add_edge(CfgEdge::new(try_id, finally_id, "exception".to_string()));
+        if !try_ctx.pending_jumps.contains(&Jump::Throw) {
+            try_ctx.pending_jumps.push(Jump::Throw);
+        }
+    }
+
+    for &exit in &exits {
+        cfg.add_edge(CfgEdge::new(exit, finally_id, "next".to_string()));
+    }
+    let finally_exits = process_block(cfg, ctx, finally_node, source, finally_id);
+
+    // Jumps that entered the finally block continue to their original targets
+    for jump in try_ctx.pending_jumps {
+        for &exit in &finally_exits {
+            ctx.connect_jump(cfg, exit, jump);
+        }
+    }
+
+    if exits.is_empty() {
+        vec![] // Only abrupt paths reach the finally block
+    } else {
+        finally_exits
+    }
+}
+
+/// Find the block of an `except`, `catch`, `else`, or `finally` clause.
+fn clause_body(clause: Node) -> Option<Node> {
+    if let Some(body) = clause.child_by_field_name("body") {
+        return Some(body);
+    }
+    clause.named_children(&mut clause.walk()).find(|child| {
+        matches!(
+            child.kind(),
+            "block" | "statement_block" | "compound_statement"
+        )
+    })
+}
+
+/// Extract the exception type(s) a handler catches, or `None` for a catch-all.
+fn exception_type(handler: Node, source: &str) -> Option<String> {
+    let type_node = match handler.kind() {
+        // Python: `except (ValueError, KeyError) as e:`
+        "except_clause" | "except_group_clause" => {
+            let mut cursor = handler.walk();
+            let value = handler.child_by_field_name("value").or_else(|| {
+                handler
+                    .named_children(&mut cursor)
+                    .find(|child| child.kind() != "block")
+            })?;
+            if value.kind() == "as_pattern" {
+                value.named_child(0)?
+            } else {
+                value
+            }
+        }
+        _ => {
+            let mut cursor = handler.walk();
+            let parameter = handler.named_children(&mut cursor).find(|child| {
+                matches!(child.kind(), "catch_formal_parameter" | "parameter_list")
+            })?;
+            match parameter.kind() {
+                // Java: `catch (IOException | RuntimeException e)`
+                "catch_formal_parameter" => parameter
+                    .named_children(&mut parameter.walk())
+                    .find(|child| child.kind() == "catch_type")?,
+                // C++: `catch (const std::exception& e)`; `catch (...)` catches everything
+                _ => parameter.named_child(0)?.child_by_field_name("type")?,
+            }
+        }
+    };
+
+    let text = source.get(type_node.start_byte()..type_node.end_byte())?;
+    let text = text
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    Some(text)
+}
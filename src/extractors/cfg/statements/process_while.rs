@@ -1,9 +1,11 @@
-use super::super::core::{get_statement_text, CfgContext, LabelNormalizer};
-use super::super::processors::process_block;
+use super::super::core::{get_statement_text, node_span, CfgContext, LabelNormalizer};
+use super::super::processors::process_body;
+use super::process_loop_else::process_loop_else;
+use super::process_suspend::process_suspension_points;
 use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
 use tree_sitter::Node;
 
-/// Process a while expression and return exit points.
+/// Process a while expression or statement and return exit points.
 /// Implements CFG-06: while loops with condition + back edge.
 pub fn process_while(
     cfg: &mut ControlFlowGraph,
@@ -12,60 +14,67 @@ pub fn process_while(
     source: &str,
     entry: usize,
 ) -> Vec<usize> {
-    // Get condition and body from while_expression
-    let mut cursor = while_node.walk();
-    let mut condition_node = None;
-    let mut body_node = None;
-
-    for child in while_node.named_children(&mut cursor) {
-        match child.kind() {
-            "block" => body_node = Some(child),
-            _ => {
-                // First non-block child is the condition
-                if condition_node.is_none() {
-                    condition_node = Some(child);
-                }
-            }
+    // Get condition and body; a Rust loop label precedes the condition
+    let condition = match while_node.child_by_field_name("condition") {
+        // C, Java and JavaScript wrap the condition in parentheses
+        Some(node) if node.kind() == "parenthesized_expression" => {
+            node.named_child(0).unwrap_or(node)
         }
-    }
-
-    let condition = match condition_node {
         Some(node) => node,
         None => return vec![entry], // No condition found, fallback
     };
 
-    let body = match body_node {
+    let body = match while_node.child_by_field_name("body") {
         Some(node) => node,
         None => return vec![entry], // No body found, fallback
     };
 
+    // Awaits in the condition suspend on every iteration, before it is tested
+    let suspension = process_suspension_points(cfg, ctx, condition, source, entry, "next");
+
     // Create condition node with normalized label
     let condition_id = ctx.alloc_id();
     let condition_text = get_statement_text(condition, source);
-    cfg.add_node(CfgNode::new(
-        condition_id,
-        LabelNormalizer::while_loop_label(&condition_text),
-    ));
+    cfg.add_node(
+        CfgNode::new(
+            condition_id,
+            LabelNormalizer::while_loop_label(&condition_text),
+        )
+        .with_span(node_span(condition)),
+    );
+    ctx.conditions.push((condition_id, node_span(condition)));
 
-    // Connect entry to condition
-    cfg.add_edge(CfgEdge::new(entry, condition_id, "next".to_string()));
+    // Connect entry to condition; iterations restart at the first suspension point
+    let loop_head = match suspension {
+        Some((first, last)) => {
+            cfg.add_edge(CfgEdge::new(last, condition_id, "next".to_string()));
+            first
+        }
+        None => {
+            cfg.add_edge(CfgEdge::new(entry, condition_id, "next".to_string()));
+            condition_id
+        }
+    };
 
     // Create after-loop node (where false branch and breaks go)
     let after_id = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(
-        after_id,
-        LabelNormalizer::loop_after_label("while_loop"),
-    ));
+    cfg.add_node(
+        CfgNode::new(after_id, LabelNormalizer::loop_after_label("while_loop"))
+            .with_span(node_span(while_node)),
+    );
 
     // Push loop context for break/continue handling
-    ctx.push_loop(condition_id, after_id);
+    ctx.push_loop(loop_head, after_id);
 
     // Process body starting from a new ID
     let body_start_id = ctx.alloc_id();
-    cfg.add_node(CfgNode::new(
-        body_start_id,
-        LabelNormalizer::loop_body_label("while_loop"),
-    ));
+    cfg.add_node(
+        CfgNode::new(
+            body_start_id,
+            LabelNormalizer::loop_body_label("while_loop"),
+        )
+        .with_span(node_span(body)),
+    );
 
     // Connect condition to body start (true branch)
     cfg.add_edge(CfgEdge::new(
@@ -75,7 +84,7 @@ pub fn process_while(
     ));
 
     // Process body
-    let body_exits = process_block(cfg, ctx, body, source, body_start_id);
+    let body_exits = process_body(cfg, ctx, body, source, body_start_id);
 
     // Pop loop context
     ctx.pop_loop();
@@ -83,12 +92,12 @@ pub fn process_while(
     // Add back edges from body exits to condition
     for &exit in &body_exits {
         if exit != after_id {
-            cfg.add_edge(CfgEdge::new(exit, condition_id, "back".to_string()));
+            cfg.add_edge(CfgEdge::new(exit, loop_head, "back".to_string()));
         }
     }
 
-    // Connect condition to after_loop (false branch)
-    cfg.add_edge(CfgEdge::new(condition_id, after_id, "false".to_string()));
+    // Connect condition to after_loop (false branch), through a Python `else:` block
+    process_loop_else(cfg, ctx, while_node, source, condition_id, after_id);
 
     vec![after_id]
 }
@@ -0,0 +1,99 @@
+use super::super::core::{get_statement_text, node_span, CfgContext, Jump, LabelNormalizer};
+use super::super::processors::process_block;
+use super::process_suspend::{
+    add_suspension_point, is_async, mark_suspension_point, process_suspension_points,
+};
+use crate::models::{CfgEdge, CfgNode, ControlFlowGraph};
+use tree_sitter::Node;
+
+/// Process a Python `with` statement and return exit points.
+///
+/// The context manager's `__enter__` runs before the body and its `__exit__`
+/// runs however the body is left: normally, by a return, break or continue,
+/// or by an exception, which continues past `__exit__`. An `async with`
+/// awaits on entering and leaving the context.
+pub fn process_with(
+    cfg: &mut ControlFlowGraph,
+    ctx: &mut CfgContext,
+    with_node: Node,
+    source: &str,
+    entry: usize,
+) -> Vec<usize> {
+    let mut cursor = with_node.walk();
+    let clause = with_node
+        .named_children(&mut cursor)
+        .find(|child| child.kind() == "with_clause");
+    let (clause, body) = match (clause, with_node.child_by_field_name("body")) {
+        (Some(clause), Some(body)) => (clause, body),
+        _ => return vec![entry], // Incomplete statement, fallback
+    };
+    let value = get_statement_text(clause, source);
+    let value = value.trim_end_matches(';');
+    let is_async = is_async(with_node);
+
+    // Awaits in the context expressions run before the context is entered
+    let current = match process_suspension_points(cfg, ctx, clause, source, entry, "next") {
+        Some((_, last)) => last,
+        None => entry,
+    };
+
+    let enter_id = if is_async {
+        add_suspension_point(
+            cfg,
+            ctx,
+            LabelNormalizer::async_with_label("enter", value),
+            clause,
+            current,
+            "next",
+        )
+    } else {
+        let enter_id = ctx.alloc_id();
+        cfg.add_node(
+            CfgNode::new(enter_id, LabelNormalizer::with_label("enter", value))
+                .with_span(node_span(clause)),
+        );
+        cfg.add_edge(CfgEdge::new(current, enter_id, "next".to_string()));
+        enter_id
+    };
+
+    // The exit node exists before the body so jumps out of it can reach it
+    let exit_id = ctx.alloc_id();
+    let exit_label = if is_async {
+        LabelNormalizer::async_with_label("exit", value)
+    } else {
+        LabelNormalizer::with_label("exit", value)
+    };
+    cfg.add_node(CfgNode::new(exit_id, exit_label).with_span(node_span(clause)));
+    if is_async {
+        mark_suspension_point(cfg, ctx, exit_id);
+    }
+
+    // Leaving the body runs `__exit__` first, like a finally block
+    ctx.push_try(Vec::new(), Some(exit_id));
+    let exits = process_block(cfg, ctx, body, source, enter_id);
+    let mut with_ctx = match ctx.pop_try() {
+        Some(with_ctx) => with_ctx,
+        None => return exits,
+    };
+
+    // An exception raised in the body is passed to `__exit__`
+    cfg.add_edge(CfgEdge::new(enter_id, exit_id, "exception".to_string()));
+    if !with_ctx.pending_jumps.contains(&Jump::Throw) {
+        with_ctx.pending_jumps.push(Jump::Throw);
+    }
+
+    for &exit in &exits {
+        cfg.add_edge(CfgEdge::new(exit, exit_id, "next".to_string()));
+    }
+
+    // Jumps that left through `__exit__` continue to their original targets
+    for jump in with_ctx.pending_jumps {
+        ctx.connect_jump(cfg, exit_id, jump);
+    }
+
+    if exits.is_empty() {
+        vec![] // Only abrupt paths reach `__exit__`
+    } else {
+        vec![exit_id]
+    }
+}
@@ -2,6 +2,6 @@ pub mod cfg;
 pub mod functions;
 pub mod top_level;
 
-pub use cfg::build_cfg_from_block;
+pub use cfg::{build_cfg_from_block, build_cfg_with_options, CfgOptions};
 pub use functions::extract_functions;
 pub use top_level::extract_top_level_items;
@@ -7,7 +7,11 @@ use tree_sitter::Language;
 pub struct LanguageConfig {
     /// Tree-sitter language parser
     pub language: Language,
+    /// Node types for function-like definitions; the first is the named
+    /// top-level function declaration
+    pub function_node_types: Vec<&'static str>,
     /// Node type for function definitions
+    #[deprecated(note = "use `function_node_types` or `get_function_node_type()`")]
     pub function_node_type: &'static str,
     /// Node type for function bodies (usually "block")
     pub body_node_type: &'static str,
@@ -15,11 +19,14 @@ pub struct LanguageConfig {
     pub identifier_types: Vec<&'static str>,
 }
 
+// The deprecated `function_node_type` is still filled in for existing readers
+#[allow(deprecated)]
 impl LanguageConfig {
     /// Creates a configuration for Rust language.
     pub fn rust() -> Self {
         LanguageConfig {
             language: tree_sitter_rust::language().into(),
+            function_node_types: vec!["function_item", "closure_expression"],
             function_node_type: "function_item",
             body_node_type: "block",
             identifier_types: vec!["identifier", "type_identifier"],
@@ -30,6 +37,7 @@ impl LanguageConfig {
     pub fn python() -> Self {
         LanguageConfig {
             language: tree_sitter_python::LANGUAGE.into(),
+            function_node_types: vec!["function_definition", "lambda"],
             function_node_type: "function_definition",
             body_node_type: "block",
             identifier_types: vec!["identifier"],
@@ -40,6 +48,14 @@ impl LanguageConfig {
     pub fn javascript() -> Self {
         LanguageConfig {
             language: tree_sitter_javascript::LANGUAGE.into(),
+            function_node_types: vec![
+                "function_declaration",
+                "generator_function_declaration",
+                "method_definition",
+                "function_expression",
+                "generator_function",
+                "arrow_function",
+            ],
             function_node_type: "function_declaration",
             body_node_type: "statement_block",
             identifier_types: vec!["identifier", "property_identifier"],
@@ -50,6 +66,14 @@ impl LanguageConfig {
     pub fn typescript() -> Self {
         LanguageConfig {
             language: tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            function_node_types: vec![
+                "function_declaration",
+                "generator_function_declaration",
+                "method_definition",
+                "function_expression",
+                "generator_function",
+                "arrow_function",
+            ],
             function_node_type: "function_declaration",
             body_node_type: "statement_block",
             identifier_types: vec!["identifier", "property_identifier", "type_identifier"],
@@ -60,6 +84,11 @@ impl LanguageConfig {
     pub fn java() -> Self {
         LanguageConfig {
             language: tree_sitter_java::LANGUAGE.into(),
+            function_node_types: vec![
+                "method_declaration",
+                "constructor_declaration",
+                "lambda_expression",
+            ],
             function_node_type: "method_declaration",
             body_node_type: "block",
             identifier_types: vec!["identifier"],
@@ -70,6 +99,7 @@ impl LanguageConfig {
     pub fn c() -> Self {
         LanguageConfig {
             language: tree_sitter_c::LANGUAGE.into(),
+            function_node_types: vec!["function_definition"],
             function_node_type: "function_definition",
             body_node_type: "compound_statement",
             identifier_types: vec!["identifier"],
@@ -80,15 +110,21 @@ impl LanguageConfig {
     pub fn cpp() -> Self {
         LanguageConfig {
             language: tree_sitter_cpp::LANGUAGE.into(),
+            function_node_types: vec!["function_definition", "lambda_expression"],
             function_node_type: "function_definition",
             body_node_type: "compound_statement",
             identifier_types: vec!["identifier"],
         }
     }
 
-    /// Returns the node type for function definitions.
+    /// Returns the node type for named top-level function definitions.
     pub fn get_function_node_type(&self) -> &str {
-        self.function_node_type
+        self.function_node_types[0]
+    }
+
+    /// Checks whether a node kind defines a function, method, lambda or closure.
+    pub fn is_function_node(&self, kind: &str) -> bool {
+        self.function_node_types.contains(&kind)
     }
 
     /// Returns the node type for function bodies.
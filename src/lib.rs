@@ -21,6 +21,7 @@ pub use models::{
     CFGNodeIR,
     CfgEdge,
     CfgNode,
+    CfgSpan,
     ConditionOperator,
     ControlFlowGraph,
     DataDependencyEdge,
@@ -43,8 +44,15 @@ pub use models::{
     EarlyExitKind,
     ForLoopIR,
     FunctionCFGIR,
+    FunctionSSAIR,
     FunctionSpan,
+    HalsteadResult,
     LoopKind,
+    NodeDefUse,
+    PhiNodeIR,
+    PhiOperandIR,
+    SsaNodeIR,
+    SsaValue,
     TopLevelItem,
     VariableDefinition,
     VariableEvent,
@@ -72,8 +80,14 @@ pub use language::{detect_language_config, LanguageConfig, SupportedLanguage};
 // Storage exports
 pub use storage::{
     CacheKey,
+    CallConfidence,
+    CallEdge,
+    // Call graph
+    CallGraph,
+    CallResolver,
     CallSiteId,
     CallSiteSummary,
+    CallType,
     // Call resolution
     ClassHierarchyAnalyzer,
     ConstructorDetector,
@@ -142,7 +156,9 @@ pub use storage::{
 
 // Analyzer exports
 pub use analyzers::{
-    ComplexityAnalyzer, ComplexityResult, CrossFileVariable, DataFlowAnalyzer, EarlyExitNormalizer,
-    ForLoopNormalizer, VariableLifecycleAnalyzer, WorkspaceDataFlowAnalyzer,
+    CognitiveComplexityAnalyzer, ComplexityAnalyzer, ComplexityResult, ControlDependence,
+    CrossFileVariable, DataFlowAnalyzer, DominanceAnalyzer, DominanceFrontier, DominanceResult,
+    DominatorTreeEdge, EarlyExitNormalizer, ForLoopNormalizer, HalsteadAnalyzer, LoopAnalyzer,
+    LoopExit, LoopForest, NaturalLoop, VariableLifecycleAnalyzer, WorkspaceDataFlowAnalyzer,
     WorkspaceDataFlowResult,
 };
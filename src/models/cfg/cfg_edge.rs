@@ -1,3 +1,4 @@
+use crate::models::CfgSpan;
 use serde::{Deserialize, Serialize};
 
 /// Represents an edge in the Control Flow Graph.
@@ -9,12 +10,26 @@ pub struct CfgEdge {
     pub to: usize,
     /// Type of edge (e.g., "next" for sequential flow)
     pub kind: String,
+    /// Source location of the construct the edge leaves
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<CfgSpan>,
 }
 
 impl CfgEdge {
     /// Creates a new CFG edge.
     pub fn new(from: usize, to: usize, kind: String) -> Self {
-        CfgEdge { from, to, kind }
+        CfgEdge {
+            from,
+            to,
+            kind,
+            span: None,
+        }
+    }
+
+    /// Sets the source span of this edge.
+    pub fn with_span(mut self, span: CfgSpan) -> Self {
+        self.span = Some(span);
+        self
     }
 }
 
@@ -1,5 +1,6 @@
 use crate::export::{export_jsonl, export_mermaid, export_mermaid_validated};
-use crate::models::{CfgEdge, CfgNode};
+use crate::models::{CfgEdge, CfgNode, CfgSpan};
+use std::collections::HashMap;
 
 /// Represents a complete Control Flow Graph.
 #[derive(Debug, Clone)]
@@ -27,6 +28,23 @@ impl ControlFlowGraph {
         self.edges.push(edge);
     }
 
+    /// Gives every edge without a span the span of the node it leaves.
+    ///
+    /// A branch or jump is decided by the construct of its source node, so the
+    /// edge points back to that construct.
+    pub fn attach_edge_spans(&mut self) {
+        let spans: HashMap<usize, CfgSpan> = self
+            .nodes
+            .iter()
+            .filter_map(|node| node.span.map(|span| (node.cfg_node, span)))
+            .collect();
+        for edge in &mut self.edges {
+            if edge.span.is_none() {
+                edge.span = spans.get(&edge.from).copied();
+            }
+        }
+    }
+
     /// Generates Mermaid diagram syntax.
     pub fn to_mermaid(&self) -> String {
         export_mermaid(self)
@@ -1,3 +1,4 @@
+use crate::models::CfgSpan;
 use serde::{Deserialize, Serialize};
 
 /// Represents a node in the Control Flow Graph.
@@ -7,6 +8,10 @@ pub struct CfgNode {
     pub cfg_node: usize,
     /// Label describing the node content
     pub label: String,
+    /// Source location of the construct this node was built from; `None` for
+    /// the synthetic ENTRY and EXIT nodes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<CfgSpan>,
 }
 
 impl CfgNode {
@@ -15,6 +20,13 @@ impl CfgNode {
         CfgNode {
             cfg_node: id,
             label,
+            span: None,
         }
     }
+
+    /// Sets the source span of this node.
+    pub fn with_span(mut self, span: CfgSpan) -> Self {
+        self.span = Some(span);
+        self
+    }
 }
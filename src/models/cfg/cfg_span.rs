@@ -0,0 +1,84 @@
+use crate::models::FunctionSpan;
+use serde::{Deserialize, Serialize};
+
+/// Source location of a CFG node or edge.
+///
+/// Byte offsets index into the analyzed source; lines and columns are
+/// 1-based, like the spans of [`FunctionSpan`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct CfgSpan {
+    /// Byte offset of the first byte
+    pub start_byte: usize,
+    /// Byte offset one past the last byte
+    pub end_byte: usize,
+    /// 1-based starting line
+    pub start_line: usize,
+    /// 1-based starting column
+    pub start_col: usize,
+    /// 1-based ending line
+    pub end_line: usize,
+    /// 1-based ending column (exclusive)
+    pub end_col: usize,
+}
+
+impl CfgSpan {
+    /// Creates a span from tree-sitter's 0-based byte range and positions.
+    pub fn new(
+        start_byte: usize,
+        end_byte: usize,
+        (start_line, start_col): (usize, usize),
+        (end_line, end_col): (usize, usize),
+    ) -> Self {
+        CfgSpan {
+            start_byte,
+            end_byte,
+            start_line: start_line + 1,
+            start_col: start_col + 1,
+            end_line: end_line + 1,
+            end_col: end_col + 1,
+        }
+    }
+
+    /// Smallest span covering both this span and `other`.
+    pub fn merge(&self, other: &CfgSpan) -> CfgSpan {
+        let start = if other.start_byte < self.start_byte {
+            other
+        } else {
+            self
+        };
+        let end = if other.end_byte > self.end_byte {
+            other
+        } else {
+            self
+        };
+        CfgSpan {
+            start_byte: start.start_byte,
+            end_byte: end.end_byte,
+            start_line: start.start_line,
+            start_col: start.start_col,
+            end_line: end.end_line,
+            end_col: end.end_col,
+        }
+    }
+
+    /// Checks whether `other` lies within this span.
+    pub fn contains(&self, other: &CfgSpan) -> bool {
+        self.start_byte <= other.start_byte && other.end_byte <= self.end_byte
+    }
+
+    /// Checks whether a 1-based `(line, col)` position lies within this span.
+    pub fn contains_position(&self, line: usize, col: usize) -> bool {
+        (self.start_line, self.start_col) <= (line, col)
+            && (line, col) < (self.end_line, self.end_col)
+    }
+
+    /// Formats the span as `"line:col–line:col"`.
+    pub fn format(&self) -> String {
+        FunctionSpan::format_span(
+            self.start_line - 1,
+            self.start_col - 1,
+            self.end_line - 1,
+            self.end_col - 1,
+        )
+    }
+}
@@ -2,7 +2,9 @@
 pub use self::cfg_edge::{CfgEdge, CfgEdgeWrapper};
 pub use self::cfg_graph::ControlFlowGraph;
 pub use self::cfg_node::CfgNode;
+pub use self::cfg_span::CfgSpan;
 
 mod cfg_edge;
 mod cfg_graph;
 mod cfg_node;
+mod cfg_span;
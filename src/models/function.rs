@@ -24,6 +24,14 @@ impl FunctionSpan {
         }
     }
 
+    /// Number of lines the function spans, 0 if its span cannot be parsed.
+    pub fn line_count(&self) -> u32 {
+        match Self::parse_span(&self.span) {
+            Some((start, _, end, _)) if end >= start => (end - start + 1) as u32,
+            _ => 0,
+        }
+    }
+
     /// Formats a span from 0-based coordinates to 1-based human-readable format.
     ///
     /// # Arguments
@@ -48,4 +56,17 @@ impl FunctionSpan {
             end_col + 1
         )
     }
+
+    /// Parses a span formatted by [`FunctionSpan::format_span`] back into
+    /// 0-based `(start_line, start_col, end_line, end_col)`.
+    pub fn parse_span(span: &str) -> Option<(usize, usize, usize, usize)> {
+        let position = |position: &str| {
+            let (line, col) = position.split_once(':')?;
+            let (line, col) = (line.parse::<usize>().ok()?, col.parse::<usize>().ok()?);
+            Some((line.checked_sub(1)?, col.checked_sub(1)?))
+        };
+        let (start, end) = span.split_once('–')?;
+        let ((start_line, start_col), (end_line, end_col)) = (position(start)?, position(end)?);
+        Some((start_line, start_col, end_line, end_col))
+    }
 }
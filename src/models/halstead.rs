@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// Halstead metrics and Maintainability Index for a function.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HalsteadResult {
+    /// Function name being analyzed
+    pub function: String,
+    /// Qualified name of the function, e.g. `Parser::parse`
+    #[serde(default)]
+    pub qualname: String,
+    /// Number of distinct operators (n1)
+    pub distinct_operators: u32,
+    /// Number of distinct operands (n2)
+    pub distinct_operands: u32,
+    /// Total number of operators (N1)
+    pub total_operators: u32,
+    /// Total number of operands (N2)
+    pub total_operands: u32,
+    /// Vocabulary, n1 + n2
+    pub vocabulary: u32,
+    /// Length, N1 + N2
+    pub length: u32,
+    /// Volume, N * log2(n)
+    pub volume: f64,
+    /// Difficulty, (n1 / 2) * (N2 / n2)
+    pub difficulty: f64,
+    /// Effort, D * V
+    pub effort: f64,
+    /// Lines spanned by the function, including its signature
+    pub loc: u32,
+    /// Cyclomatic complexity the Maintainability Index was computed with
+    pub cyclomatic: u32,
+    /// Maintainability Index scaled to 0-100, higher being easier to maintain
+    pub maintainability_index: f64,
+}
+
+impl HalsteadResult {
+    /// Set the qualified name, which defaults to the function name.
+    pub fn with_qualname(mut self, qualname: String) -> Self {
+        self.qualname = qualname;
+        self
+    }
+}
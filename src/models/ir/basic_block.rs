@@ -1,3 +1,4 @@
+use crate::models::CfgSpan;
 use serde::{Deserialize, Serialize};
 
 /// Represents a basic block - a sequence of straight-line statements.
@@ -9,6 +10,9 @@ pub struct BasicBlock {
     pub stmts: Vec<String>,
     /// Source code span information
     pub span: String,
+    /// Byte range and line/column span covering the block's statements
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<CfgSpan>,
 }
 
 /// Represents an edge between basic blocks.
@@ -35,6 +39,7 @@ impl BasicBlock {
             bb: id,
             stmts: statements,
             span,
+            location: None,
         }
     }
 
@@ -43,6 +48,16 @@ impl BasicBlock {
         self.stmts.push(stmt);
     }
 
+    /// Extend this block's location to cover a statement's span.
+    pub fn extend_location(&mut self, span: CfgSpan) {
+        let location = match self.location {
+            Some(location) => location.merge(&span),
+            None => span,
+        };
+        self.location = Some(location);
+        self.span = location.format();
+    }
+
     /// Check if this basic block is empty.
     pub fn is_empty(&self) -> bool {
         self.stmts.is_empty()
@@ -1,3 +1,4 @@
+use crate::models::CfgSpan;
 use serde::{Deserialize, Serialize};
 
 /// Language-neutral CFG node representation.
@@ -13,6 +14,9 @@ pub struct CFGNodeIR {
     pub label: String,
     /// Source code span information
     pub span: String,
+    /// Byte range and line/column span of the node's source construct
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<CfgSpan>,
     /// Data source for this node (e.g., "tree-sitter", "compiler", "lsp")
     pub source: String,
     /// Confidence level ("exact", "inferred", "uncertain")
@@ -32,6 +36,9 @@ pub struct CFGEdgeIR {
     pub to: String,
     /// Edge kind (e.g., "true", "false", "next", "error", "exception")
     pub kind: String,
+    /// Byte range and line/column span of the construct the edge leaves
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<CfgSpan>,
     /// Data source for this edge (e.g., "tree-sitter", "compiler", "lsp")
     pub source: String,
     /// Confidence level ("exact", "inferred", "uncertain")
@@ -43,6 +50,10 @@ pub struct CFGEdgeIR {
 pub struct FunctionCFGIR {
     /// Function identifier
     pub function_name: String,
+    /// Function name qualified by its enclosing types, modules and functions,
+    /// e.g. `Parser::parse`
+    #[serde(default)]
+    pub qualname: String,
     /// All CFG nodes in this function
     pub nodes: Vec<CFGNodeIR>,
     /// All CFG edges in this function
@@ -60,6 +71,7 @@ impl CFGNodeIR {
             id,
             label,
             span,
+            location: None,
             source: "tree-sitter".to_string(),
             confidence: "exact".to_string(),
         }
@@ -80,10 +92,17 @@ impl CFGNodeIR {
             id,
             label,
             span,
+            location: None,
             source,
             confidence,
         }
     }
+
+    /// Set the source location of this node.
+    pub fn with_location(mut self, location: CfgSpan) -> Self {
+        self.location = Some(location);
+        self
+    }
 }
 
 impl CFGEdgeIR {
@@ -95,6 +114,7 @@ impl CFGEdgeIR {
             from,
             to,
             kind,
+            location: None,
             source: "tree-sitter".to_string(),
             confidence: "exact".to_string(),
         }
@@ -115,16 +135,24 @@ impl CFGEdgeIR {
             from,
             to,
             kind,
+            location: None,
             source,
             confidence,
         }
     }
+
+    /// Set the source location of this edge.
+    pub fn with_location(mut self, location: CfgSpan) -> Self {
+        self.location = Some(location);
+        self
+    }
 }
 
 impl FunctionCFGIR {
     /// Create a new function CFG IR.
     pub fn new(function_name: String, source_file: Option<String>) -> Self {
         FunctionCFGIR {
+            qualname: function_name.clone(),
             function_name,
             nodes: Vec::new(),
             edges: Vec::new(),
@@ -132,6 +160,12 @@ impl FunctionCFGIR {
         }
     }
 
+    /// Set the qualified name, which defaults to the function name.
+    pub fn with_qualname(mut self, qualname: String) -> Self {
+        self.qualname = qualname;
+        self
+    }
+
     /// Add a node to this function's CFG.
     pub fn add_node(&mut self, node: CFGNodeIR) {
         self.nodes.push(node);
@@ -0,0 +1,199 @@
+use serde::{Deserialize, Serialize};
+
+/// A version of a variable. Version 0 is the variable's value on function
+/// entry, such as a parameter or a global.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SsaValue {
+    /// Variable name in the source
+    pub variable: String,
+    /// Version, numbered from 1 in definition order
+    pub version: u32,
+}
+
+/// Variables defined and used by a CFG node, before versioning.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct NodeDefUse {
+    /// Variables the node assigns
+    pub defs: Vec<String>,
+    /// Variables the node reads, before its own assignments
+    pub uses: Vec<String>,
+}
+
+/// A CFG node with the variable versions it defines and uses.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SsaNodeIR {
+    #[serde(rename = "type")]
+    pub node_type: String,
+    /// Function this node belongs to
+    pub func: String,
+    /// CFG node identifier
+    pub id: String,
+    /// Language-agnostic node label
+    pub label: String,
+    /// Versions this node defines
+    pub defs: Vec<SsaValue>,
+    /// Versions this node uses
+    pub uses: Vec<SsaValue>,
+}
+
+/// The version reaching a phi node from one predecessor.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PhiOperandIR {
+    /// Predecessor node identifier
+    pub from: String,
+    /// Version live at the end of the predecessor
+    pub value: SsaValue,
+}
+
+/// A phi node, merging the versions of a variable where paths join.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PhiNodeIR {
+    #[serde(rename = "type")]
+    pub node_type: String,
+    /// Function this phi node belongs to
+    pub func: String,
+    /// CFG node at whose start the phi node runs
+    pub node: String,
+    /// Version the phi node defines
+    pub target: SsaValue,
+    /// One operand per reachable predecessor of `node`
+    pub operands: Vec<PhiOperandIR>,
+}
+
+/// Static single assignment form of a function's CFG.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FunctionSSAIR {
+    /// Function identifier
+    pub function_name: String,
+    /// Function name qualified by its enclosing types, modules and functions,
+    /// e.g. `Parser::parse`
+    #[serde(default)]
+    pub qualname: String,
+    /// Nodes reachable from ENTRY, in CFG order
+    pub nodes: Vec<SsaNodeIR>,
+    /// Phi nodes, in CFG order of their node
+    pub phis: Vec<PhiNodeIR>,
+    /// Source file information
+    pub source_file: Option<String>,
+}
+
+impl SsaValue {
+    /// Create a new variable version.
+    pub fn new(variable: String, version: u32) -> Self {
+        SsaValue { variable, version }
+    }
+
+    /// Get the versioned name (e.g., "x_2").
+    pub fn name(&self) -> String {
+        format!("{}_{}", self.variable, self.version)
+    }
+}
+
+impl SsaNodeIR {
+    /// Create a new SSA node with no definitions or uses.
+    pub fn new(func: String, id: String, label: String) -> Self {
+        SsaNodeIR {
+            node_type: "SSANode".to_string(),
+            func,
+            id,
+            label,
+            defs: Vec::new(),
+            uses: Vec::new(),
+        }
+    }
+}
+
+impl PhiNodeIR {
+    /// Create a new phi node with no operands.
+    pub fn new(func: String, node: String, target: SsaValue) -> Self {
+        PhiNodeIR {
+            node_type: "Phi".to_string(),
+            func,
+            node,
+            target,
+            operands: Vec::new(),
+        }
+    }
+}
+
+impl FunctionSSAIR {
+    /// Create a new function SSA IR.
+    pub fn new(function_name: String, source_file: Option<String>) -> Self {
+        FunctionSSAIR {
+            qualname: function_name.clone(),
+            function_name,
+            nodes: Vec::new(),
+            phis: Vec::new(),
+            source_file,
+        }
+    }
+
+    /// Set the qualified name, which defaults to the function name.
+    pub fn with_qualname(mut self, qualname: String) -> Self {
+        self.qualname = qualname;
+        self
+    }
+
+    /// Get a node by its CFG node identifier.
+    pub fn node(&self, id: &str) -> Option<&SsaNodeIR> {
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Get the phi nodes at the start of a CFG node.
+    pub fn phis_at(&self, node: &str) -> Vec<&PhiNodeIR> {
+        self.phis.iter().filter(|phi| phi.node == node).collect()
+    }
+
+    /// Get the node defining a version, a phi node's being where it runs.
+    /// Version 0 is defined on entry, by no node.
+    pub fn definition_site(&self, value: &SsaValue) -> Option<&str> {
+        self.phis
+            .iter()
+            .find(|phi| phi.target == *value)
+            .map(|phi| phi.node.as_str())
+            .or_else(|| {
+                self.nodes
+                    .iter()
+                    .find(|node| node.defs.contains(value))
+                    .map(|node| node.id.as_str())
+            })
+    }
+
+    /// Get the nodes using a version, including those of the phi nodes
+    /// merging it.
+    pub fn use_sites(&self, value: &SsaValue) -> Vec<&str> {
+        let mut sites: Vec<&str> = self
+            .nodes
+            .iter()
+            .filter(|node| node.uses.contains(value))
+            .map(|node| node.id.as_str())
+            .collect();
+        for phi in &self.phis {
+            let merges = phi.operands.iter().any(|operand| operand.value == *value);
+            if merges && !sites.contains(&phi.node.as_str()) {
+                sites.push(phi.node.as_str());
+            }
+        }
+        sites
+    }
+
+    /// Convert to JSONL format, nodes first and then phi nodes.
+    pub fn to_jsonl(&self) -> String {
+        let mut jsonl = String::new();
+        let lines = self
+            .nodes
+            .iter()
+            .map(serde_json::to_string)
+            .chain(self.phis.iter().map(serde_json::to_string));
+        for line in lines {
+            match line {
+                Ok(json) => {
+                    jsonl.push_str(&json);
+                    jsonl.push('\n');
+                }
+                Err(_) => continue,
+            }
+        }
+        jsonl
+    }
+}
@@ -3,11 +3,12 @@ pub mod data_flow_graph;
 pub mod decision_tree;
 pub mod def_use_chain;
 pub mod function;
+pub mod halstead;
 pub mod ir;
 pub mod item;
 pub mod variable_lifecycle;
 
-pub use cfg::{CfgEdge, CfgEdgeWrapper, CfgNode, ControlFlowGraph};
+pub use cfg::{CfgEdge, CfgEdgeWrapper, CfgNode, CfgSpan, ControlFlowGraph};
 pub use data_flow_graph::{
     DataDependencyEdge, DataFlowGraph, DataFlowNode, DependencyType, VariableDefinition,
 };
@@ -17,9 +18,11 @@ pub use decision_tree::{
 };
 pub use def_use_chain::{DefUseChain, DefUseChainSet, DefUseSite, DefUseSiteType};
 pub use function::FunctionSpan;
+pub use halstead::HalsteadResult;
 pub use ir::{
     BasicBlock, BasicBlockEdge, BasicBlockGraph, CFGEdgeIR, CFGNodeIR, EarlyExitIR, EarlyExitKind,
-    ForLoopIR, FunctionCFGIR, LoopKind,
+    ForLoopIR, FunctionCFGIR, FunctionSSAIR, LoopKind, NodeDefUse, PhiNodeIR, PhiOperandIR,
+    SsaNodeIR, SsaValue,
 };
 pub use item::TopLevelItem;
 pub use variable_lifecycle::{
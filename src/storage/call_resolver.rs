@@ -0,0 +1,354 @@
+use super::call_edge::{CallConfidence, CallEdge, CallType};
+use super::name_resolver::NameResolver;
+use super::symbol_core::{SymbolId, TopLevelSymbol};
+use super::symbol_store::SymbolStore;
+use crate::language::SupportedLanguage;
+use std::collections::HashMap;
+
+/// Resolves extracted call sites to the symbols they call.
+///
+/// Callees are looked up in order of certainty: the caller's own scope, the
+/// name resolver's import bindings, a type or module named by the call's
+/// qualifier, and finally every method sharing the callee's name.
+pub struct CallResolver<'a> {
+    symbol_store: &'a SymbolStore,
+    name_resolver: Option<&'a NameResolver>,
+    /// qualname -> symbol, for callable symbols and the types that own them
+    by_qualname: HashMap<&'a str, &'a TopLevelSymbol>,
+    /// name -> callable symbols with that name
+    by_name: HashMap<&'a str, Vec<&'a TopLevelSymbol>>,
+    /// class qualname -> its constructor
+    constructors: HashMap<&'a str, &'a TopLevelSymbol>,
+}
+
+impl<'a> CallResolver<'a> {
+    /// Create a resolver over the symbols of a file or workspace.
+    pub fn new(symbol_store: &'a SymbolStore, name_resolver: Option<&'a NameResolver>) -> Self {
+        let mut by_qualname = HashMap::new();
+        let mut by_name: HashMap<&str, Vec<&TopLevelSymbol>> = HashMap::new();
+        let mut constructors = HashMap::new();
+
+        for symbol in symbol_store.get_all_symbols() {
+            if Self::is_callable(symbol) || Self::is_class(symbol) {
+                by_qualname.insert(symbol.qualname.as_str(), symbol);
+                by_name
+                    .entry(symbol.name.as_str())
+                    .or_default()
+                    .push(symbol);
+            }
+        }
+        // Keep candidate lists stable across runs
+        for candidates in by_name.values_mut() {
+            candidates.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
+            for symbol in candidates
+                .iter()
+                .filter(|symbol| Self::is_constructor(symbol))
+            {
+                constructors
+                    .entry(Self::parent_scope(&symbol.qualname))
+                    .or_insert(*symbol);
+            }
+        }
+
+        CallResolver {
+            symbol_store,
+            name_resolver,
+            by_qualname,
+            by_name,
+            constructors,
+        }
+    }
+
+    /// Resolve a call edge's targets and confidence.
+    ///
+    /// Edges whose callee cannot be found are `Dynamic` for dynamically typed
+    /// languages and `Unknown` otherwise. Calls through function pointers or
+    /// other computed callees are left `Dynamic` without targets.
+    pub fn resolve(&self, mut edge: CallEdge) -> CallEdge {
+        if edge.call_type == CallType::Dynamic {
+            edge.targets.clear();
+            edge.confidence = CallConfidence::Dynamic;
+            return edge;
+        }
+
+        let caller = match self.symbol_store.get_symbol(&edge.caller_sym) {
+            Some(caller) => caller,
+            None => return edge,
+        };
+        let callee = match edge.module_hints.first() {
+            Some(callee) => callee.clone(),
+            None => return edge,
+        };
+        let (qualifier, name) = Self::split_callee(&callee);
+
+        let (targets, confidence) = match qualifier {
+            None => self.resolve_unqualified(caller, name, &edge),
+            Some("self" | "Self" | "this") => self.resolve_in_scope(caller, name),
+            Some(qualifier) => {
+                self.resolve_qualified(caller, qualifier, name, callee.contains('.'))
+            }
+        };
+
+        if targets.is_empty() {
+            edge.targets.clear();
+            edge.confidence = if Self::is_dynamic_language(caller) {
+                CallConfidence::Dynamic
+            } else {
+                CallConfidence::Unknown
+            };
+            return edge;
+        }
+
+        // Calling a class constructs it
+        if targets.len() == 1 && Self::is_class(targets[0]) {
+            edge.call_type = CallType::Constructor;
+            edge.targets = vec![self.constructor_of(targets[0])];
+        } else {
+            edge.targets = targets.iter().map(|symbol| symbol.id.clone()).collect();
+        }
+        edge.confidence = confidence;
+        edge
+    }
+
+    /// Resolve a bare name: the caller's own type (for languages with an
+    /// implicit receiver), the same file, imports, then the workspace.
+    fn resolve_unqualified(
+        &self,
+        caller: &TopLevelSymbol,
+        name: &str,
+        edge: &CallEdge,
+    ) -> (Vec<&'a TopLevelSymbol>, CallConfidence) {
+        // `helper()` inside a Java or C++ method may call a sibling method
+        if Self::has_implicit_receiver(caller) && edge.call_type != CallType::Constructor {
+            let scope = Self::parent_scope(&caller.qualname);
+            let siblings: Vec<&TopLevelSymbol> = self
+                .candidates(caller, name)
+                .filter(|symbol| {
+                    Self::is_method(symbol) && Self::parent_scope(&symbol.qualname) == scope
+                })
+                .collect();
+            if !siblings.is_empty() {
+                return Self::by_count(siblings);
+            }
+        }
+
+        let same_file: Vec<&TopLevelSymbol> = self
+            .candidates(caller, name)
+            .filter(|symbol| symbol.file_path == caller.file_path && !Self::is_method(symbol))
+            .collect();
+        if !same_file.is_empty() {
+            return Self::by_count(same_file);
+        }
+
+        if let Some(name_resolver) = self.name_resolver {
+            let binding =
+                name_resolver.resolve_name(&caller.file_path, name, edge.site_span.clone());
+            let resolved = binding
+                .resolved_sym_id
+                .as_ref()
+                .and_then(|id| self.symbol_store.get_symbol(id));
+            if let Some(symbol) = resolved {
+                if binding.is_confident() {
+                    return (vec![symbol], CallConfidence::Direct);
+                }
+            }
+        }
+
+        // Free functions and classes elsewhere in the workspace
+        let candidates: Vec<&TopLevelSymbol> = self
+            .candidates(caller, name)
+            .filter(|symbol| !Self::is_method(symbol))
+            .collect();
+        Self::by_count(candidates)
+    }
+
+    /// Resolve `self.name`, `Self::name`, or `this.name` against the caller's own type.
+    fn resolve_in_scope(
+        &self,
+        caller: &TopLevelSymbol,
+        name: &str,
+    ) -> (Vec<&'a TopLevelSymbol>, CallConfidence) {
+        let scope = Self::parent_scope(&caller.qualname);
+        let declared: Vec<&TopLevelSymbol> = self
+            .candidates(caller, name)
+            .filter(|symbol| {
+                Self::is_method(symbol) && Self::parent_scope(&symbol.qualname) == scope
+            })
+            .collect();
+        match declared.len() {
+            0 => {}
+            1 => return (declared, CallConfidence::Direct),
+            // Overloads
+            _ => return (declared, CallConfidence::Virtual),
+        }
+        match self
+            .by_qualname
+            .get(format!("{}::{}", scope, name).as_str())
+        {
+            Some(symbol) => (vec![*symbol], CallConfidence::Direct),
+            // Inherited or trait methods: any method with that name
+            None => (self.methods(caller, name), CallConfidence::Virtual),
+        }
+    }
+
+    /// Resolve `Type::name`, `module.name`, or `value.name`.
+    fn resolve_qualified(
+        &self,
+        caller: &TopLevelSymbol,
+        qualifier: &str,
+        name: &str,
+        is_member_access: bool,
+    ) -> (Vec<&'a TopLevelSymbol>, CallConfidence) {
+        let owner = Self::last_component(qualifier);
+
+        // The qualifier names a type that declares the callee, or a module file
+        let owned: Vec<&TopLevelSymbol> = self
+            .candidates(caller, name)
+            .filter(|symbol| {
+                let scope_name = Self::last_component(Self::parent_scope(&symbol.qualname));
+                let file_stem = symbol.file_path.file_stem().and_then(|stem| stem.to_str());
+                scope_name == owner || (!Self::is_method(symbol) && file_stem == Some(owner))
+            })
+            .collect();
+        if !owned.is_empty() {
+            return Self::by_count(owned);
+        }
+
+        // A method called on a value of unknown type could be any same-named method
+        if is_member_access {
+            return (self.methods(caller, name), CallConfidence::Virtual);
+        }
+        (Vec::new(), CallConfidence::Unknown)
+    }
+
+    /// One candidate is a direct call; several are ambiguous.
+    fn by_count(candidates: Vec<&TopLevelSymbol>) -> (Vec<&TopLevelSymbol>, CallConfidence) {
+        let confidence = match candidates.len() {
+            1 => CallConfidence::Direct,
+            _ => CallConfidence::Virtual,
+        };
+        (candidates, confidence)
+    }
+
+    /// The constructor declared by a class, or the class itself if it has none.
+    fn constructor_of(&self, class: &TopLevelSymbol) -> SymbolId {
+        match self.constructors.get(class.qualname.as_str()) {
+            Some(constructor) => constructor.id.clone(),
+            None => class.id.clone(),
+        }
+    }
+
+    /// Callable symbols and classes with the given name that the caller's
+    /// language can call.
+    fn candidates<'s>(
+        &'s self,
+        caller: &'s TopLevelSymbol,
+        name: &str,
+    ) -> impl Iterator<Item = &'a TopLevelSymbol> + 's {
+        let family = Self::language_family(caller);
+        self.by_name
+            .get(name)
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(move |symbol| Self::language_family(symbol) == family)
+    }
+
+    /// Methods with the given name, across all types.
+    fn methods(&self, caller: &TopLevelSymbol, name: &str) -> Vec<&'a TopLevelSymbol> {
+        self.candidates(caller, name)
+            .filter(|symbol| Self::is_method(symbol))
+            .collect()
+    }
+
+    /// Split a callee expression into its qualifier and called name.
+    ///
+    /// `helper` has no qualifier, `self.run` is `("self", "run")`, and
+    /// `Foo::new::<T>` is `("Foo", "new")`.
+    fn split_callee(callee: &str) -> (Option<&str>, &str) {
+        let callee = match callee.find("::<") {
+            Some(turbofish) => &callee[..turbofish],
+            None => callee,
+        };
+        let qualified = callee
+            .rfind("::")
+            .map(|index| (index, 2))
+            .into_iter()
+            .chain(callee.rfind('.').map(|index| (index, 1)))
+            .max_by_key(|(index, _)| *index);
+        match qualified {
+            Some((index, width)) => (Some(&callee[..index]), &callee[index + width..]),
+            None => (None, callee),
+        }
+    }
+
+    /// Qualified name of the scope a symbol is declared in.
+    ///
+    /// Qualnames separate scopes with `::` or `.` depending on the language,
+    /// so `file.rs::Shape::area`, `pkg.Shape.area`, and `geo::Shape::area`
+    /// all have `Shape` as the last component of their scope.
+    fn parent_scope(qualname: &str) -> &str {
+        match Self::split_callee(qualname) {
+            (Some(scope), _) => scope,
+            (None, _) => "",
+        }
+    }
+
+    /// Last `::` or `.` separated component of a qualified name.
+    fn last_component(qualname: &str) -> &str {
+        Self::split_callee(qualname).1
+    }
+
+    fn is_callable(symbol: &TopLevelSymbol) -> bool {
+        symbol.kind.contains("function")
+            || symbol.kind.contains("method")
+            || symbol.kind == "constructor"
+    }
+
+    fn is_method(symbol: &TopLevelSymbol) -> bool {
+        symbol.kind.contains("method") || symbol.kind == "constructor"
+    }
+
+    fn is_class(symbol: &TopLevelSymbol) -> bool {
+        symbol.kind.ends_with("class") || symbol.kind == "struct"
+    }
+
+    /// Constructors named for their class (Java, C++) or by convention.
+    ///
+    /// Rust's `new` is recorded as a plain method, so calling a tuple struct
+    /// does not resolve to it.
+    fn is_constructor(symbol: &TopLevelSymbol) -> bool {
+        symbol.kind == "constructor"
+            && (matches!(symbol.name.as_str(), "constructor" | "__init__" | "__new__")
+                || Self::last_component(Self::parent_scope(&symbol.qualname)) == symbol.name)
+    }
+
+    /// Languages where unresolved calls may still bind at run time.
+    fn is_dynamic_language(symbol: &TopLevelSymbol) -> bool {
+        matches!(
+            SupportedLanguage::from_path(&symbol.file_path),
+            Ok(SupportedLanguage::Python
+                | SupportedLanguage::JavaScript
+                | SupportedLanguage::TypeScript)
+        )
+    }
+
+    /// Languages that call each other's symbols directly: JavaScript with
+    /// TypeScript and C with C++.
+    fn language_family(symbol: &TopLevelSymbol) -> Option<SupportedLanguage> {
+        match SupportedLanguage::from_path(&symbol.file_path).ok()? {
+            SupportedLanguage::TypeScript => Some(SupportedLanguage::JavaScript),
+            SupportedLanguage::Cpp => Some(SupportedLanguage::C),
+            language => Some(language),
+        }
+    }
+
+    /// Languages where a method may call its siblings without `this`.
+    fn has_implicit_receiver(symbol: &TopLevelSymbol) -> bool {
+        Self::is_method(symbol)
+            && matches!(
+                SupportedLanguage::from_path(&symbol.file_path),
+                Ok(SupportedLanguage::Java | SupportedLanguage::Cpp)
+            )
+    }
+}
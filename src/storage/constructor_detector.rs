@@ -20,8 +20,7 @@ impl ConstructorDetector {
         // JavaScript/TypeScript constructors
         let js_patterns = ["^constructor$"];
 
-        // Java/C++ constructors (class name = method name, harder to detect without context)
-        // For now, we'll rely on kind detection rather than name patterns
+        // Java/C++ constructors (class name = method name) are detected by kind below
 
         let all_patterns = [
             rust_patterns.as_ref(),
@@ -37,6 +36,13 @@ impl ConstructorDetector {
             }
         }
 
+        // Symbols the extractors already classified as constructors
+        constructors.extend(
+            store
+                .get_all_symbols()
+                .filter(|symbol| symbol.kind == "constructor"),
+        );
+
         // Deduplicate by symbol ID
         constructors.sort_by(|a, b| a.id.as_str().cmp(b.id.as_str()));
         constructors.dedup_by(|a, b| a.id == b.id);
@@ -56,12 +62,17 @@ impl ConstructorDetector {
 
         let matches = store
             .get_all_symbols()
-            .filter(|symbol| symbol.kind == "function" && regex.is_match(&symbol.name))
+            .filter(|symbol| Self::is_callable_kind(&symbol.kind) && regex.is_match(&symbol.name))
             .collect();
 
         Ok(matches)
     }
 
+    /// Check if a symbol kind describes something callable (functions and methods).
+    fn is_callable_kind(kind: &str) -> bool {
+        matches!(kind, "function" | "method" | "constructor")
+    }
+
     /// Find symbols by custom regex pattern (language-agnostic).
     pub fn find_by_pattern<'a>(
         store: &'a SymbolStore,
@@ -79,7 +79,7 @@ impl CycleDetector {
             // Find a module with no dependencies in remaining set
             let next = remaining
                 .iter()
-                .find(|&&ref module| {
+                .find(|&module| {
                     adjacency
                         .get(module)
                         .map(|deps| deps.iter().all(|dep| !remaining.contains(dep)))
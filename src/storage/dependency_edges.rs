@@ -11,6 +11,12 @@ pub struct ImportEdge {
     pub import_type: ImportType,
     pub span: String,
     pub import_syntax: String,
+    /// Module the importing file belongs to (e.g. its Java package), when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_module: Option<String>,
+    /// Local name the import is bound to when it differs from `imported_symbol`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
 }
 
 /// Export relationship from files or modules.
@@ -22,6 +28,9 @@ pub struct ExportEdge {
     pub target_module: Option<String>,
     pub span: String,
     pub visibility: String,
+    /// Name the symbol is exported under when it differs from its own name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
 }
 
 /// Types of import relationships.
@@ -35,6 +44,8 @@ pub enum ImportType {
     Wildcard,
     /// Relative import
     Relative,
+    /// Dynamic import (`import()` in JavaScript/TypeScript)
+    Dynamic,
 }
 
 /// Types of export relationships.
@@ -67,8 +78,22 @@ impl ImportEdge {
             import_type,
             span,
             import_syntax,
+            source_module: None,
+            alias: None,
         }
     }
+
+    /// Set the module the importing file belongs to.
+    pub fn with_source_module(mut self, source_module: &str) -> Self {
+        self.source_module = Some(source_module.to_string());
+        self
+    }
+
+    /// Set the local name the import is bound to.
+    pub fn with_alias(mut self, alias: &str) -> Self {
+        self.alias = Some(alias.to_string());
+        self
+    }
 }
 
 impl ExportEdge {
@@ -88,6 +113,23 @@ impl ExportEdge {
             target_module,
             span,
             visibility,
+            alias: None,
+        }
+    }
+
+    /// Set the name the symbol is exported under.
+    pub fn with_alias(mut self, alias: &str) -> Self {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    /// Name importers use for this export: the alias, or the symbol's in-file name.
+    pub fn exported_name(&self) -> String {
+        if let Some(alias) = &self.alias {
+            return alias.clone();
         }
+        let prefix = format!("{}::", self.source_file.display());
+        let id = self.exported_symbol.as_str();
+        id.strip_prefix(&prefix).unwrap_or(id).to_string()
     }
 }
@@ -1,8 +1,10 @@
 use super::cycle_detector::CycleDetector;
 use super::dependency_analysis::DependencyAnalysis;
-use super::dependency_edges::ImportEdge;
-use super::module_graph::{Module, ModuleEdge, ModuleId};
+use super::dependency_edges::{ImportEdge, ImportType};
+use super::module_graph::{EdgeKind, Module, ModuleEdge, ModuleId, ModuleType};
+use super::module_normalizer::ModuleNormalizer;
 use crate::core::NTreeError;
+use crate::language::SupportedLanguage;
 use std::collections::HashMap;
 
 /// Directed module dependency graph with cycle detection.
@@ -24,20 +26,69 @@ impl DependencyGraph {
     }
 
     /// Build graph from import edges.
+    ///
+    /// Module IDs are normalized per language. The importing side uses the
+    /// edge's source module when the extractor knows it (a Java package) and
+    /// the file otherwise; relative JS/TS imports resolve to the imported file.
     pub fn from_import_edges(imports: &[ImportEdge]) -> Result<Self, NTreeError> {
         let mut graph = Self::new();
 
         for import in imports {
-            let from_id = ModuleId::from_language_path(
-                &import.source_file.to_string_lossy(),
-                "unknown", // TODO: Get language from file extension
+            let language = SupportedLanguage::from_path(&import.source_file)
+                .map(|language| language.name())
+                .unwrap_or("unknown");
+            let from_id = match &import.source_module {
+                Some(source_module) => ModuleId::from_language_path(source_module, language),
+                None => ModuleNormalizer::normalize_file(&import.source_file, language),
+            };
+            let to_id = ModuleNormalizer::normalize_import(
+                &import.target_module,
+                &import.source_file,
+                language,
             );
-            let to_id = ModuleId::from_language_path(&import.target_module, "unknown");
+            let edge_kind = match import.import_type {
+                ImportType::Dynamic => EdgeKind::Dynamic,
+                _ if matches!(language, "c" | "cpp") => EdgeKind::Include,
+                _ => EdgeKind::Import,
+            };
+
+            let path_root = import
+                .source_file
+                .parent()
+                .map(|parent| parent.to_path_buf());
+            let from_module = graph.modules.entry(from_id.clone()).or_insert_with(|| {
+                Module::new(
+                    from_id.clone(),
+                    Vec::new(),
+                    language.to_string(),
+                    ModuleType::Local,
+                )
+            });
+            // A module first seen as an import target turns out to be local
+            from_module.module_type = ModuleType::Local;
+            if let Some(root) = path_root {
+                if !from_module.path_roots.contains(&root) {
+                    from_module.path_roots.push(root);
+                }
+            }
+            graph.modules.entry(to_id.clone()).or_insert_with(|| {
+                let module_type = if to_id.as_str().starts_with("c:system:") {
+                    ModuleType::System
+                } else {
+                    ModuleType::External
+                };
+                Module::new(to_id.clone(), Vec::new(), language.to_string(), module_type)
+            });
+
+            // Imports between files of the same package are not module dependencies
+            if from_id == to_id {
+                continue;
+            }
 
             graph.add_edge(ModuleEdge::new(
                 from_id.clone(),
                 to_id.clone(),
-                super::module_graph::EdgeKind::Import,
+                edge_kind,
                 import.span.clone(),
             ));
         }
@@ -68,6 +119,16 @@ impl DependencyGraph {
         self.edges.push(edge);
     }
 
+    /// Get a module by ID.
+    pub fn get_module(&self, id: &ModuleId) -> Option<&Module> {
+        self.modules.get(id)
+    }
+
+    /// Get all dependency edges.
+    pub fn edges(&self) -> &[ModuleEdge] {
+        &self.edges
+    }
+
     /// Detect cycles in the module dependency graph.
     pub fn detect_cycles(&self) -> Vec<Vec<ModuleId>> {
         CycleDetector::detect_cycles(&self.adjacency, &self.modules)
@@ -128,8 +128,12 @@ impl SummaryEdgeGenerator {
         function_cfgs: &HashMap<SymbolId, ControlFlowGraph>,
     ) -> Option<usize> {
         if let Some(cfg) = function_cfgs.get(caller_sym) {
+            if let Some(node) = self.innermost_node_at(cfg, site_span) {
+                return Some(node);
+            }
+            // CFGs built without source spans can only be matched by label
             for node in &cfg.nodes {
-                if node.label.contains(site_span) || self.span_matches(&node.label, site_span) {
+                if node.label.contains(site_span) {
                     return Some(node.cfg_node);
                 }
             }
@@ -189,8 +193,18 @@ impl SummaryEdgeGenerator {
         None
     }
 
-    fn span_matches(&self, node_label: &str, span: &str) -> bool {
-        node_label.contains(span) || span.contains("line") && node_label.contains("line")
+    /// Find the smallest CFG node whose span contains the start of a
+    /// `"line:col–line:col"` call site span.
+    fn innermost_node_at(&self, cfg: &ControlFlowGraph, site_span: &str) -> Option<usize> {
+        let (line, col) = site_span.split('–').next()?.split_once(':')?;
+        let (line, col) = (line.trim().parse().ok()?, col.trim().parse().ok()?);
+
+        cfg.nodes
+            .iter()
+            .filter_map(|node| node.span.map(|span| (node.cfg_node, span)))
+            .filter(|(_, span)| span.contains_position(line, col))
+            .min_by_key(|(_, span)| span.end_byte - span.start_byte)
+            .map(|(node_id, _)| node_id)
     }
 
     pub fn get_call_sites(&self) -> &HashMap<usize, CallSiteSummary> {
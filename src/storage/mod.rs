@@ -1,6 +1,7 @@
 /// Storage backends for IR data and symbol tracking.
 pub mod call_edge;
 pub mod call_graph_table;
+pub mod call_resolver;
 pub mod constructor_detector;
 pub mod cycle_detector;
 pub mod data_export;
@@ -30,6 +31,7 @@ pub mod symbol_store;
 
 pub use call_edge::{CallConfidence, CallEdge, CallType};
 pub use call_graph_table::{CallGraph, CallGraphStats};
+pub use call_resolver::CallResolver;
 pub use constructor_detector::ConstructorDetector;
 pub use data_export::{DataSet, DataSetStats};
 pub use dependency_analysis::DependencyAnalysis;
@@ -1,4 +1,5 @@
 use super::module_graph::ModuleId;
+use std::path::{Component, Path, PathBuf};
 
 /// Module identifier normalization utilities.
 pub struct ModuleNormalizer;
@@ -16,6 +17,134 @@ impl ModuleNormalizer {
         }
     }
 
+    /// Module ID for a source file, matching what imports of that file normalize to.
+    pub fn normalize_file(file_path: &Path, language: &str) -> ModuleId {
+        match language {
+            "javascript" | "typescript" => ModuleId::new(format!(
+                "js:file:{}",
+                Self::js_module_path(file_path).display()
+            )),
+            "python" => Self::normalize_python(&Self::python_module_path(file_path)),
+            "rust" => Self::normalize_rust(&Self::rust_module_path(file_path)),
+            _ => Self::normalize(&file_path.to_string_lossy(), language),
+        }
+    }
+
+    /// Module ID for an import target written in `source_file`.
+    ///
+    /// Relative JavaScript/TypeScript specifiers are resolved against the
+    /// importing file, so `./utils` and `../src/utils.js` reach the same module.
+    pub fn normalize_import(target: &str, source_file: &Path, language: &str) -> ModuleId {
+        let is_relative = target.starts_with("./") || target.starts_with("../");
+
+        match language {
+            "javascript" | "typescript" if is_relative => {
+                let base = source_file.parent().unwrap_or_else(|| Path::new(""));
+                Self::normalize_file(&Self::join_relative(base, target), language)
+            }
+            _ => Self::normalize(target, language),
+        }
+    }
+
+    /// Resolve a quoted C/C++ include to an existing header file.
+    ///
+    /// The including file's directory is searched first, then each include
+    /// directory in order, matching how compilers look up `#include "..."`.
+    pub fn resolve_include(
+        header: &str,
+        including_file: &Path,
+        include_dirs: &[PathBuf],
+    ) -> Option<PathBuf> {
+        let base = including_file.parent().unwrap_or_else(|| Path::new(""));
+        std::iter::once(base)
+            .chain(include_dirs.iter().map(PathBuf::as_path))
+            .map(|dir| Self::join_relative(dir, header))
+            .find(|candidate| candidate.is_file())
+    }
+
+    /// File path without its JavaScript/TypeScript extension or trailing `index`.
+    fn js_module_path(file_path: &Path) -> PathBuf {
+        let is_source = matches!(
+            file_path.extension().and_then(|ext| ext.to_str()),
+            Some("js" | "mjs" | "cjs" | "jsx" | "ts" | "mts" | "cts" | "tsx")
+        );
+        let mut module_path = if is_source {
+            file_path.with_extension("")
+        } else {
+            file_path.to_path_buf()
+        };
+        // `foo.d.ts` declares the module `foo`
+        if module_path.extension().and_then(|ext| ext.to_str()) == Some("d") {
+            module_path = module_path.with_extension("");
+        }
+        if module_path.file_name().and_then(|name| name.to_str()) == Some("index") {
+            module_path.pop();
+        }
+        module_path
+    }
+
+    /// Dotted module name of a Python file: its stem, qualified by each
+    /// enclosing directory that is a package (has an `__init__.py`).
+    fn python_module_path(file_path: &Path) -> String {
+        let mut components = Vec::new();
+        let stem = file_path.file_stem().and_then(|stem| stem.to_str());
+        if let Some(stem) = stem.filter(|stem| *stem != "__init__") {
+            components.push(stem);
+        }
+        let mut dir = file_path.parent();
+        while let Some(package) = dir.filter(|dir| dir.join("__init__.py").is_file()) {
+            match package.file_name().and_then(|name| name.to_str()) {
+                Some(name) => components.push(name),
+                None => break,
+            }
+            dir = package.parent();
+        }
+        components.reverse();
+        components.join(".")
+    }
+
+    /// Path of a Rust file's module from its crate root: `src/net/http.rs` and
+    /// `src/net/http/mod.rs` are both `crate::net::http`.
+    fn rust_module_path(file_path: &Path) -> String {
+        let module_path = file_path.with_extension("");
+        let mut components: Vec<&str> = module_path
+            .components()
+            .filter_map(|component| component.as_os_str().to_str())
+            .collect();
+        match components.iter().rposition(|component| *component == "src") {
+            Some(src) => {
+                components.drain(..=src);
+            }
+            None => {
+                components.drain(..components.len().saturating_sub(1));
+            }
+        }
+        if components.last() == Some(&"mod")
+            || (components.len() == 1 && matches!(components[0], "lib" | "main"))
+        {
+            components.pop();
+        }
+        std::iter::once("crate")
+            .chain(components)
+            .collect::<Vec<_>>()
+            .join("::")
+    }
+
+    /// Lexically join a relative specifier onto a directory, folding `.` and `..`.
+    fn join_relative(base: &Path, relative: &str) -> PathBuf {
+        let mut joined = base.to_path_buf();
+        for component in Path::new(relative).components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    joined.pop();
+                }
+                other => joined.push(other),
+            }
+        }
+        joined
+    }
+
     /// Normalize Rust module path (crate::module::submodule).
     fn normalize_rust(path: &str) -> ModuleId {
         ModuleId::new(format!("rust:{}", path))
@@ -1,12 +1,14 @@
-use super::dependency_edges::ImportEdge;
+use super::dependency_edges::{ExportEdge, ExportType, ImportEdge};
 use super::export_table::ExportTable;
 use super::module_graph::ModuleId;
+use super::module_normalizer::ModuleNormalizer;
 use super::name_binding::NameBinding;
 use super::symbol_core::SymbolId;
 use crate::core::NTreeError;
+use crate::language::SupportedLanguage;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Cross-file name resolver with confidence tracking.
 #[derive(Debug, Serialize, Deserialize)]
@@ -49,6 +51,33 @@ impl NameResolver {
         Ok(resolver)
     }
 
+    /// Build resolver from extracted import and export edges.
+    ///
+    /// Each export is registered under the module of its file. Re-exports are
+    /// skipped because the re-exported symbol lives in another file.
+    pub fn from_edges(
+        import_edges: &[ImportEdge],
+        export_edges: &[ExportEdge],
+    ) -> Result<Self, NTreeError> {
+        let symbol_exports: Vec<(ModuleId, String, SymbolId)> = export_edges
+            .iter()
+            .filter(|export| export.export_type != ExportType::ReExport)
+            .map(|export| {
+                let module_id = ModuleNormalizer::normalize_file(
+                    &export.source_file,
+                    Self::language(&export.source_file),
+                );
+                (
+                    module_id,
+                    export.exported_name(),
+                    export.exported_symbol.clone(),
+                )
+            })
+            .collect();
+
+        Self::from_dependency_data(import_edges, &symbol_exports)
+    }
+
     /// Resolve a name usage to symbol ID with confidence.
     pub fn resolve_name(&self, file_path: &PathBuf, name: &str, site_span: String) -> NameBinding {
         use super::resolution_engine::ResolutionEngine;
@@ -58,36 +87,50 @@ impl NameResolver {
 
     /// Add import mapping from import edge.
     fn add_import_mapping(&mut self, import: &ImportEdge) -> Result<(), NTreeError> {
-        let module_id = ModuleId::from_language_path(&import.target_module, "python");
+        let language = Self::language(&import.source_file);
+        let module_id = ModuleNormalizer::normalize_import(
+            &import.target_module,
+            &import.source_file,
+            language,
+        );
 
         // Handle different import types
-        match &import.imported_symbol {
-            Some(symbol_name) => {
-                // from module import symbol
-                self.import_mappings
-                    .entry(import.source_file.clone())
-                    .or_insert_with(HashMap::new)
-                    .insert(symbol_name.clone(), (module_id, symbol_name.clone()));
-            }
-            None => {
+        let (alias, original_name) = match (&import.alias, &import.imported_symbol) {
+            // import { symbol as alias } / import alias from module
+            (Some(alias), Some(symbol_name)) => (alias.clone(), symbol_name.clone()),
+            // import * as alias from module / const alias = require(module)
+            (Some(alias), None) => (alias.clone(), import.target_module.clone()),
+            // from module import symbol
+            (None, Some(symbol_name)) => (symbol_name.clone(), symbol_name.clone()),
+            // JS/TS side-effect imports and dynamic imports bind no name
+            (None, None) if matches!(language, "javascript" | "typescript") => return Ok(()),
+            (None, None) => {
                 // import module (module name becomes alias)
                 let module_name = import
                     .target_module
                     .split('.')
-                    .last()
+                    .next_back()
                     .unwrap_or(&import.target_module)
                     .to_string();
-
-                self.import_mappings
-                    .entry(import.source_file.clone())
-                    .or_insert_with(HashMap::new)
-                    .insert(module_name, (module_id, import.target_module.clone()));
+                (module_name, import.target_module.clone())
             }
-        }
+        };
+
+        self.import_mappings
+            .entry(import.source_file.clone())
+            .or_default()
+            .insert(alias, (module_id, original_name));
 
         Ok(())
     }
 
+    /// Language name used to normalize module paths for a file.
+    fn language(file_path: &Path) -> &'static str {
+        SupportedLanguage::from_path(file_path)
+            .map(|language| language.name())
+            .unwrap_or("unknown")
+    }
+
     /// Get import mappings for debugging.
     pub fn get_import_mappings(&self) -> &HashMap<PathBuf, HashMap<String, (ModuleId, String)>> {
         &self.import_mappings
@@ -98,3 +141,9 @@ impl NameResolver {
         &self.export_table
     }
 }
+
+impl Default for NameResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::models::HalsteadResult;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -37,6 +38,9 @@ pub struct TopLevelSymbol {
     pub span: String,
     /// File where symbol is defined
     pub file_path: PathBuf,
+    /// Linkage for languages that have it (`internal` for C `static`, `external` otherwise)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub linkage: Option<String>,
 }
 
 impl TopLevelSymbol {
@@ -56,8 +60,21 @@ impl TopLevelSymbol {
             qualname,
             span,
             file_path,
+            linkage: None,
         }
     }
+
+    /// Override the symbol ID (e.g. to keep same-named members distinct).
+    pub fn with_id(mut self, id: SymbolId) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// Record the symbol's linkage.
+    pub fn with_linkage(mut self, linkage: &str) -> Self {
+        self.linkage = Some(linkage.to_string());
+        self
+    }
 }
 
 /// Function-specific facts for detailed analysis.
@@ -72,6 +89,9 @@ pub struct FunctionFacts {
     pub return_type: Option<String>,
     pub is_async: bool,
     pub is_private: bool,
+    /// Halstead metrics and Maintainability Index, when computed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub halstead: Option<HalsteadResult>,
 }
 
 impl FunctionFacts {
@@ -87,12 +107,19 @@ impl FunctionFacts {
             span: function_span.span.clone(),
             body_span: function_span.body.clone(),
             complexity: complexity_result.cyclomatic,
-            loc: 0,            // TODO: Calculate from span
+            loc: function_span.line_count(),
             return_type: None, // TODO: Extract from AST
             is_async: false,   // TODO: Detect from AST
             is_private: symbol.name.starts_with('_'),
+            halstead: None,
         }
     }
+
+    /// Attach the function's Halstead metrics and Maintainability Index.
+    pub fn with_halstead(mut self, halstead: HalsteadResult) -> Self {
+        self.halstead = Some(halstead);
+        self
+    }
 }
 
 /// Statistics about the symbol store.
@@ -0,0 +1,406 @@
+use ntree::{CallConfidence, CallEdge, CallType, FunctionSpan, SourceCode};
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Write `(relative path, content)` pairs into a fresh temp workspace.
+fn write_workspace(files: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    for (relative_path, content) in files {
+        let path = dir.path().join(relative_path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+/// Find the call edge whose callee expression is `callee`.
+fn find_call<'a>(edges: &[&'a CallEdge], callee: &str) -> &'a CallEdge {
+    edges
+        .iter()
+        .find(|edge| edge.module_hints.first().map(String::as_str) == Some(callee))
+        .unwrap_or_else(|| panic!("missing call to {}", callee))
+}
+
+fn target_names(edge: &CallEdge, root: &Path) -> Vec<String> {
+    let prefix = format!("{}/", root.display());
+    edge.targets
+        .iter()
+        .map(|target| target.as_str().trim_start_matches(&prefix).to_string())
+        .collect()
+}
+
+#[test]
+fn test_rust_call_graph() {
+    let dir = write_workspace(&[(
+        "lib.rs",
+        r#"
+struct Counter { n: u32 }
+
+impl Counter {
+    fn new() -> Self { Counter { n: 0 } }
+    fn bump(&mut self) { self.log(); }
+    fn log(&self) { println!("{}", self.n); }
+    fn reset() -> Self { Self::new() }
+}
+
+fn run() {
+    let mut c = Counter::new();
+    c.bump();
+    helper(Vec::new());
+}
+
+fn helper(v: Vec<u32>) {}
+"#,
+    )]);
+    let file_path = dir.path().join("lib.rs");
+
+    let analysis = SourceCode::new(&file_path).unwrap().analyze().unwrap();
+    let edges = analysis.call_graph().get_all_call_edges();
+    let root = dir.path();
+
+    let new = find_call(&edges, "Counter::new");
+    assert_eq!(new.confidence, CallConfidence::Direct);
+    assert_eq!(target_names(new, root), vec!["lib.rs::Counter::new"]);
+    assert!(new.caller_sym.as_str().ends_with("lib.rs::run"));
+
+    let bump = find_call(&edges, "c.bump");
+    assert_eq!(bump.confidence, CallConfidence::Virtual);
+    assert_eq!(bump.call_type, CallType::InstanceMethod);
+    assert_eq!(target_names(bump, root), vec!["lib.rs::Counter::bump"]);
+
+    // `self` and `Self` resolve within the impl
+    assert_eq!(
+        target_names(find_call(&edges, "self.log"), root),
+        vec!["lib.rs::Counter::log"]
+    );
+    assert_eq!(
+        target_names(find_call(&edges, "Self::new"), root),
+        vec!["lib.rs::Counter::new"]
+    );
+
+    // Calls nested in arguments are found; unknown callees stay unresolved
+    assert!(find_call(&edges, "helper").has_definitive_target());
+    assert_eq!(
+        find_call(&edges, "Vec::new").confidence,
+        CallConfidence::Unknown
+    );
+    assert_eq!(
+        find_call(&edges, "println!").confidence,
+        CallConfidence::Unknown
+    );
+
+    let stats = analysis.interprocedural().call_stats();
+    assert_eq!(stats.total_call_sites, edges.len());
+    assert_eq!(stats.direct_calls, 4);
+}
+
+#[test]
+fn test_python_workspace_call_graph() {
+    let dir = write_workspace(&[
+        (
+            "utils.py",
+            r#"
+def helper(x):
+    return x
+
+class Store:
+    def __init__(self):
+        self.items = []
+
+    def add(self, item):
+        self.items.append(item)
+        self._log()
+
+    def _log(self):
+        pass
+"#,
+        ),
+        (
+            "app.py",
+            r#"
+from utils import helper, Store
+import utils
+
+def main():
+    store = Store()
+    store.add(helper(1))
+    utils.helper(2)
+    missing()
+"#,
+        ),
+    ]);
+
+    let analysis = SourceCode::new(dir.path()).unwrap().analyze().unwrap();
+    let edges = analysis.call_graph().get_all_call_edges();
+    let root = dir.path();
+
+    let store = find_call(&edges, "Store");
+    assert_eq!(store.call_type, CallType::Constructor);
+    assert_eq!(target_names(store, root), vec!["utils.py::Store::__init__"]);
+
+    let helper = find_call(&edges, "helper");
+    assert_eq!(helper.confidence, CallConfidence::Direct);
+    assert_eq!(target_names(helper, root), vec!["utils.py::helper"]);
+    assert_eq!(
+        target_names(find_call(&edges, "utils.helper"), root),
+        vec!["utils.py::helper"]
+    );
+    assert_eq!(
+        target_names(find_call(&edges, "self._log"), root),
+        vec!["utils.py::Store::_log"]
+    );
+    assert_eq!(
+        find_call(&edges, "store.add").confidence,
+        CallConfidence::Virtual
+    );
+
+    // Unresolved Python calls may still bind at run time
+    assert_eq!(
+        find_call(&edges, "missing").confidence,
+        CallConfidence::Dynamic
+    );
+
+    let log_call = find_call(&edges, "self._log");
+    assert!(log_call
+        .caller_sym
+        .as_str()
+        .ends_with("utils.py::Store::add"));
+}
+
+#[test]
+fn test_python_imports_pick_the_imported_module() {
+    let dir = write_workspace(&[
+        ("parsing.py", "def helper(x):\n    return x\n"),
+        ("pkg/__init__.py", ""),
+        ("pkg/format.py", "def helper(x):\n    return str(x)\n"),
+        (
+            "app.py",
+            "from pkg.format import helper as fmt\nfrom parsing import helper\n\ndef main():\n    fmt(helper(1))\n",
+        ),
+    ]);
+
+    let analysis = SourceCode::new(dir.path()).unwrap().analyze().unwrap();
+    let edges = analysis.call_graph().get_all_call_edges();
+    let root = dir.path();
+
+    // Same-named functions resolve through the import, not by name alone
+    let helper = find_call(&edges, "helper");
+    assert_eq!(helper.confidence, CallConfidence::Direct);
+    assert_eq!(target_names(helper, root), vec!["parsing.py::helper"]);
+
+    let fmt = find_call(&edges, "fmt");
+    assert_eq!(fmt.confidence, CallConfidence::Direct);
+    assert_eq!(target_names(fmt, root), vec!["pkg/format.py::helper"]);
+}
+
+#[test]
+fn test_javascript_and_typescript_call_graph() {
+    let dir = write_workspace(&[
+        (
+            "widget.ts",
+            r#"
+export class Widget {
+    constructor() { this.draw(); }
+    draw(): void {}
+}
+"#,
+        ),
+        (
+            "app.js",
+            r#"
+import { Widget } from './widget';
+
+function render(items) {
+    const w = new Widget();
+    w?.draw();
+    html`<p>${items.length}</p>`;
+    items.map(format);
+}
+"#,
+        ),
+    ]);
+
+    let analysis = SourceCode::new(dir.path()).unwrap().analyze().unwrap();
+    let edges = analysis.call_graph().get_all_call_edges();
+    let root = dir.path();
+
+    let widget = find_call(&edges, "Widget");
+    assert_eq!(widget.call_type, CallType::Constructor);
+    assert_eq!(widget.confidence, CallConfidence::Direct);
+    assert_eq!(
+        target_names(widget, root),
+        vec!["widget.ts::Widget.constructor"]
+    );
+
+    let draw = find_call(&edges, "this.draw");
+    assert_eq!(draw.confidence, CallConfidence::Direct);
+    assert_eq!(target_names(draw, root), vec!["widget.ts::Widget.draw"]);
+
+    // Optional chaining is recorded like a plain member call
+    let optional = find_call(&edges, "w.draw");
+    assert_eq!(optional.call_type, CallType::InstanceMethod);
+    assert_eq!(target_names(optional, root), vec!["widget.ts::Widget.draw"]);
+
+    // Tagged templates call their tag
+    assert_eq!(
+        find_call(&edges, "html").confidence,
+        CallConfidence::Dynamic
+    );
+    assert_eq!(
+        find_call(&edges, "items.map").call_type,
+        CallType::InstanceMethod
+    );
+}
+
+#[test]
+fn test_java_call_graph() {
+    let dir = write_workspace(&[(
+        "geo/Shape.java",
+        r#"
+package geo;
+
+import java.util.List;
+
+public class Shape {
+    private int n;
+
+    public Shape(int n) { this.n = n; }
+
+    public int area() { return helper(n) + Math.max(n, 0); }
+
+    private int helper(int x) { return this.scale(x); }
+
+    private int scale(int x) { return x; }
+
+    static Shape make() { return new Shape(1); }
+
+    static List<Shape> many() { return new java.util.ArrayList<Shape>(); }
+}
+"#,
+    )]);
+
+    let analysis = SourceCode::new(dir.path()).unwrap().analyze().unwrap();
+    let edges = analysis.call_graph().get_all_call_edges();
+    let root = dir.path();
+
+    // Unqualified calls inside a method target the enclosing class
+    let helper = find_call(&edges, "helper");
+    assert_eq!(helper.call_type, CallType::InstanceMethod);
+    assert_eq!(helper.confidence, CallConfidence::Direct);
+    assert_eq!(
+        target_names(helper, root),
+        vec!["geo/Shape.java::Shape.helper(int)"]
+    );
+    assert_eq!(
+        target_names(find_call(&edges, "this.scale"), root),
+        vec!["geo/Shape.java::Shape.scale(int)"]
+    );
+
+    let max = find_call(&edges, "Math.max");
+    assert_eq!(max.call_type, CallType::StaticMethod);
+    assert_eq!(max.confidence, CallConfidence::Unknown);
+
+    let shape = find_call(&edges, "Shape");
+    assert_eq!(shape.call_type, CallType::Constructor);
+    assert_eq!(
+        target_names(shape, root),
+        vec!["geo/Shape.java::Shape.Shape(int)"]
+    );
+    assert_eq!(
+        find_call(&edges, "java.util.ArrayList").call_type,
+        CallType::Constructor
+    );
+}
+
+#[test]
+fn test_c_and_cpp_call_graph() {
+    let dir = write_workspace(&[
+        (
+            "apply.c",
+            r#"
+static int twice(int x) { return 2 * x; }
+
+int apply(int (*fp)(int), int v) {
+    int (*local)(int) = twice;
+    return fp(v) + local(v) + twice(v);
+}
+"#,
+        ),
+        (
+            "shape.cpp",
+            r#"
+namespace geo {
+class Shape {
+public:
+    Shape();
+    int area() const;
+    int scaled() const;
+    static Shape* make();
+};
+
+Shape::Shape() {}
+int Shape::area() const { return 1; }
+int Shape::scaled() const { return area() * 2; }
+Shape* Shape::make() { return new Shape(); }
+}
+
+int main() {
+    geo::Shape* s = geo::Shape::make();
+    return s->area();
+}
+"#,
+        ),
+    ]);
+
+    let analysis = SourceCode::new(dir.path()).unwrap().analyze().unwrap();
+    let edges = analysis.call_graph().get_all_call_edges();
+    let root = dir.path();
+
+    // Calls through function pointers cannot be resolved statically
+    for pointer in ["fp", "local"] {
+        let call = find_call(&edges, pointer);
+        assert_eq!(call.call_type, CallType::Dynamic);
+        assert_eq!(call.confidence, CallConfidence::Dynamic);
+        assert!(call.targets.is_empty());
+    }
+    let twice = find_call(&edges, "twice");
+    assert_eq!(twice.call_type, CallType::FreeFunction);
+    assert_eq!(target_names(twice, root), vec!["apply.c::twice"]);
+
+    let make = find_call(&edges, "geo::Shape::make");
+    assert_eq!(make.call_type, CallType::StaticMethod);
+    assert_eq!(make.confidence, CallConfidence::Direct);
+    assert_eq!(
+        target_names(make, root),
+        vec!["shape.cpp::geo::Shape::make()"]
+    );
+
+    let area = find_call(&edges, "s.area");
+    assert_eq!(area.call_type, CallType::InstanceMethod);
+    assert_eq!(
+        target_names(area, root),
+        vec!["shape.cpp::geo::Shape::area()const"]
+    );
+    assert_eq!(
+        target_names(find_call(&edges, "area"), root),
+        vec!["shape.cpp::geo::Shape::area()const"]
+    );
+
+    let shape = find_call(&edges, "Shape");
+    assert_eq!(shape.call_type, CallType::Constructor);
+    assert_eq!(
+        target_names(shape, root),
+        vec!["shape.cpp::geo::Shape::Shape()"]
+    );
+}
+
+#[test]
+fn test_symbol_spans_parse_back_to_coordinates() {
+    // Callers are matched to function nodes by the coordinates of their span
+    let span = FunctionSpan::format_span(2, 0, 11, 1);
+    assert_eq!(span, "3:1–12:2");
+    assert_eq!(FunctionSpan::parse_span(&span), Some((2, 0, 11, 1)));
+    assert_eq!(FunctionSpan::parse_span("3:1"), None);
+    assert_eq!(FunctionSpan::parse_span("0:1–2:1"), None);
+}
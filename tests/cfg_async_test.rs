@@ -0,0 +1,201 @@
+mod common;
+
+use common::{function_cfgs, write_source};
+use ntree::api::generate_basic_blocks;
+use serde_json::Value;
+
+#[test]
+fn test_python_await_async_for_and_async_with() {
+    let cfgs = function_cfgs(
+        r#"
+async def serve(conn):
+    data = await conn.read()
+    async for msg in conn:
+        await handle(msg)
+    async with lock:
+        flush()
+"#,
+        ".py",
+    );
+    let cfg = &cfgs[0];
+
+    // The await suspends to the caller and resumes into its statement
+    assert_eq!(
+        cfg.edge_kinds("await(value: conn.read())", "EXIT"),
+        vec!["suspend"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("await(value: conn.read())", "data = await conn.read();"),
+        vec!["resume"]
+    );
+
+    // Every iteration of `async for` awaits the next item
+    let next = "async_for_next(iter: conn)";
+    assert_eq!(cfg.edge_kinds("await handle(msg);", next), vec!["back"]);
+    assert_eq!(
+        cfg.edge_kinds(next, "for_loop(cond: conn.has_next, pattern: msg)"),
+        vec!["resume"]
+    );
+
+    // `async with` awaits on entering and leaving the context manager
+    assert_eq!(
+        cfg.edge_kinds("async_with_enter(value: lock)", "flush();"),
+        vec!["resume"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("flush();", "async_with_exit(value: lock)"),
+        vec!["next"]
+    );
+
+    assert_eq!(
+        cfg.suspension_points(),
+        vec![
+            "await(value: conn.read())",
+            next,
+            "await(value: handle(msg))",
+            "async_with_enter(value: lock)",
+            "async_with_exit(value: lock)",
+        ]
+    );
+}
+
+#[test]
+fn test_nested_awaits_run_innermost_first() {
+    let cfgs = function_cfgs(
+        r#"
+async def poll(q):
+    while await q.ready():
+        item = await q.get(await q.key())
+"#,
+        ".py",
+    );
+    let cfg = &cfgs[0];
+
+    assert_eq!(
+        cfg.edge_kinds(
+            "await(value: q.key())",
+            "await(value: q.get(await q.key()))"
+        ),
+        vec!["resume"]
+    );
+
+    // The loop condition's await runs again on every iteration
+    assert_eq!(
+        cfg.edge_kinds(
+            "item = await q.get(await q.key());",
+            "await(value: q.ready())"
+        ),
+        vec!["back"]
+    );
+}
+
+#[test]
+fn test_javascript_generators_and_for_await() {
+    let cfgs = function_cfgs(
+        r#"
+function* walk(tree) {
+    yield tree.value;
+    const rest = yield* tree.children;
+    const callback = async () => { await rest; };
+}
+
+async function drain(stream) {
+    for await (const chunk of stream) { write(chunk); }
+}
+"#,
+        ".js",
+    );
+
+    assert_eq!(
+        cfgs[0].suspension_points(),
+        vec![
+            "yield(value: tree.value)",
+            "yield_from(value: tree.children)"
+        ]
+    );
+    // The arrow function suspends on its own
+    assert!(cfgs[1].suspension_points().contains(&"await(value: rest)"));
+
+    let drain = &cfgs[2];
+    assert_eq!(
+        drain.suspension_points(),
+        vec!["async_for_next(iter: stream)"]
+    );
+    assert_eq!(
+        drain.edge_kinds("write(chunk);", "async_for_next(iter: stream)"),
+        vec!["back"]
+    );
+}
+
+#[test]
+fn test_rust_await_in_branches_and_tail_position() {
+    let cfgs = function_cfgs(
+        r#"
+async fn sync(conn: Conn) -> u8 {
+    if conn.ready().await {
+        conn.flush().await;
+    }
+    conn.close().await
+}
+"#,
+        ".rs",
+    );
+    let cfg = &cfgs[0];
+
+    assert_eq!(
+        cfg.edge_kinds("await(value: conn.ready())", "if (conn.ready().await)"),
+        vec!["resume"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("if (conn.ready().await)", "await(value: conn.flush())"),
+        vec!["true"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("await(value: conn.close())", "conn.close().await;"),
+        vec!["resume"]
+    );
+    assert_eq!(cfg.suspension_points().len(), 3);
+}
+
+#[test]
+fn test_basic_blocks_end_at_suspension_points() {
+    let temp_file = write_source(
+        r#"
+async fn serve(conn: Conn) {
+    let request = conn.read().await;
+    let response = handle(request);
+    conn.write(response).await;
+}
+"#,
+        ".rs",
+    );
+    let blocks = generate_basic_blocks(temp_file.path()).unwrap();
+    let lines: Vec<Value> = blocks[0]
+        .jsonl
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    let stmts: Vec<Vec<&str>> = lines
+        .iter()
+        .filter_map(|line| line["stmts"].as_array())
+        .map(|stmts| stmts.iter().map(|stmt| stmt.as_str().unwrap()).collect())
+        .collect();
+    assert_eq!(
+        stmts,
+        vec![
+            vec!["ENTRY", "let request = conn.read().await;"],
+            vec![
+                "let response = handle(request);",
+                "conn.write(response).await;"
+            ],
+            vec!["EXIT"],
+        ]
+    );
+
+    let resume_edges = lines
+        .iter()
+        .filter(|line| line["edge"]["kind"] == "resume")
+        .count();
+    assert_eq!(resume_edges, 1);
+}
@@ -0,0 +1,166 @@
+mod common;
+
+use common::write_source;
+use ntree::api::{generate_cfg_ir, generate_cfgs, CfgResult};
+
+/// `(function_name, qualname)` of each CFG generated for `code`.
+fn cfg_names(code: &str, suffix: &str) -> Vec<(String, String)> {
+    let temp_file = write_source(code, suffix);
+    generate_cfgs(temp_file.path())
+        .expect("Failed to generate CFGs")
+        .into_iter()
+        .map(|cfg| (cfg.function_name, cfg.qualname))
+        .collect()
+}
+
+fn names(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(name, qualname)| (name.to_string(), qualname.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_rust_methods_and_closures() {
+    let cfgs = cfg_names(
+        r#"
+struct Parser;
+
+impl<T> Parser<T> {
+    fn parse(&self, input: &str) -> usize {
+        let count = |s: &str| s.len();
+        count(input)
+    }
+}
+
+mod util {
+    pub fn helper() {}
+}
+"#,
+        ".rs",
+    );
+
+    assert_eq!(
+        cfgs,
+        names(&[
+            ("parse", "Parser::parse"),
+            ("<closure>", "Parser::parse::<closure>"),
+            ("helper", "util::helper"),
+        ])
+    );
+}
+
+#[test]
+fn test_javascript_methods_and_arrow_functions() {
+    let cfgs = cfg_names(
+        r#"
+class Parser {
+    constructor(tokens) { this.tokens = tokens; }
+    parse() { return this.tokens.map(t => t.value); }
+}
+const visit = function(node) { return node; };
+"#,
+        ".js",
+    );
+
+    assert_eq!(
+        cfgs,
+        names(&[
+            ("constructor", "Parser::constructor"),
+            ("parse", "Parser::parse"),
+            ("<lambda>", "Parser::parse::<lambda>"),
+            ("visit", "visit"),
+        ])
+    );
+}
+
+#[test]
+fn test_java_constructors_and_lambdas() {
+    let cfgs = cfg_names(
+        r#"
+class Parser {
+    Parser(int depth) {
+        this.depth = depth;
+    }
+
+    void parse(List<String> tokens) {
+        tokens.forEach(t -> {
+            if (t.isEmpty()) return;
+            emit(t);
+        });
+    }
+}
+"#,
+        ".java",
+    );
+
+    assert_eq!(
+        cfgs,
+        names(&[
+            ("Parser", "Parser::Parser"),
+            ("parse", "Parser::parse"),
+            ("<lambda>", "Parser::parse::<lambda>"),
+        ])
+    );
+}
+
+#[test]
+fn test_python_nested_functions_and_lambdas() {
+    let temp_file = write_source(
+        r#"
+class Parser:
+    def parse(self, tokens):
+        def keep(token):
+            return token.strip()
+        return sorted(tokens, key=lambda t: len(t))
+"#,
+        ".py",
+    );
+    let cfgs: Vec<CfgResult> = generate_cfgs(temp_file.path()).unwrap();
+
+    let qualnames: Vec<&str> = cfgs.iter().map(|cfg| cfg.qualname.as_str()).collect();
+    assert_eq!(
+        qualnames,
+        vec![
+            "Parser::parse",
+            "Parser::parse::keep",
+            "Parser::parse::<lambda>"
+        ]
+    );
+
+    // An expression-bodied lambda is a single statement between ENTRY and EXIT
+    let lambda = &cfgs[2];
+    assert!(lambda.jsonl.contains("\"label\":\"len(t);\""));
+    assert_eq!(lambda.jsonl.matches("cfg_edge").count(), 2);
+}
+
+#[test]
+fn test_cpp_out_of_class_definitions_and_ir() {
+    let code = r#"
+namespace geo {
+class Shape {
+public:
+    double area() { return 0; }
+};
+}
+
+double geo::Shape::perimeter() const {
+    auto scale = [](double x) { return x * 2; };
+    return scale(1);
+}
+"#;
+    assert_eq!(
+        cfg_names(code, ".cpp"),
+        names(&[
+            ("area", "geo::Shape::area"),
+            ("perimeter", "geo::Shape::perimeter"),
+            ("<lambda>", "geo::Shape::perimeter::<lambda>"),
+        ])
+    );
+
+    // The CFG IR covers the same functions
+    let temp_file = write_source(code, ".cpp");
+    let irs = generate_cfg_ir(temp_file.path()).unwrap();
+    let ir_names: Vec<&str> = irs.iter().map(|ir| ir.function_name.as_str()).collect();
+    assert_eq!(ir_names, vec!["area", "perimeter", "<lambda>"]);
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use common::function_cfg;
+
+#[test]
+fn test_c_do_while_and_goto_cleanup() {
+    let cfg = function_cfg(
+        r#"
+int copy(int n) {
+    int i = 0;
+    do {
+        if (fail(i)) goto cleanup;
+        i++;
+    } while (i < n);
+    return 0;
+cleanup:
+    release();
+    return -1;
+}
+"#,
+        ".c",
+    );
+
+    // The body runs before the condition is first checked
+    assert_eq!(
+        cfg.successors("int i = 0;"),
+        vec![("do_while_loop_body", "next")]
+    );
+    assert_eq!(
+        cfg.edge_kinds("do_while_loop(cond: i < n;)", "do_while_loop_body"),
+        vec!["back"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("do_while_loop(cond: i < n;)", "after_do_while_loop"),
+        vec!["false"]
+    );
+
+    // The goto reaches the label after the return
+    assert_eq!(
+        cfg.edge_kinds("goto cleanup;", "label(name: cleanup)"),
+        vec!["goto"]
+    );
+    assert_eq!(
+        cfg.successors("label(name: cleanup)"),
+        vec![("release();", "next")]
+    );
+    assert_eq!(cfg.edge_kinds("return -1;", "EXIT"), vec!["exit"]);
+    assert!(cfg.unreachable().is_empty());
+}
+
+#[test]
+fn test_goto_to_undefined_label_leaves_the_function() {
+    let cfg = function_cfg(
+        r#"
+int check(int n) {
+    if (n < 0) goto missing;
+    return n;
+}
+"#,
+        ".c",
+    );
+
+    // No dangling label node: the goto goes straight to EXIT
+    assert!(cfg
+        .nodes
+        .iter()
+        .all(|(_, label)| label != "label(name: missing)"));
+    assert_eq!(
+        cfg.edge_kinds("goto missing;", "EXIT"),
+        vec!["unresolved_goto"]
+    );
+    assert_eq!(cfg.cyclomatic(), 2);
+    assert!(cfg.unreachable().is_empty());
+}
+
+#[test]
+fn test_java_labeled_break_and_continue() {
+    let cfg = function_cfg(
+        r#"
+class Grid {
+    void scan(int[][] rows) {
+        outer:
+        for (int i = 0; i < rows.length; i++) {
+            for (int cell : rows[i]) {
+                if (cell < 0) continue outer;
+                if (cell == 0) break outer;
+                visit(cell);
+            }
+        }
+        done();
+    }
+}
+"#,
+        ".java",
+    );
+
+    // `continue outer` skips the inner loop's condition
+    assert_eq!(
+        cfg.successors("continue_stmt"),
+        vec![(
+            "for_loop(init: int i = 0, cond: i < rows.length, update: i++)",
+            "continue"
+        )]
+    );
+
+    // `break outer` leaves both loops, landing just before `done()`
+    let done = cfg.node_id("done();");
+    let after_outer = cfg.edges.iter().find(|(_, to, _)| *to == done).unwrap().0;
+    assert!(cfg
+        .edges
+        .contains(&(cfg.node_id("break_stmt"), after_outer, "break".to_string())));
+    assert!(cfg.unreachable().is_empty());
+}
+
+#[test]
+fn test_javascript_labeled_loops_and_do_while() {
+    let cfg = function_cfg(
+        r#"
+function drain(queues) {
+    search: for (const queue of queues) {
+        do {
+            if (queue.closed) break search;
+        } while (queue.pop());
+    }
+}
+"#,
+        ".js",
+    );
+
+    // `break search` leaves the do-while and the for loop
+    assert_eq!(
+        cfg.successors("break_stmt"),
+        vec![("after_for_loop", "break")]
+    );
+    assert_eq!(
+        cfg.edge_kinds("do_while_loop(cond: queue.pop();)", "after_do_while_loop"),
+        vec!["false"]
+    );
+    assert_eq!(
+        cfg.edge_kinds(
+            "after_do_while_loop",
+            "for_loop(cond: queues.has_next, pattern: queue)"
+        ),
+        vec!["back"]
+    );
+    assert!(cfg.unreachable().is_empty());
+}
+
+#[test]
+fn test_rust_labeled_loop() {
+    let cfg = function_cfg(
+        r#"
+fn poll(workers: &[Worker]) {
+    'outer: loop {
+        for worker in workers {
+            if worker.done() {
+                break 'outer;
+            }
+        }
+    }
+    finish();
+}
+"#,
+        ".rs",
+    );
+
+    assert_eq!(cfg.edge_kinds("break_stmt", "after_loop"), vec!["break"]);
+    assert_eq!(cfg.edge_kinds("after_loop", "finish();"), vec!["next"]);
+    // The inner loop's exit goes back around the outer loop
+    assert_eq!(cfg.edge_kinds("after_for_loop", "loop_expr"), vec!["back"]);
+    assert!(cfg.unreachable().is_empty());
+}
+
+#[test]
+fn test_rust_loop_without_break_never_completes() {
+    let cfg = function_cfg(
+        r#"
+fn serve(listener: Listener) {
+    loop {
+        listener.accept();
+    }
+}
+"#,
+        ".rs",
+    );
+
+    assert_eq!(
+        cfg.edge_kinds("listener.accept();", "loop_expr"),
+        vec!["back"]
+    );
+    // Nothing leaves the loop, so EXIT has no predecessors
+    let exit = cfg.node_id("EXIT");
+    assert!(!cfg.edges.iter().any(|(_, to, _)| *to == exit));
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use common::function_cfgs;
+
+#[test]
+fn test_with_runs_exit_on_every_path() {
+    let cfgs = function_cfgs(
+        r#"
+def load(path):
+    with open(path) as fh:
+        if not fh:
+            return None
+        data = fh.read()
+    return data
+"#,
+        ".py",
+    );
+    let cfg = &cfgs[0];
+    let enter = "with_enter(value: open(path) as fh)";
+    let exit = "with_exit(value: open(path) as fh)";
+
+    assert_eq!(cfg.edge_kinds(enter, "if (not fh)"), vec!["next"]);
+    assert_eq!(cfg.edge_kinds("data = fh.read();", exit), vec!["next"]);
+    assert_eq!(cfg.edge_kinds(exit, "return data"), vec!["next"]);
+
+    // A return inside the body leaves through `__exit__`
+    assert_eq!(cfg.edge_kinds("return None", "EXIT"), Vec::<&str>::new());
+    assert_eq!(cfg.edge_kinds("return None", exit), vec!["exit"]);
+
+    // So does an exception, which propagates after `__exit__`
+    assert_eq!(cfg.edge_kinds(enter, exit), vec!["exception"]);
+    assert_eq!(cfg.edge_kinds(exit, "EXIT"), vec!["exit", "exception"]);
+}
+
+#[test]
+fn test_match_cases_and_guards() {
+    let cfgs = function_cfgs(
+        r#"
+def run(cmd):
+    match cmd:
+        case ["go", direction] if direction in exits:
+            move(direction)
+        case ["look"]:
+            look()
+        case _:
+            raise ValueError(cmd)
+    done()
+"#,
+        ".py",
+    );
+    let cfg = &cfgs[0];
+    let dispatch = "match_expr(value: cmd)";
+    let go = "match_arm(pattern: [\"go\", direction])";
+    let guard = "case_guard(cond: direction in exits)";
+    let look = "match_arm(pattern: [\"look\"])";
+
+    assert_eq!(cfg.edge_kinds(dispatch, go), vec!["[\"go\", direction]"]);
+    assert_eq!(cfg.edge_kinds(dispatch, look), vec!["[\"look\"]"]);
+    assert_eq!(cfg.edge_kinds(dispatch, "match_arm(pattern: _)"), vec!["_"]);
+
+    // A failed guard moves on to the next case
+    assert_eq!(cfg.edge_kinds(go, guard), vec!["next"]);
+    assert_eq!(cfg.edge_kinds(guard, "move(direction);"), vec!["true"]);
+    assert_eq!(cfg.edge_kinds(guard, look), vec!["false"]);
+
+    // The wildcard case always matches and raises
+    assert!(cfg.edge_kinds(dispatch, "match_join").is_empty());
+    assert_eq!(
+        cfg.edge_kinds("raise ValueError(cmd);", "EXIT"),
+        vec!["exception"]
+    );
+    assert_eq!(cfg.edge_kinds("match_join", "done();"), vec!["next"]);
+}
+
+#[test]
+fn test_loop_else_is_skipped_by_break() {
+    let cfgs = function_cfgs(
+        r#"
+def find(xs):
+    for x in xs:
+        if x:
+            break
+    else:
+        missing()
+    found()
+"#,
+        ".py",
+    );
+    let cfg = &cfgs[0];
+    let header = "for_loop(cond: xs.has_next, pattern: x)";
+
+    assert_eq!(cfg.edge_kinds(header, "for_loop_else"), vec!["false"]);
+    assert_eq!(cfg.edge_kinds("missing();", "after_for_loop"), vec!["next"]);
+    assert_eq!(cfg.edge_kinds("if (x)", "break_stmt"), vec!["true"]);
+    assert_eq!(
+        cfg.edge_kinds("break_stmt", "after_for_loop"),
+        vec!["break"]
+    );
+    assert!(cfg.edge_kinds(header, "after_for_loop").is_empty());
+}
+
+#[test]
+fn test_assert_elif_and_comprehensions() {
+    let cfgs = function_cfgs(
+        r#"
+def scale(xs, k):
+    assert k, "no factor"
+    if k > 1:
+        grow()
+    elif k < 1:
+        shrink()
+    ys = [x * k for x in xs if x]
+    return ys
+"#,
+        ".py",
+    );
+    let cfg = &cfgs[0];
+
+    // A failed assertion raises
+    let assertion = "assert k, \"no factor\";";
+    assert_eq!(cfg.edge_kinds(assertion, "EXIT"), vec!["exception"]);
+    assert_eq!(cfg.edge_kinds(assertion, "if (k > 1)"), vec!["next"]);
+
+    // The elif is tested only when the if condition fails
+    assert_eq!(cfg.edge_kinds("if (k > 1)", "else-entry"), vec!["false"]);
+    assert_eq!(cfg.edge_kinds("if (k < 1)", "shrink();"), vec!["true"]);
+
+    // The comprehension loops before the assignment completes
+    let header = "comprehension(cond: xs.has_next, pattern: x)";
+    let filter = "comprehension_filter(cond: x)";
+    assert_eq!(cfg.edge_kinds(header, filter), vec!["true"]);
+    assert_eq!(cfg.edge_kinds(filter, header), vec!["false"]);
+    assert_eq!(cfg.edge_kinds(filter, "comprehension_body"), vec!["true"]);
+    assert_eq!(cfg.edge_kinds("comprehension_body", header), vec!["back"]);
+    assert_eq!(cfg.edge_kinds(header, "after_comprehension"), vec!["false"]);
+    assert_eq!(
+        cfg.edge_kinds("after_comprehension", "ys = [x * k for x in xs if x];"),
+        vec!["next"]
+    );
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use common::{function_cfgs, write_source};
+use ntree::api::generate_cfgs;
+use ntree::SourceCode;
+
+#[test]
+fn test_if_value_has_explicit_join() {
+    let cfgs = function_cfgs(
+        r#"
+fn sign(c: bool) -> i32 {
+    let v = if c { 1 } else { -1 };
+    v
+}
+"#,
+        ".rs",
+    );
+    let cfg = &cfgs[0];
+
+    assert_eq!(cfg.edge_kinds("ENTRY", "if (c)"), vec!["next"]);
+    assert_eq!(
+        cfg.edge_kinds("if (c)", "if_value_join"),
+        vec!["true", "false"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("if_value_join", "let v = if c { 1 } else { -1 };"),
+        vec!["next"]
+    );
+}
+
+#[test]
+fn test_match_value_with_returning_arm() {
+    let cfgs = function_cfgs(
+        r#"
+fn unwrap_or_zero(x: Option<i32>) -> i32 {
+    let n = match x {
+        Some(n) => n,
+        None => return 0,
+    };
+    n + 1
+}
+"#,
+        ".rs",
+    );
+    let cfg = &cfgs[0];
+    let binding = "let n = match x { Some(n) => n, None => return 0, };";
+
+    assert_eq!(
+        cfg.edge_kinds("match_arm(pattern: None;)", "return 0"),
+        vec!["next"]
+    );
+    assert!(!cfg.edge_kinds("return 0", "EXIT").is_empty());
+    assert!(cfg.edge_kinds("return 0", "match_join").is_empty());
+    assert_eq!(cfg.edge_kinds("match_join", binding), vec!["next"]);
+}
+
+#[test]
+fn test_try_in_argument_position() {
+    let cfgs = function_cfgs(
+        r#"
+fn load(a: &str, b: &str) -> Result<(), Error> {
+    record(parse(a)?, parse(b)?);
+    return finish(check(a)?)
+}
+"#,
+        ".rs",
+    );
+    let cfg = &cfgs[0];
+
+    // Each `?` may return before the call is made, in evaluation order
+    let first = cfg.node_id("try_expr(parse(a)?)");
+    let second = cfg.node_id("try_expr(parse(b)?)");
+    assert_eq!(cfg.edge_kinds("try_expr(parse(a)?)", "EXIT"), vec!["error"]);
+    assert_eq!(cfg.edge_kinds("try_expr(parse(b)?)", "EXIT"), vec!["error"]);
+    let ok = |try_id: u64| {
+        cfg.edges
+            .iter()
+            .find(|(from, _, kind)| *from == try_id && kind == "ok")
+            .map(|(_, to, _)| *to)
+            .unwrap()
+    };
+    assert!(cfg
+        .edges
+        .iter()
+        .any(|(from, to, _)| *from == ok(first) && *to == second));
+    let call = cfg.node_id("record(parse(a)?, parse(b)?);");
+    assert!(cfg
+        .edges
+        .iter()
+        .any(|(from, to, _)| *from == ok(second) && *to == call));
+
+    // The returned call still runs once its argument succeeds
+    assert!(!cfg
+        .edge_kinds("return finish(check(a)?)", "EXIT")
+        .is_empty());
+}
+
+#[test]
+fn test_value_expressions_count_towards_complexity() {
+    let temp_file = write_source(
+        r#"
+fn pick(c: bool, xs: &[i32]) -> i32 {
+    let base = if c { 1 } else { 2 };
+    let found = loop {
+        if base > 0 {
+            break base;
+        }
+    };
+    consume(match found {
+        0 => 1,
+        _ => 2,
+    })
+}
+"#,
+        ".rs",
+    );
+    let cfgs = generate_cfgs(temp_file.path()).expect("Failed to generate CFGs");
+    assert!(cfgs[0].jsonl.contains("\"after_loop\""));
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .analyze()
+        .expect("Analysis should succeed");
+
+    assert_eq!(result.complexity().all()[0].cyclomatic, 4);
+    assert!(result.complexity().with_unreachable_code().is_empty());
+}
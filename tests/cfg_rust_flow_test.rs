@@ -0,0 +1,199 @@
+mod common;
+
+use common::{function_cfgs, write_source};
+use ntree::SourceCode;
+
+#[test]
+fn test_let_else_diverges_on_pattern_failure() {
+    let cfgs = function_cfgs(
+        r#"
+fn parse(input: Option<&str>) -> usize {
+    let Some(text) = input else {
+        return 0;
+    };
+    text.len()
+}
+"#,
+        ".rs",
+    );
+    let cfg = &cfgs[0];
+    let let_else = "let_else(pattern: Some(text), value: input)";
+
+    assert_eq!(cfg.edge_kinds("ENTRY", let_else), vec!["next"]);
+    assert_eq!(cfg.edge_kinds(let_else, "return 0;"), vec!["no_match"]);
+    assert_eq!(cfg.edge_kinds("return 0;", "EXIT"), vec!["exit"]);
+}
+
+#[test]
+fn test_if_let_and_while_let_pattern_failure() {
+    let cfgs = function_cfgs(
+        r#"
+fn drain(first: Option<i32>, it: &mut Iter, ready: bool) {
+    if let Some(x) = first {
+        use_it(x);
+    }
+    while let Some(y) = it.next() {
+        use_it(y);
+    }
+    if let Ok(z) = parse() {
+        use_it(z);
+    } else {
+        skip();
+    }
+    if ready {
+        go();
+    }
+}
+"#,
+        ".rs",
+    );
+    let cfg = &cfgs[0];
+
+    // A pattern that fails to match skips the body along a `no_match` edge
+    let if_let = "if (let Some(x) = first)";
+    assert_eq!(cfg.edge_kinds(if_let, "use_it(x);"), vec!["true"]);
+    assert_eq!(cfg.edge_kinds(if_let, "join"), vec!["no_match"]);
+    let header = "while_loop(cond: let Some(y) = it.next();)";
+    assert_eq!(cfg.edge_kinds(header, "while_loop_body"), vec!["true"]);
+    assert_eq!(cfg.edge_kinds(header, "after_while_loop"), vec!["no_match"]);
+    assert_eq!(
+        cfg.edge_kinds("if (let Ok(z) = parse())", "skip();"),
+        vec!["no_match"]
+    );
+
+    // Boolean conditions keep their false edges
+    let out_kinds = cfg.out_kinds("if (ready)");
+    assert!(out_kinds.contains(&"false"));
+    assert!(!out_kinds.contains(&"no_match"));
+}
+
+#[test]
+fn test_labeled_block_break_value() {
+    let cfgs = function_cfgs(
+        r#"
+fn pick(xs: &[i32]) -> i32 {
+    for x in xs {
+        let v = 'found: {
+            if *x > 0 {
+                break 'found 1;
+            }
+            if *x == 0 {
+                break;
+            }
+            -1
+        };
+        record(v);
+    }
+    0
+}
+"#,
+        ".rs",
+    );
+    let cfg = &cfgs[0];
+    let block = "labeled_block(label: 'found)";
+
+    assert_eq!(cfg.edge_kinds("for_loop_body", block), vec!["next"]);
+    assert_eq!(
+        cfg.edge_kinds(
+            "after_labeled_block",
+            "let v = 'found: { if *x > 0 { break 'found 1; } if *x == 0 { break; } -1 };"
+        ),
+        vec!["next"]
+    );
+
+    // The labeled break leaves the block, an unlabeled one the loop
+    let breaks: Vec<u64> = cfg
+        .nodes
+        .iter()
+        .filter(|(_, label)| label == "break_stmt")
+        .map(|(id, _)| *id)
+        .collect();
+    let after_block = cfg.node_id("after_labeled_block");
+    let after_loop = cfg.node_id("after_for_loop");
+    let target = |from: u64| {
+        cfg.edges
+            .iter()
+            .find(|(edge_from, _, kind)| *edge_from == from && kind == "break")
+            .map(|(_, to, _)| *to)
+    };
+    assert_eq!(target(breaks[0]), Some(after_block));
+    assert_eq!(target(breaks[1]), Some(after_loop));
+}
+
+#[test]
+fn test_diverging_calls_terminate_paths() {
+    let cfgs = function_cfgs(
+        r#"
+fn run(mode: u8) -> u8 {
+    if mode == 0 {
+        unreachable!("mode checked");
+    }
+    if mode == 1 {
+        std::process::exit(1);
+    }
+    if mode == 2 {
+        fail("bad mode");
+    }
+    let check = |m: Option<u8>| -> Option<u8> { Some(m? + 1) };
+    todo!()
+}
+
+fn fail(msg: &str) -> ! {
+    panic!("{}", msg)
+}
+"#,
+        ".rs",
+    );
+    let cfg = &cfgs[0];
+
+    assert_eq!(
+        cfg.edge_kinds("unreachable!(\"mode checked\");", "EXIT"),
+        vec!["exception"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("std::process::exit(1);", "EXIT"),
+        vec!["exit"]
+    );
+    assert_eq!(cfg.edge_kinds("fail(\"bad mode\");", "EXIT"), vec!["exit"]);
+    assert_eq!(
+        cfg.edge_kinds("panic_expr(todo!())", "EXIT"),
+        vec!["exception"]
+    );
+
+    // A `?` inside a closure returns from the closure, not the function
+    assert!(!cfg
+        .nodes
+        .iter()
+        .any(|(_, label)| label.starts_with("try_expr")));
+}
+
+#[test]
+fn test_idiomatic_rust_has_no_unreachable_nodes() {
+    let temp_file = write_source(
+        r#"
+fn classify(value: Option<i32>) -> &'static str {
+    let Some(v) = value else {
+        return "none";
+    };
+    let kind = 'kind: {
+        if v < 0 {
+            break 'kind "negative";
+        }
+        "positive"
+    };
+    match v {
+        0 => unreachable!(),
+        _ => kind,
+    }
+}
+"#,
+        ".rs",
+    );
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .with_complexity_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    assert!(result.complexity().with_unreachable_code().is_empty());
+}
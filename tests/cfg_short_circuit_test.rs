@@ -0,0 +1,189 @@
+mod common;
+
+use common::{write_source, Cfg};
+use ntree::api::{generate_cfgs, generate_cfgs_with_options, CfgOptions};
+use ntree::SourceCode;
+
+/// Build the CFG of the single function in `code` with short-circuit expansion.
+fn expanded_cfg(code: &str, suffix: &str) -> Cfg {
+    let temp_file = write_source(code, suffix);
+    let options = CfgOptions::new().with_short_circuit(true);
+    let cfgs =
+        generate_cfgs_with_options(temp_file.path(), &options).expect("Failed to generate CFGs");
+    assert_eq!(cfgs.len(), 1);
+    Cfg::from_jsonl(&cfgs[0].jsonl)
+}
+
+#[test]
+fn test_python_and_or_conditions() {
+    let cfg = expanded_cfg(
+        r#"
+def check(a, b, c):
+    if a and b or c:
+        accept()
+    reject()
+"#,
+        ".py",
+    );
+
+    // The if node tests its operands in evaluation order
+    assert_eq!(
+        cfg.edge_kinds("if (a and b or c)", "condition(a)"),
+        vec!["next"]
+    );
+    assert_eq!(cfg.edge_kinds("condition(a)", "condition(b)"), vec!["true"]);
+    assert_eq!(
+        cfg.edge_kinds("condition(a)", "condition(c)"),
+        vec!["false"]
+    );
+    assert_eq!(cfg.edge_kinds("condition(b)", "accept();"), vec!["true"]);
+    assert_eq!(
+        cfg.edge_kinds("condition(b)", "condition(c)"),
+        vec!["false"]
+    );
+    assert_eq!(cfg.edge_kinds("condition(c)", "accept();"), vec!["true"]);
+    assert!(cfg.edge_kinds("if (a and b or c)", "accept();").is_empty());
+
+    // One decision per operand
+    assert_eq!(cfg.cyclomatic(), 4);
+}
+
+#[test]
+fn test_c_ternaries_and_loop_conditions() {
+    let cfg = expanded_cfg(
+        r#"
+int clamp(int a, int b) {
+    int y = a > b ? a : b;
+    for (int i = 0; i < a && i < b; i++) { step(i); }
+    do { y--; } while (y > a || y > b);
+    return y;
+}
+"#,
+        ".c",
+    );
+
+    // A ternary is tested before its statement, which uses the selected value
+    assert_eq!(
+        cfg.edge_kinds("condition(a > b)", "ternary_true(value: a)"),
+        vec!["true"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("condition(a > b)", "ternary_false(value: b)"),
+        vec!["false"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("ternary_false(value: b)", "int y = a > b ? a : b;"),
+        vec!["next"]
+    );
+
+    assert_eq!(
+        cfg.edge_kinds("condition(i < a)", "after_for_loop"),
+        vec!["false"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("condition(i < b)", "for_loop_body"),
+        vec!["true"]
+    );
+
+    // A do-while condition that holds loops back to the body
+    assert_eq!(
+        cfg.edge_kinds("condition(y > a)", "do_while_loop_body"),
+        vec!["back"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("condition(y > a)", "condition(y > b)"),
+        vec!["false"]
+    );
+
+    assert_eq!(cfg.cyclomatic(), 6);
+}
+
+#[test]
+fn test_rust_let_chains_and_nested_ternaries() {
+    let cfg = expanded_cfg(
+        r#"
+fn first(items: &[u8], strict: bool) -> u8 {
+    if let Some(x) = items.first() && strict {
+        use_it(x);
+    } else {
+        skip();
+    }
+    0
+}
+"#,
+        ".rs",
+    );
+
+    assert_eq!(
+        cfg.edge_kinds(
+            "condition(let Some(x) = items.first())",
+            "condition(strict)"
+        ),
+        vec!["true"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("condition(let Some(x) = items.first())", "skip();"),
+        vec!["no_match"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("condition(strict)", "skip();"),
+        vec!["false"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("condition(strict)", "use_it(x);"),
+        vec!["true"]
+    );
+
+    let cfg = expanded_cfg(
+        r#"
+function sign(n) {
+    const s = n > 0 ? 1 : n < 0 ? -1 : 0;
+    return s;
+}
+"#,
+        ".js",
+    );
+
+    // The nested ternary runs before the outer false branch selects its value
+    assert_eq!(
+        cfg.edge_kinds("condition(n > 0)", "condition(n < 0)"),
+        vec!["false"]
+    );
+    assert_eq!(
+        cfg.edge_kinds(
+            "ternary_true(value: -1)",
+            "ternary_false(value: n < 0 ? -1 : 0)"
+        ),
+        vec!["next"]
+    );
+    assert_eq!(cfg.cyclomatic(), 3);
+}
+
+#[test]
+fn test_expansion_is_opt_in() {
+    let code = r#"
+function pick(a, b) {
+    if (a && b) { return a ? b : a; }
+    return null;
+}
+"#;
+    let temp_file = write_source(code, ".js");
+    let cfgs = generate_cfgs(temp_file.path()).unwrap();
+    assert!(!cfgs[0].jsonl.contains("condition("));
+
+    let plain = SourceCode::new(temp_file.path())
+        .unwrap()
+        .minimal()
+        .analyze()
+        .unwrap();
+    let expanded = SourceCode::new(temp_file.path())
+        .unwrap()
+        .minimal()
+        .with_short_circuit_expansion(true)
+        .analyze()
+        .unwrap();
+
+    assert_eq!(plain.complexity().all()[0].cyclomatic, 2);
+    assert_eq!(expanded.complexity().all()[0].cyclomatic, 4);
+    assert!(expanded.cfgs().all()[0].jsonl.contains("condition(b)"));
+}
@@ -0,0 +1,146 @@
+mod common;
+
+use common::write_source;
+use ntree::api::{generate_basic_blocks, generate_cfg_ir, generate_cfgs};
+use ntree::CfgSpan;
+use serde_json::Value;
+
+/// JSONL lines of the single function's CFG in `code`.
+fn cfg_lines(code: &str, suffix: &str) -> Vec<Value> {
+    let temp_file = write_source(code, suffix);
+    let cfgs = generate_cfgs(temp_file.path()).expect("Failed to generate CFGs");
+    assert_eq!(cfgs.len(), 1);
+    cfgs[0]
+        .jsonl
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+fn node<'a>(lines: &'a [Value], label: &str) -> &'a Value {
+    lines
+        .iter()
+        .find(|line| line["label"] == label)
+        .unwrap_or_else(|| panic!("missing node {}", label))
+}
+
+fn span(value: &Value) -> CfgSpan {
+    serde_json::from_value(value["span"].clone()).expect("node has no span")
+}
+
+#[test]
+fn test_statement_spans_point_back_to_source() {
+    let code = "fn scale(x: i32) -> i32 {\n    let y = x * 2;\n    if y > 10 {\n        return 10;\n    }\n    y\n}\n";
+    let lines = cfg_lines(code, ".rs");
+
+    let assignment = span(node(&lines, "let y = x * 2;"));
+    assert_eq!(
+        &code[assignment.start_byte..assignment.end_byte],
+        "let y = x * 2;"
+    );
+    assert_eq!((assignment.start_line, assignment.start_col), (2, 5));
+    assert_eq!((assignment.end_line, assignment.end_col), (2, 19));
+    assert_eq!(assignment.format(), "2:5–2:19");
+
+    // A condition node covers just its condition; the join covers the whole if
+    let condition = span(node(&lines, "if (y > 10)"));
+    assert_eq!(&code[condition.start_byte..condition.end_byte], "y > 10");
+    let join = span(node(&lines, "join"));
+    assert!(join.contains(&condition));
+    assert_eq!((join.start_line, join.end_line), (3, 5));
+
+    // ENTRY and EXIT are synthetic
+    assert!(node(&lines, "ENTRY").get("span").is_none());
+    assert!(node(&lines, "EXIT").get("span").is_none());
+}
+
+#[test]
+fn test_edges_carry_the_span_of_their_source_node() {
+    let lines = cfg_lines(
+        r#"
+def pick(items):
+    for item in items:
+        if item:
+            break
+    return item
+"#,
+        ".py",
+    );
+
+    let break_node = node(&lines, "break_stmt");
+    let break_id = break_node["cfg_node"].as_u64().unwrap();
+    let break_edge = lines
+        .iter()
+        .filter_map(|line| line.get("cfg_edge"))
+        .find(|edge| edge["from"] == break_id)
+        .expect("break has an outgoing edge");
+    assert_eq!(span(break_edge), span(break_node));
+    assert_eq!(span(break_node).format(), "5:13–5:18");
+
+    // Edges leaving ENTRY have no source construct
+    let entry_edge = lines
+        .iter()
+        .filter_map(|line| line.get("cfg_edge"))
+        .find(|edge| edge["from"] == 0)
+        .unwrap();
+    assert!(entry_edge.get("span").is_none());
+}
+
+#[test]
+fn test_ir_nodes_and_edges_carry_locations() {
+    let code = "int sign(int x) {\n    while (x > 1) x--;\n    return x;\n}\n";
+    let temp_file = write_source(code, ".c");
+    let irs = generate_cfg_ir(temp_file.path()).unwrap();
+    assert_eq!(irs.len(), 1);
+
+    let return_node = irs[0]
+        .nodes
+        .iter()
+        .find(|node| node.label == "return x;")
+        .unwrap();
+    let location = return_node.location.expect("IR node has a location");
+    assert_eq!(&code[location.start_byte..location.end_byte], "return x;");
+    assert_eq!(return_node.span, "3:5–3:14");
+
+    let condition = irs[0]
+        .nodes
+        .iter()
+        .find(|node| node.label.starts_with("while_loop"))
+        .unwrap();
+    let back_edge = irs[0]
+        .edges
+        .iter()
+        .find(|edge| edge.kind == "back")
+        .unwrap();
+    assert_ne!(back_edge.from, condition.id);
+    assert!(back_edge.location.is_some());
+
+    // The locations survive a JSONL round trip
+    let jsonl = irs[0].to_jsonl();
+    assert!(jsonl.contains("\"location\":{\"start_byte\":"));
+}
+
+#[test]
+fn test_basic_block_spans_cover_all_statements() {
+    let code =
+        "fn sum(a: i32, b: i32) -> i32 {\n    let c = a + b;\n    let d = c * 2;\n    d\n}\n";
+    let temp_file = write_source(code, ".rs");
+    let blocks = generate_basic_blocks(temp_file.path()).unwrap();
+
+    let block: Value = blocks[0]
+        .jsonl
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).unwrap())
+        .find(|line| {
+            line["stmts"]
+                .as_array()
+                .is_some_and(|stmts| stmts.len() > 1)
+        })
+        .expect("straight-line statements share a block");
+
+    let location: CfgSpan = serde_json::from_value(block["location"].clone()).unwrap();
+    let text = &code[location.start_byte..location.end_byte];
+    assert!(text.starts_with("let c = a + b;"));
+    assert!(text.ends_with("let d = c * 2;"));
+    assert_eq!(block["span"], "2:5–3:19");
+}
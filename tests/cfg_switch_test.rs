@@ -0,0 +1,155 @@
+mod common;
+
+use common::function_cfg;
+
+#[test]
+fn test_c_switch_fallthrough() {
+    let cfg = function_cfg(
+        r#"
+int classify(int x) {
+    switch (x) {
+    case 1:
+        one();
+    case 2: {
+        two();
+        break;
+    }
+    default:
+        other();
+    }
+    return x;
+}
+"#,
+        ".c",
+    );
+
+    assert_eq!(
+        cfg.out_kinds("switch_stmt(value: x)"),
+        vec!["1", "2", "default"]
+    );
+    // `case 1` has no break, so it runs into `case 2`
+    assert_eq!(
+        cfg.edge_kinds("one();", "case(value: 2)"),
+        vec!["fallthrough"]
+    );
+    assert_eq!(cfg.edge_kinds("break_stmt", "after_switch"), vec!["break"]);
+    assert_eq!(cfg.edge_kinds("other();", "after_switch"), vec!["next"]);
+    assert_eq!(cfg.edge_kinds("after_switch", "return x;"), vec!["next"]);
+    assert_eq!(cfg.cyclomatic(), 3);
+}
+
+#[test]
+fn test_javascript_switch_without_default() {
+    let cfg = function_cfg(
+        r#"
+function reduce(state, action) {
+    switch (action.type) {
+        case "add":
+            state = add(state, action);
+            break;
+        case "reset":
+            return initial();
+    }
+    return state;
+}
+"#,
+        ".js",
+    );
+
+    // Without a default, an unmatched value skips straight past the switch
+    assert_eq!(
+        cfg.successors("switch_stmt(value: action.type)"),
+        vec![
+            ("case(value: \"add\")", "\"add\""),
+            ("case(value: \"reset\")", "\"reset\""),
+            ("after_switch", "no_match"),
+        ]
+    );
+    assert_eq!(cfg.edge_kinds("break_stmt", "after_switch"), vec!["break"]);
+    assert_eq!(cfg.edge_kinds("return initial();", "EXIT"), vec!["exit"]);
+    assert_eq!(cfg.cyclomatic(), 3);
+}
+
+#[test]
+fn test_java_arrow_cases_and_switch_expressions() {
+    let cfg = function_cfg(
+        r#"
+class Shapes {
+    int sides(Shape shape) {
+        switch (shape.kind()) {
+            case TRIANGLE -> log("three");
+            case SQUARE, RECTANGLE -> { log("four"); }
+            default -> throw new IllegalStateException();
+        }
+        int corners = switch (shape.kind()) {
+            case TRIANGLE -> 3;
+            default -> {
+                int n = count(shape);
+                yield n;
+            }
+        };
+        return corners;
+    }
+}
+"#,
+        ".java",
+    );
+
+    assert_eq!(
+        cfg.out_kinds("switch_stmt(value: shape.kind())"),
+        vec!["TRIANGLE", "SQUARE, RECTANGLE", "default"]
+    );
+    // Arrow cases never fall through
+    assert!(!cfg.edges.iter().any(|(_, _, kind)| kind == "fallthrough"));
+    assert_eq!(
+        cfg.edge_kinds("throw new IllegalStateException();", "EXIT"),
+        vec!["exception"]
+    );
+
+    // The switch expression is expanded before the declaration that uses it
+    let declaration = "int corners = switch (shape.kind()) { case TRIANGLE -> 3; default -> { int n = count(shape); yield n; } };";
+    let after_expression = cfg
+        .edges
+        .iter()
+        .find(|(_, to, _)| *to == cfg.node_id(declaration))
+        .map(|(from, _, _)| *from)
+        .unwrap();
+    assert!(cfg
+        .edges
+        .iter()
+        .any(|(from, to, kind)| *from == cfg.node_id("yield n;")
+            && *to == after_expression
+            && kind == "break"));
+    assert_eq!(cfg.edge_kinds(declaration, "return corners;"), vec!["next"]);
+}
+
+#[test]
+fn test_switch_where_every_case_returns() {
+    let cfg = function_cfg(
+        r#"
+int sign(int x) {
+    switch (x) {
+    case 1:
+        return 1;
+    default:
+        return 2;
+    }
+}
+"#,
+        ".c",
+    );
+
+    // Nothing leaves the switch, so there is no node after it
+    assert!(!cfg.nodes.iter().any(|(_, label)| label == "after_switch"));
+    assert_eq!(cfg.edge_kinds("return 1;", "EXIT"), vec!["exit"]);
+    assert_eq!(cfg.edge_kinds("return 2;", "EXIT"), vec!["exit"]);
+    // Every node but ENTRY has a predecessor
+    for (id, label) in &cfg.nodes {
+        assert!(
+            label == "ENTRY" || cfg.edges.iter().any(|(_, to, _)| to == id),
+            "{} is unreachable",
+            label
+        );
+    }
+    assert_eq!(cfg.cyclomatic(), 2);
+}
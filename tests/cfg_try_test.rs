@@ -0,0 +1,219 @@
+mod common;
+
+use common::function_cfg;
+
+#[test]
+fn test_python_try_except_else_finally() {
+    let cfg = function_cfg(
+        r#"
+def load(path):
+    try:
+        data = read(path)
+    except (ValueError, KeyError) as e:
+        log(e)
+    except OSError:
+        raise
+    else:
+        parse(data)
+    finally:
+        close()
+    done()
+"#,
+        ".py",
+    );
+
+    // Each handler is reached from the try with its exception type
+    assert_eq!(
+        cfg.edge_kinds("try_stmt", "catch_clause(type: ValueError, KeyError)"),
+        vec!["exception(ValueError, KeyError)"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("try_stmt", "catch_clause(type: OSError)"),
+        vec!["exception(OSError)"]
+    );
+
+    // The guarded block, else block and handler bodies are real sub-CFGs
+    assert_eq!(
+        cfg.edge_kinds("try_stmt", "data = read(path);"),
+        vec!["next"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("data = read(path);", "try_else"),
+        vec!["next"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("parse(data);", "finally_block"),
+        vec!["next"]
+    );
+    assert_eq!(cfg.edge_kinds("log(e);", "finally_block"), vec!["next"]);
+
+    // A re-raise in a handler runs the finally block before leaving the function
+    assert_eq!(cfg.edge_kinds("raise;", "finally_block"), vec!["exception"]);
+    assert_eq!(cfg.edge_kinds("close();", "EXIT"), vec!["exception"]);
+    assert_eq!(cfg.edge_kinds("close();", "done();"), vec!["next"]);
+
+    assert!(cfg.unreachable().is_empty());
+}
+
+#[test]
+fn test_return_inside_try_runs_finally() {
+    let cfg = function_cfg(
+        r#"
+class Reader {
+    int read() {
+        try {
+            open();
+            return next();
+        } finally {
+            close();
+        }
+    }
+}
+"#,
+        ".java",
+    );
+
+    assert_eq!(
+        cfg.edge_kinds("return next();", "finally_block"),
+        vec!["exit"]
+    );
+    // Only the return and an uncaught exception reach the finally block
+    assert_eq!(
+        cfg.edge_kinds("try_stmt", "finally_block"),
+        vec!["exception"]
+    );
+    let mut kinds = cfg.edge_kinds("close();", "EXIT");
+    kinds.sort();
+    assert_eq!(kinds, vec!["exception", "exit"]);
+
+    assert!(cfg.unreachable().is_empty());
+}
+
+#[test]
+fn test_typed_catch_with_finally() {
+    let cfg = function_cfg(
+        r#"
+class Reader {
+    void read() {
+        try {
+            open();
+        } catch (IOException e) {
+            log(e);
+        } finally {
+            close();
+        }
+        done();
+    }
+}
+"#,
+        ".java",
+    );
+
+    // An exception the handler does not catch still runs the finally block,
+    // then leaves the function
+    assert_eq!(
+        cfg.edge_kinds("try_stmt", "catch_clause(type: IOException)"),
+        vec!["exception(IOException)"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("try_stmt", "finally_block"),
+        vec!["exception"]
+    );
+    assert_eq!(cfg.edge_kinds("close();", "EXIT"), vec!["exception"]);
+    assert_eq!(cfg.edge_kinds("close();", "done();"), vec!["next"]);
+
+    // A catch-all handler catches everything, so nothing bypasses it
+    let cfg = function_cfg(
+        r#"
+function read() {
+    try {
+        open();
+    } catch (e) {
+        log(e);
+    } finally {
+        close();
+    }
+}
+"#,
+        ".js",
+    );
+    assert!(cfg.edge_kinds("try_stmt", "finally_block").is_empty());
+    assert_eq!(cfg.edge_kinds("close();", "EXIT"), vec!["next"]);
+
+    assert!(cfg.unreachable().is_empty());
+}
+
+#[test]
+fn test_nested_finally_blocks_run_in_order() {
+    let cfg = function_cfg(
+        r#"
+function save(doc) {
+    try {
+        try {
+            return write(doc);
+        } finally {
+            unlock();
+        }
+    } finally {
+        flush();
+    }
+}
+"#,
+        ".js",
+    );
+
+    let finally_ids: Vec<u64> = cfg
+        .nodes
+        .iter()
+        .filter(|(_, label)| label == "finally_block")
+        .map(|(id, _)| *id)
+        .collect();
+    assert_eq!(finally_ids.len(), 2);
+
+    // The inner finally continues the return into the outer finally, not EXIT
+    let unlock = cfg.node_id("unlock();");
+    let targets: Vec<u64> = cfg
+        .edges
+        .iter()
+        .filter(|(from, _, kind)| *from == unlock && kind == "exit")
+        .map(|(_, to, _)| *to)
+        .collect();
+    assert_eq!(targets.len(), 1);
+    assert!(finally_ids.contains(&targets[0]));
+    assert!(cfg.edge_kinds("flush();", "EXIT").contains(&"exit"));
+}
+
+#[test]
+fn test_cpp_catch_clauses() {
+    let cfg = function_cfg(
+        r#"
+void run() {
+    try {
+        step();
+    } catch (const std::exception& e) {
+        report(e);
+        throw;
+    } catch (...) {
+        recover();
+    }
+    finish();
+}
+"#,
+        ".cpp",
+    );
+
+    assert_eq!(
+        cfg.edge_kinds("try_stmt", "catch_clause(type: std::exception)"),
+        vec!["exception(std::exception)"]
+    );
+    assert_eq!(
+        cfg.edge_kinds("try_stmt", "catch_clause(type: *)"),
+        vec!["exception"]
+    );
+    // Rethrowing outside any try leaves the function
+    assert_eq!(cfg.edge_kinds("throw;", "EXIT"), vec!["exception"]);
+
+    // try, two handlers, and the normal path merge before `finish()`
+    assert_eq!(cfg.cyclomatic(), 3);
+    assert!(cfg.unreachable().is_empty());
+}
@@ -0,0 +1,142 @@
+use ntree::SourceCode;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+/// Analyze a source snippet and return the cognitive complexity of each
+/// function, in source order.
+fn cognitive_scores(suffix: &str, source: &str) -> Vec<(String, u32)> {
+    let mut temp_file = NamedTempFile::with_suffix(suffix).expect("Failed to create temp file");
+    temp_file
+        .write_all(source.as_bytes())
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_complexity_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    result
+        .complexity()
+        .all()
+        .iter()
+        .map(|result| (result.function.clone(), result.cognitive))
+        .collect()
+}
+
+#[test]
+fn test_rust_nesting_and_boolean_sequences() {
+    let source = r#"
+fn classify(items: &[i32], limit: i32) -> i32 {
+    let mut total = 0;
+    'outer: for item in items {
+        if *item > limit && limit > 0 || *item < 0 {
+            break 'outer;
+        } else if *item == 0 {
+            continue;
+        } else {
+            total += match item {
+                1 => 1,
+                _ => 2,
+            };
+        }
+    }
+    total
+}
+
+fn flat(a: bool, b: bool, c: bool) -> bool {
+    a && b && c
+}
+"#;
+
+    // for +1, if +2 (nested), && then || +2, labeled break +1, else if +1,
+    // else +1, match +3 (nested twice)
+    assert_eq!(
+        cognitive_scores(".rs", source),
+        vec![("classify".to_string(), 11), ("flat".to_string(), 1)]
+    );
+}
+
+#[test]
+fn test_recursion_and_jumps() {
+    let python = "def fact(n):\n    if n <= 1:\n        return 1\n    return n * fact(n - 1)\n";
+    assert_eq!(
+        cognitive_scores(".py", python),
+        vec![("fact".to_string(), 2)]
+    );
+
+    let java = r#"
+class Search {
+    int find(int[] xs, int target) {
+        for (int i = 0; i < xs.length; i++) {
+            try {
+                if (xs[i] == target) {
+                    return i;
+                }
+            } catch (Exception e) {
+                return -1;
+            }
+        }
+        return xs.length > 0 ? this.find(xs, target - 1) : -1;
+    }
+}
+"#;
+    // for +1, nested if +2, nested catch +2, ternary +1, recursion +1
+    assert_eq!(
+        cognitive_scores(".java", java),
+        vec![("find".to_string(), 7)]
+    );
+
+    let c = r#"
+int scan(int x) {
+    while (x > 0) {
+        switch (x % 3) {
+        case 0:
+            goto done;
+        default:
+            x--;
+        }
+    }
+done:
+    return x;
+}
+"#;
+    // while +1, nested switch +2, goto +1
+    assert_eq!(cognitive_scores(".c", c), vec![("scan".to_string(), 4)]);
+}
+
+#[test]
+fn test_nested_functions_and_filter() {
+    let mut temp_file = NamedTempFile::with_suffix(".js").expect("Failed to create temp file");
+    temp_file
+        .write_all(
+            b"function run(items, ok) {\n  items.forEach((item) => {\n    if (item && ok) {\n      log(item);\n    }\n  });\n}\n\nfunction noop() {\n  return 1;\n}\n",
+        )
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_complexity_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    // The callback nests its `if` inside `run`, but not inside itself
+    let scores: Vec<(&str, u32)> = result
+        .complexity()
+        .all()
+        .iter()
+        .map(|result| (result.function.as_str(), result.cognitive))
+        .collect();
+    assert_eq!(scores, vec![("run", 3), ("<lambda>", 2), ("noop", 0)]);
+
+    let complex: Vec<&str> = result
+        .complexity()
+        .filter_by_cognitive(3)
+        .iter()
+        .map(|result| result.function.as_str())
+        .collect();
+    assert_eq!(complex, vec!["run"]);
+    assert_eq!(result.complexity().filter_by_cognitive(0).len(), 3);
+}
@@ -0,0 +1,144 @@
+//! CFG helpers shared by the CFG integration tests.
+#![allow(dead_code)]
+
+use ntree::api::generate_cfgs;
+use serde_json::Value;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+/// Nodes `(id, label)` and edges `(from, to, kind)` of a function's CFG.
+pub struct Cfg {
+    pub nodes: Vec<(u64, String)>,
+    pub edges: Vec<(u64, u64, String)>,
+}
+
+impl Cfg {
+    /// Parse a CFG from its JSONL serialization.
+    pub fn from_jsonl(jsonl: &str) -> Self {
+        let mut cfg = Cfg {
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        };
+        for line in jsonl.lines() {
+            let value: Value = serde_json::from_str(line).unwrap();
+            if let Some(edge) = value.get("cfg_edge") {
+                cfg.edges.push((
+                    edge["from"].as_u64().unwrap(),
+                    edge["to"].as_u64().unwrap(),
+                    edge["kind"].as_str().unwrap().to_string(),
+                ));
+            } else {
+                cfg.nodes.push((
+                    value["cfg_node"].as_u64().unwrap(),
+                    value["label"].as_str().unwrap().to_string(),
+                ));
+            }
+        }
+        cfg
+    }
+
+    pub fn node_id(&self, label: &str) -> u64 {
+        self.nodes
+            .iter()
+            .find(|(_, node_label)| node_label == label)
+            .unwrap_or_else(|| panic!("missing node {}", label))
+            .0
+    }
+
+    /// Kinds of the edges from the node labelled `from` to the node labelled `to`.
+    pub fn edge_kinds(&self, from: &str, to: &str) -> Vec<&str> {
+        let (from, to) = (self.node_id(from), self.node_id(to));
+        self.edges
+            .iter()
+            .filter(|(edge_from, edge_to, _)| *edge_from == from && *edge_to == to)
+            .map(|(_, _, kind)| kind.as_str())
+            .collect()
+    }
+
+    pub fn cyclomatic(&self) -> usize {
+        self.edges.len() + 2 - self.nodes.len()
+    }
+
+    /// Kinds of the edges leaving the node labelled `from`.
+    pub fn out_kinds(&self, from: &str) -> Vec<&str> {
+        self.successors(from)
+            .into_iter()
+            .map(|(_, kind)| kind)
+            .collect()
+    }
+
+    /// `(label, edge kind)` of each successor of the node labelled `from`.
+    pub fn successors(&self, from: &str) -> Vec<(&str, &str)> {
+        let from = self.node_id(from);
+        self.edges
+            .iter()
+            .filter(|(edge_from, _, _)| *edge_from == from)
+            .map(|(_, to, kind)| {
+                let label = self
+                    .nodes
+                    .iter()
+                    .find(|(id, _)| id == to)
+                    .map(|(_, label)| label.as_str())
+                    .unwrap();
+                (label, kind.as_str())
+            })
+            .collect()
+    }
+
+    /// Nodes not reachable from ENTRY.
+    pub fn unreachable(&self) -> Vec<&str> {
+        let mut reached = vec![self.node_id("ENTRY")];
+        let mut index = 0;
+        while index < reached.len() {
+            for (from, to, _) in &self.edges {
+                if *from == reached[index] && !reached.contains(to) {
+                    reached.push(*to);
+                }
+            }
+            index += 1;
+        }
+        self.nodes
+            .iter()
+            .filter(|(id, _)| !reached.contains(id))
+            .map(|(_, label)| label.as_str())
+            .collect()
+    }
+
+    /// Labels of the nodes with a `suspend` edge, in node order.
+    pub fn suspension_points(&self) -> Vec<&str> {
+        self.nodes
+            .iter()
+            .filter(|(id, _)| {
+                self.edges
+                    .iter()
+                    .any(|(from, _, kind)| from == id && kind == "suspend")
+            })
+            .map(|(_, label)| label.as_str())
+            .collect()
+    }
+}
+
+/// Write `code` to a temporary file with the given suffix.
+pub fn write_source(code: &str, suffix: &str) -> NamedTempFile {
+    let mut temp_file = NamedTempFile::with_suffix(suffix).expect("Failed to create temp file");
+    temp_file
+        .write_all(code.as_bytes())
+        .expect("Failed to write to temp file");
+    temp_file
+}
+
+/// Build the CFG of each function in `code`.
+pub fn function_cfgs(code: &str, suffix: &str) -> Vec<Cfg> {
+    let temp_file = write_source(code, suffix);
+    let cfgs = generate_cfgs(temp_file.path()).expect("Failed to generate CFGs");
+    cfgs.iter()
+        .map(|result| Cfg::from_jsonl(&result.jsonl))
+        .collect()
+}
+
+/// Build the CFG of the single function in `code`.
+pub fn function_cfg(code: &str, suffix: &str) -> Cfg {
+    let mut cfgs = function_cfgs(code, suffix);
+    assert_eq!(cfgs.len(), 1);
+    cfgs.remove(0)
+}
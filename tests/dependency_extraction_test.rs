@@ -0,0 +1,340 @@
+use ntree::{EdgeKind, ImportType, ModuleId, ModuleType, SourceCode};
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Write `(relative path, content)` pairs into a fresh temp workspace.
+fn write_workspace(files: &[(&str, &str)]) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    for (relative_path, content) in files {
+        let path = dir.path().join(relative_path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    dir
+}
+
+fn analyze(workspace: &Path) -> ntree::AnalysisResult {
+    SourceCode::new(workspace).unwrap().analyze().unwrap()
+}
+
+#[test]
+fn test_java_imports_and_exports() {
+    let dir = write_workspace(&[(
+        "com/acme/app/App.java",
+        r#"
+package com.acme.app;
+
+import java.util.List;
+import java.util.*;
+import java.util.Map.Entry;
+import static java.lang.Math.max;
+import static org.junit.Assert.*;
+
+public class App {}
+class Helper {}
+"#,
+    )]);
+
+    let dataset = analyze(dir.path()).export_dataset().unwrap();
+    let imports = &dataset.import_edges;
+    assert_eq!(imports.len(), 5);
+    assert!(imports
+        .iter()
+        .all(|i| i.source_module.as_deref() == Some("com.acme.app")));
+
+    let list = &imports[0];
+    assert_eq!(list.target_module, "java.util");
+    assert_eq!(list.imported_symbol.as_deref(), Some("List"));
+    assert_eq!(list.import_type, ImportType::Symbol);
+
+    assert_eq!(imports[1].import_type, ImportType::Wildcard);
+    assert_eq!(imports[1].imported_symbol, None);
+    assert_eq!(imports[2].imported_symbol.as_deref(), Some("Map.Entry"));
+    assert_eq!(imports[3].target_module, "java.lang");
+    assert_eq!(imports[3].imported_symbol.as_deref(), Some("Math.max"));
+    assert_eq!(imports[4].target_module, "org.junit");
+    assert_eq!(imports[4].import_type, ImportType::Wildcard);
+
+    // Only the public top-level type is exported
+    assert_eq!(dataset.export_edges.len(), 1);
+    assert!(dataset.export_edges[0]
+        .exported_symbol
+        .as_str()
+        .ends_with("::App"));
+}
+
+#[test]
+fn test_java_package_cycle() {
+    let dir = write_workspace(&[
+        (
+            "com/acme/a/A.java",
+            "package com.acme.a;\nimport com.acme.b.B;\npublic class A {}\n",
+        ),
+        (
+            "com/acme/b/B.java",
+            "package com.acme.b;\nimport com.acme.a.*;\npublic class B {}\n",
+        ),
+        (
+            "com/acme/c/C.java",
+            "package com.acme.c;\nimport com.acme.a.A;\npublic class C {}\n",
+        ),
+    ]);
+
+    let analysis = analyze(dir.path());
+    let graph = analysis.dependencies().unwrap();
+    let report = graph.analyze_dependencies();
+
+    assert_eq!(report.total_modules, 3);
+    assert_eq!(report.total_edges, 3);
+    assert!(report.has_cycles);
+    let cycle: Vec<&str> = report.cycle_details[0].iter().map(|m| m.as_str()).collect();
+    assert!(cycle.contains(&"java:com.acme.a"));
+    assert!(cycle.contains(&"java:com.acme.b"));
+    assert!(graph.topological_sort().is_none());
+}
+
+#[test]
+fn test_java_package_topological_order() {
+    let dir = write_workspace(&[
+        (
+            "com/acme/a/A.java",
+            "package com.acme.a;\nimport com.acme.b.B;\npublic class A {}\n",
+        ),
+        (
+            "com/acme/b/B.java",
+            "package com.acme.b;\nimport java.util.List;\npublic class B {}\n",
+        ),
+    ]);
+
+    let graph = analyze(dir.path()).dependencies().unwrap();
+    let order: Vec<String> = graph
+        .topological_sort()
+        .unwrap()
+        .iter()
+        .map(|m| m.as_str().to_string())
+        .collect();
+
+    let position = |id: &str| order.iter().position(|m| m == id).unwrap();
+    assert!(position("java:java.util") < position("java:com.acme.b"));
+    assert!(position("java:com.acme.b") < position("java:com.acme.a"));
+}
+
+#[test]
+fn test_javascript_imports_and_exports() {
+    let dir = write_workspace(&[(
+        "app.js",
+        r#"
+import def, { a, b as c } from './lib';
+import * as ns from '../pkg/index.js';
+import './polyfill';
+export * from './all';
+export { x as y } from './x';
+export { a as aa };
+export default function main() {}
+export const k = 1, j = 2;
+const m = require('./cjs');
+const { p, q: r } = require('lodash');
+module.exports.v = k;
+async function lazy() { await import('./lazy'); }
+"#,
+    )]);
+
+    let dataset = analyze(dir.path()).export_dataset().unwrap();
+    let imports = &dataset.import_edges;
+
+    let find = |alias: &str| {
+        imports
+            .iter()
+            .find(|i| i.alias.as_deref() == Some(alias))
+            .unwrap_or_else(|| panic!("missing import bound to {}", alias))
+    };
+    assert_eq!(find("def").imported_symbol.as_deref(), Some("default"));
+    assert_eq!(find("c").imported_symbol.as_deref(), Some("b"));
+    assert_eq!(find("ns").import_type, ImportType::Module);
+    assert_eq!(find("m").target_module, "./cjs");
+    assert_eq!(find("r").imported_symbol.as_deref(), Some("q"));
+
+    let targets: Vec<&str> = imports.iter().map(|i| i.target_module.as_str()).collect();
+    for expected in ["./polyfill", "./all", "./x", "lodash"] {
+        assert!(
+            targets.contains(&expected),
+            "missing import of {}",
+            expected
+        );
+    }
+    let dynamic: Vec<_> = imports
+        .iter()
+        .filter(|i| i.import_type == ImportType::Dynamic)
+        .collect();
+    assert_eq!(dynamic.len(), 1);
+    assert_eq!(dynamic[0].target_module, "./lazy");
+
+    let exported: Vec<String> = dataset
+        .export_edges
+        .iter()
+        .map(|e| e.exported_name())
+        .collect();
+    for expected in ["*", "y", "aa", "default", "k", "j", "v"] {
+        assert!(
+            exported.contains(&expected.to_string()),
+            "missing export {}",
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_typescript_cross_file_resolution() {
+    let dir = write_workspace(&[
+        (
+            "src/models/user.ts",
+            r#"
+export interface User { id: number }
+export function loadUser(id: number): User { return { id }; }
+export default class UserStore {}
+"#,
+        ),
+        (
+            "src/app.ts",
+            r#"
+import type { User } from './models/user';
+import Store, { loadUser as load } from './models/user';
+const legacy = require('./legacy');
+"#,
+        ),
+        (
+            "src/legacy.js",
+            "function helper() {}\nmodule.exports = helper;\n",
+        ),
+    ]);
+
+    let analysis = analyze(dir.path());
+    let resolver = analysis.name_bindings().unwrap();
+    let app = dir.path().join("src/app.ts");
+
+    let binding = resolver.resolve_name(&app, "load", "1:1".to_string());
+    assert!(binding.is_confident());
+    assert!(binding
+        .resolved_sym_id
+        .unwrap()
+        .as_str()
+        .ends_with("user.ts::loadUser"));
+
+    let binding = resolver.resolve_name(&app, "User", "1:1".to_string());
+    assert!(binding
+        .resolved_sym_id
+        .unwrap()
+        .as_str()
+        .ends_with("user.ts::User"));
+
+    let binding = resolver.resolve_name(&app, "Store", "1:1".to_string());
+    assert!(binding
+        .resolved_sym_id
+        .unwrap()
+        .as_str()
+        .ends_with("user.ts::UserStore"));
+
+    let binding = resolver.resolve_name(&app, "legacy", "1:1".to_string());
+    assert!(binding
+        .resolved_sym_id
+        .unwrap()
+        .as_str()
+        .ends_with("legacy.js::helper"));
+}
+
+#[test]
+fn test_c_system_and_local_includes() {
+    let dir = write_workspace(&[
+        (
+            "src/main.c",
+            r#"
+#include <stdio.h>
+#include "util.h"
+#include "missing.h"
+#ifdef DEBUG
+#include "debug/trace.h"
+#endif
+#include CONFIG_HEADER
+"#,
+        ),
+        ("src/util.h", "int util(void);\n"),
+        ("src/debug/trace.h", "void trace(void);\n"),
+    ]);
+
+    let analysis = analyze(dir.path());
+    let dataset = analysis.export_dataset().unwrap();
+    let targets: Vec<&str> = dataset
+        .import_edges
+        .iter()
+        .map(|i| i.target_module.as_str())
+        .collect();
+    let util = dir.path().join("src/util.h");
+    let trace = dir.path().join("src/debug/trace.h");
+    assert_eq!(
+        targets,
+        vec![
+            "<stdio.h>",
+            util.to_str().unwrap(),
+            "\"missing.h\"",
+            trace.to_str().unwrap(),
+        ]
+    );
+    assert!(dataset
+        .import_edges
+        .iter()
+        .all(|i| i.import_type == ImportType::Module));
+
+    let graph = analysis.dependencies().unwrap();
+    let system = graph
+        .get_module(&ModuleId::new("c:system:stdio.h".to_string()))
+        .unwrap();
+    assert_eq!(system.module_type, ModuleType::System);
+    assert!(graph
+        .get_module(&ModuleId::new("c:local:missing.h".to_string()))
+        .is_some());
+}
+
+#[test]
+fn test_cpp_header_cycle_through_include_dir() {
+    let dir = write_workspace(&[
+        (
+            "include/geo/shape.hpp",
+            "#pragma once\n#include \"geo/canvas.hpp\"\nclass Shape {};\n",
+        ),
+        (
+            "include/geo/canvas.hpp",
+            "#pragma once\n#include \"shape.hpp\"\nclass Canvas {};\n",
+        ),
+        (
+            "src/main.cpp",
+            "#include \"geo/shape.hpp\"\nint main() {}\n",
+        ),
+    ]);
+
+    // Without the include directory `geo/canvas.hpp` cannot be found
+    let graph = analyze(dir.path()).dependencies().unwrap();
+    assert!(graph.detect_cycles().is_empty());
+
+    let graph = SourceCode::new(dir.path())
+        .unwrap()
+        .with_include_dir(dir.path().join("include"))
+        .analyze()
+        .unwrap()
+        .dependencies()
+        .unwrap();
+    let cycles = graph.detect_cycles();
+    assert_eq!(cycles.len(), 1);
+    let shape = format!(
+        "c:local:{}",
+        dir.path().join("include/geo/shape.hpp").display()
+    );
+    let canvas = format!(
+        "c:local:{}",
+        dir.path().join("include/geo/canvas.hpp").display()
+    );
+    let cycle: Vec<&str> = cycles[0].iter().map(|m| m.as_str()).collect();
+    assert!(cycle.contains(&shape.as_str()));
+    assert!(cycle.contains(&canvas.as_str()));
+    assert!(graph.edges().iter().all(|e| e.kind == EdgeKind::Include));
+}
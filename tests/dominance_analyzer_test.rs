@@ -0,0 +1,216 @@
+use ntree::analyzers::DominanceAnalyzer;
+use ntree::{CFGEdgeIR, CFGNodeIR, FunctionCFGIR, SourceCode};
+use serde_json::Value;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+fn create_test_node(func: &str, id: &str, label: &str) -> CFGNodeIR {
+    CFGNodeIR::new(
+        func.to_string(),
+        id.to_string(),
+        label.to_string(),
+        "test_span".to_string(),
+    )
+}
+
+fn create_test_edge(func: &str, from: &str, to: &str, kind: &str) -> CFGEdgeIR {
+    CFGEdgeIR::new(
+        func.to_string(),
+        from.to_string(),
+        to.to_string(),
+        kind.to_string(),
+    )
+}
+
+/// Build a CFG IR from `(id, label)` nodes and `(from, to, kind)` edges.
+fn build_cfg(func: &str, nodes: &[(&str, &str)], edges: &[(&str, &str, &str)]) -> FunctionCFGIR {
+    let mut cfg = FunctionCFGIR::new(func.to_string(), None);
+    for (id, label) in nodes {
+        cfg.add_node(create_test_node(func, id, label));
+    }
+    for (from, to, kind) in edges {
+        cfg.add_edge(create_test_edge(func, from, to, kind));
+    }
+    cfg
+}
+
+#[test]
+fn test_if_else_dominance() {
+    // N1 tests a condition, N2 and N3 are its branches and N4 joins them
+    let cfg = build_cfg(
+        "diamond",
+        &[
+            ("N0", "ENTRY"),
+            ("N1", "if (x)"),
+            ("N2", "a();"),
+            ("N3", "b();"),
+            ("N4", "join"),
+            ("N9999", "EXIT"),
+        ],
+        &[
+            ("N0", "N1", "next"),
+            ("N1", "N2", "true"),
+            ("N1", "N3", "false"),
+            ("N2", "N4", "next"),
+            ("N3", "N4", "next"),
+            ("N4", "N9999", "next"),
+        ],
+    );
+
+    let result = DominanceAnalyzer::new()
+        .analyze(&cfg)
+        .expect("Analysis should succeed");
+
+    assert_eq!(result.immediate_dominator("N0"), None);
+    assert_eq!(result.immediate_dominator("N4"), Some("N1"));
+    assert!(result.dominates("N1", "N2"));
+    assert!(!result.dominates("N2", "N4"));
+    assert_eq!(result.dominance_frontier("N2"), ["N4".to_string()]);
+    assert!(result.dominance_frontier("N4").is_empty());
+
+    assert_eq!(result.immediate_post_dominator("N1"), Some("N4"));
+    assert!(result.post_dominates("N9999", "N0"));
+
+    let guards: Vec<(&str, &str)> = result
+        .controlling_branches("N3")
+        .iter()
+        .map(|dependence| (dependence.from.as_str(), dependence.kind.as_str()))
+        .collect();
+    assert_eq!(guards, vec![("N1", "false")]);
+    assert!(result.controlling_branches("N4").is_empty());
+    assert_eq!(result.dependents("N1").len(), 2);
+}
+
+#[test]
+fn test_loop_dominance() {
+    // N1 is a loop header whose body N2 jumps back to it
+    let cfg = build_cfg(
+        "loop",
+        &[
+            ("N0", "ENTRY"),
+            ("N1", "while (x)"),
+            ("N2", "x = f(x);"),
+            ("N3", "after_while_loop"),
+            ("N4", "dead();"),
+            ("N9999", "EXIT"),
+        ],
+        &[
+            ("N0", "N1", "next"),
+            ("N1", "N2", "true"),
+            ("N2", "N1", "back"),
+            ("N1", "N3", "false"),
+            ("N3", "N9999", "next"),
+        ],
+    );
+
+    let result = DominanceAnalyzer::new()
+        .analyze(&cfg)
+        .expect("Analysis should succeed");
+
+    // The header is in its own frontier, where the back edge rejoins it
+    assert_eq!(result.dominance_frontier("N1"), ["N1".to_string()]);
+    assert_eq!(result.dominance_frontier("N2"), ["N1".to_string()]);
+
+    // Unreachable code has no dominator
+    assert_eq!(result.immediate_dominator("N4"), None);
+    assert!(!result.dominates("N0", "N4"));
+
+    // Each iteration decides whether the header runs again
+    let header_guards: Vec<&str> = result
+        .controlling_branches("N1")
+        .iter()
+        .map(|dependence| dependence.kind.as_str())
+        .collect();
+    assert_eq!(header_guards, vec!["true"]);
+    assert_eq!(result.controlling_branches("N2").len(), 1);
+    assert!(result.controlling_branches("N3").is_empty());
+}
+
+#[test]
+fn test_dominance_from_analysis_result() {
+    let mut temp_file = NamedTempFile::with_suffix(".py").expect("Failed to create temp file");
+    temp_file
+        .write_all(b"def clamp(x):\n    if x > 10:\n        x = 10\n    print(x)\n    return x\n")
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_dominance_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    // IR node IDs are the CFG node IDs prefixed with N
+    let cfg = result.cfgs().for_function("clamp").expect("CFG for clamp");
+    let node = |label: &str| {
+        cfg.jsonl
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .find(|value| value["label"] == label)
+            .map(|value| format!("N{}", value["cfg_node"]))
+            .unwrap_or_else(|| panic!("missing node {}", label))
+    };
+
+    let dominance = result
+        .dominance()
+        .for_function("clamp")
+        .expect("Dominance for clamp");
+    let guards = dominance.controlling_branches(&node("x = 10;"));
+    assert_eq!(guards.len(), 1);
+    assert_eq!(guards[0].from, node("if (x > 10)"));
+    assert_eq!(guards[0].kind, "true");
+    assert!(dominance
+        .controlling_branches(&node("print(x);"))
+        .is_empty());
+
+    let jsonl = result.dominance().to_jsonl();
+    for line in jsonl.lines() {
+        let value: Value = serde_json::from_str(line).expect("Valid JSON line");
+        assert_eq!(value["func"], "clamp");
+    }
+    assert!(jsonl.contains("\"type\":\"Dominator\""));
+    assert!(jsonl.contains("\"type\":\"PostDominator\""));
+    assert!(jsonl.contains("\"type\":\"ControlDependence\""));
+
+    let minimal = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .analyze()
+        .expect("Analysis should succeed");
+    assert!(minimal.dominance().is_empty());
+}
+
+#[test]
+fn test_same_named_methods_keep_their_own_dominance() {
+    let mut temp_file = NamedTempFile::with_suffix(".py").expect("Failed to create temp file");
+    temp_file
+        .write_all(
+            b"class Low:\n    def clamp(self, x):\n        return x\n\n\
+              class High:\n    def clamp(self, x):\n        if x > 10:\n            x = 10\n        return x\n",
+        )
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_dominance_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    // Methods are looked up by qualified name
+    let low = result
+        .dominance()
+        .for_function("Low::clamp")
+        .expect("Dominance for Low::clamp");
+    let high = result
+        .dominance()
+        .for_function("High::clamp")
+        .expect("Dominance for High::clamp");
+    assert_eq!(
+        (low.function.as_str(), high.function.as_str()),
+        ("clamp", "clamp")
+    );
+    assert!(low.control_dependences.is_empty());
+    assert!(!high.control_dependences.is_empty());
+    assert!(result.dominance().for_function("clamp").is_none());
+}
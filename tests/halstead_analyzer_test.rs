@@ -0,0 +1,223 @@
+use ntree::analyzers::HalsteadAnalyzer;
+use ntree::{FunctionSpan, SourceCode};
+use serde_json::Value;
+use std::fs;
+use std::io::Write;
+use tempfile::{NamedTempFile, TempDir};
+
+const PYTHON_SOURCE: &[u8] = b"def add(a, b):\n    # sum\n    return a + b\n\nclass Box:\n    def add(self, x):\n        if x:\n            return \"s\"\n        return x\n";
+
+#[test]
+fn test_python_token_counts() {
+    let mut temp_file = NamedTempFile::with_suffix(".py").expect("Failed to create temp file");
+    temp_file
+        .write_all(PYTHON_SOURCE)
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_halstead_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    let all = result.halstead().all();
+    assert_eq!(all.len(), 2);
+
+    // Operators: def ( , : return + with `)` paired with `(`; operands:
+    // add a b a b. The comment counts as neither.
+    let add = &all[0];
+    assert_eq!(add.function, "add");
+    assert_eq!((add.distinct_operators, add.total_operators), (6, 6));
+    assert_eq!((add.distinct_operands, add.total_operands), (3, 5));
+    assert_eq!(add.vocabulary, 9);
+    assert_eq!(add.length, 11);
+    assert!((add.volume - 11.0 * 9f64.log2()).abs() < 1e-9);
+    assert!((add.difficulty - 5.0).abs() < 1e-9);
+    assert!((add.effort - add.difficulty * add.volume).abs() < 1e-9);
+    assert_eq!(add.loc, 3);
+    assert_eq!(add.cyclomatic, 1);
+    assert!(
+        (add.maintainability_index - HalsteadAnalyzer::maintainability_index(add.volume, 1, 3))
+            .abs()
+            < 1e-9
+    );
+
+    // The string literal is a single operand
+    let method = &all[1];
+    assert_eq!(method.cyclomatic, 2);
+    assert_eq!(method.total_operands, 6);
+    assert!(method.maintainability_index < add.maintainability_index);
+
+    let hard: Vec<&str> = result
+        .halstead()
+        .below_maintainability(add.maintainability_index)
+        .iter()
+        .map(|result| result.function.as_str())
+        .collect();
+    assert_eq!(hard, vec!["add"]);
+    assert_eq!(result.halstead().filter_by_name("add").len(), 2);
+
+    // Same-named functions are looked up by qualified name
+    let lookup = |qualname: &str| {
+        result
+            .halstead()
+            .for_function(qualname)
+            .map(|result| result.cyclomatic)
+    };
+    assert_eq!(lookup("add"), Some(1));
+    assert_eq!(lookup("Box::add"), Some(2));
+
+    let jsonl = result.halstead().to_jsonl().expect("JSONL export");
+    let first: Value = serde_json::from_str(jsonl.lines().next().unwrap()).expect("Valid JSON");
+    assert_eq!(first["function"], "add");
+    assert!(first["maintainability_index"].is_f64());
+
+    let minimal = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .analyze()
+        .expect("Analysis should succeed");
+    assert!(minimal.halstead().is_empty());
+}
+
+#[test]
+fn test_maintainability_index_scale() {
+    // A one-line function with no tokens to speak of is as maintainable as it gets
+    assert!((HalsteadAnalyzer::maintainability_index(0.0, 1, 1) - 99.865).abs() < 1e-3);
+
+    // Larger functions lose maintainability, bottoming out at 0
+    let small = HalsteadAnalyzer::maintainability_index(100.0, 2, 10);
+    let large = HalsteadAnalyzer::maintainability_index(5000.0, 20, 200);
+    assert!(small > large);
+    assert_eq!(
+        HalsteadAnalyzer::maintainability_index(1e12, 500, 100_000),
+        0.0
+    );
+}
+
+#[test]
+fn test_function_facts() {
+    let mut temp_file = NamedTempFile::with_suffix(".py").expect("Failed to create temp file");
+    temp_file
+        .write_all(PYTHON_SOURCE)
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_halstead_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    let store = result.symbol_store();
+    let facts: Vec<(String, u32, u32, u32)> = store
+        .get_all_symbols()
+        .filter_map(|symbol| store.get_function_facts(&symbol.id))
+        .map(|facts| {
+            let halstead = facts.halstead.as_ref().expect("Halstead metrics");
+            (
+                facts
+                    .sym_id
+                    .as_str()
+                    .rsplit("::")
+                    .next()
+                    .unwrap()
+                    .to_string(),
+                facts.loc,
+                facts.complexity,
+                halstead.total_operands,
+            )
+        })
+        .collect();
+
+    // Same-named functions each keep their own metrics
+    assert_eq!(facts.len(), 2);
+    assert!(facts.contains(&("add".to_string(), 3, 1, 5)));
+    assert!(facts
+        .iter()
+        .any(|fact| fact.1 == 4 && fact.2 == 2 && fact.3 == 6));
+
+    let dataset = result.export_dataset().expect("Dataset export");
+    assert_eq!(dataset.function_facts.len(), 2);
+
+    let span = FunctionSpan::new("f".to_string(), "3:1–12:2".to_string(), None);
+    assert_eq!(span.line_count(), 10);
+    let span = FunctionSpan::new("f".to_string(), "unknown".to_string(), None);
+    assert_eq!(span.line_count(), 0);
+}
+
+#[test]
+fn test_workspace_function_facts() {
+    let dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(dir.path().join("shapes.py"), PYTHON_SOURCE).expect("Failed to write file");
+    fs::write(
+        dir.path().join("util.py"),
+        "def double(x):\n    return x * 2\n",
+    )
+    .expect("Failed to write file");
+
+    let result = SourceCode::new(dir.path())
+        .expect("Valid workspace")
+        .minimal()
+        .with_complexity_analysis(true)
+        .with_halstead_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    // Every file's functions get facts with Halstead metrics
+    let store = result.symbol_store();
+    let facts: Vec<_> = store
+        .get_all_symbols()
+        .filter_map(|symbol| store.get_function_facts(&symbol.id))
+        .collect();
+    assert_eq!(facts.len(), 3);
+    assert!(facts.iter().all(|facts| facts.halstead.is_some()));
+    assert!(facts
+        .iter()
+        .any(|facts| facts.sym_id.as_str().ends_with("double") && facts.loc == 2));
+}
+
+/// Analyze `code` with the given file suffix and return its first function's
+/// `(distinct, total)` operators and operands.
+fn token_counts(code: &str, suffix: &str) -> ((u32, u32), (u32, u32)) {
+    let mut temp_file = NamedTempFile::with_suffix(suffix).expect("Failed to create temp file");
+    temp_file
+        .write_all(code.as_bytes())
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_halstead_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+    let function = &result.halstead().all()[0];
+    (
+        (function.distinct_operators, function.total_operators),
+        (function.distinct_operands, function.total_operands),
+    )
+}
+
+#[test]
+fn test_interpolated_strings() {
+    // Operators: def ( : return, the f-string and `{`; operands: greet name,
+    // the text fragments "hi " and "!" and the interpolated name
+    let python = "def greet(name):\n    return f\"hi {name}!\"\n";
+    assert_eq!(token_counts(python, ".py"), ((6, 6), (4, 5)));
+
+    // Operators: function ( { return, the template, `${` and ;
+    let javascript = "function greet(name) {\n    return `hi ${name}!`;\n}\n";
+    assert_eq!(token_counts(javascript, ".js"), ((7, 7), (4, 5)));
+
+    // A string without interpolation stays a single operand
+    let plain = "def greet(name):\n    return \"hi {name}!\"\n";
+    assert_eq!(token_counts(plain, ".py"), ((4, 4), (3, 3)));
+}
+
+#[test]
+fn test_keyword_leaves_are_operators() {
+    // `mut` and `i32` are operators; operands: bump x y x y 1 y
+    let rust = "fn bump(x: i32) -> i32 {\n    let mut y = x;\n    y += 1;\n    y\n}\n";
+    assert_eq!(token_counts(rust, ".rs"), ((11, 13), (4, 7)));
+}
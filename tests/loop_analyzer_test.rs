@@ -0,0 +1,227 @@
+use ntree::analyzers::{ComplexityAnalyzer, LoopAnalyzer};
+use ntree::{CFGEdgeIR, CFGNodeIR, FunctionCFGIR, SourceCode};
+use serde_json::Value;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+/// Build a CFG IR from `(id, label)` nodes and `(from, to, kind)` edges.
+fn build_cfg(func: &str, nodes: &[(&str, &str)], edges: &[(&str, &str, &str)]) -> FunctionCFGIR {
+    let mut cfg = FunctionCFGIR::new(func.to_string(), None);
+    for (id, label) in nodes {
+        cfg.add_node(CFGNodeIR::new(
+            func.to_string(),
+            id.to_string(),
+            label.to_string(),
+            "test_span".to_string(),
+        ));
+    }
+    for (from, to, kind) in edges {
+        cfg.add_edge(CFGEdgeIR::new(
+            func.to_string(),
+            from.to_string(),
+            to.to_string(),
+            kind.to_string(),
+        ));
+    }
+    cfg
+}
+
+#[test]
+fn test_nested_loops() {
+    // N1 loops over N2..N4, and N2 loops over N3 inside it
+    let cfg = build_cfg(
+        "nested",
+        &[
+            ("N0", "ENTRY"),
+            ("N1", "while (i < n)"),
+            ("N2", "while (j < m)"),
+            ("N3", "j++;"),
+            ("N4", "i++;"),
+            ("N5", "after_while_loop"),
+            ("N9999", "EXIT"),
+        ],
+        &[
+            ("N0", "N1", "next"),
+            ("N1", "N2", "true"),
+            ("N2", "N3", "true"),
+            ("N3", "N2", "back"),
+            ("N2", "N4", "false"),
+            ("N4", "N1", "back"),
+            ("N1", "N5", "false"),
+            ("N5", "N9999", "next"),
+        ],
+    );
+
+    let forest = LoopAnalyzer::new()
+        .analyze(&cfg)
+        .expect("Analysis should succeed");
+
+    assert_eq!(forest.loops.len(), 2);
+    assert_eq!(forest.max_depth(), 2);
+    assert!(forest.is_reducible());
+
+    let outer = forest.loop_at("N1").expect("Outer loop");
+    assert_eq!(outer.body, ["N1", "N2", "N3", "N4"]);
+    assert_eq!(outer.latches, ["N4"]);
+    assert_eq!(outer.depth, 1);
+    assert_eq!(outer.parent, None);
+    let exits: Vec<(&str, &str)> = outer
+        .exits
+        .iter()
+        .map(|exit| (exit.to.as_str(), exit.kind.as_str()))
+        .collect();
+    assert_eq!(exits, vec![("N5", "false")]);
+
+    let inner = forest.loop_at("N2").expect("Inner loop");
+    assert_eq!(inner.body, ["N2", "N3"]);
+    assert_eq!(inner.depth, 2);
+    assert_eq!(inner.parent.as_deref(), Some("N1"));
+    assert_eq!(inner.exits.len(), 1);
+    assert_eq!(inner.exits[0].to, "N4");
+
+    assert_eq!(forest.roots().len(), 1);
+    assert_eq!(forest.children("N1")[0].header, "N2");
+    assert_eq!(
+        forest.innermost_loop("N3").map(|l| l.header.as_str()),
+        Some("N2")
+    );
+    assert_eq!(
+        forest.innermost_loop("N4").map(|l| l.header.as_str()),
+        Some("N1")
+    );
+    assert!(forest.innermost_loop("N5").is_none());
+
+    let complexity = ComplexityAnalyzer::new()
+        .analyze(&cfg)
+        .expect("Analysis should succeed");
+    assert_eq!(complexity.max_loop_nesting, 2);
+    assert!(!complexity.irreducible);
+}
+
+#[test]
+fn test_irreducible_loop() {
+    // N2 and N3 form a cycle that N1 enters at either node
+    let cfg = build_cfg(
+        "irreducible",
+        &[
+            ("N0", "ENTRY"),
+            ("N1", "if (x)"),
+            ("N2", "a();"),
+            ("N3", "b();"),
+            ("N9999", "EXIT"),
+        ],
+        &[
+            ("N0", "N1", "next"),
+            ("N1", "N2", "true"),
+            ("N1", "N3", "false"),
+            ("N2", "N3", "next"),
+            ("N3", "N2", "goto"),
+            ("N3", "N9999", "exit"),
+        ],
+    );
+
+    let forest = LoopAnalyzer::new()
+        .analyze(&cfg)
+        .expect("Analysis should succeed");
+
+    assert_eq!(forest.loops.len(), 1);
+    assert!(!forest.is_reducible());
+    let cycle = &forest.loops[0];
+    assert_eq!(cycle.body, ["N2", "N3"]);
+    assert!(!cycle.reducible);
+
+    let complexity = ComplexityAnalyzer::new()
+        .analyze(&cfg)
+        .expect("Analysis should succeed");
+    assert!(complexity.irreducible);
+}
+
+#[test]
+fn test_goto_into_c_loop() {
+    let mut temp_file = NamedTempFile::with_suffix(".c").expect("Failed to create temp file");
+    temp_file
+        .write_all(
+            b"int jump(int x) {\n    if (x) goto inside;\n    while (x < 10) {\n        x++;\n    inside:\n        x += 2;\n    }\n    return x;\n}\n\nint count(int n) {\n    int total = 0;\n    for (int i = 0; i < n; i++) {\n        total += i;\n    }\n    return total;\n}\n",
+        )
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_loop_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    let irreducible: Vec<&str> = result
+        .loops()
+        .with_irreducible_flow()
+        .iter()
+        .map(|forest| forest.function.as_str())
+        .collect();
+    assert_eq!(irreducible, vec!["jump"]);
+
+    let count = result
+        .loops()
+        .for_function("count")
+        .expect("Loops for count");
+    assert_eq!(count.loops.len(), 1);
+    assert!(count.loops[0].reducible);
+    assert_eq!(count.max_depth(), 1);
+
+    let jsonl = result.loops().to_jsonl();
+    assert_eq!(jsonl.lines().count(), 2);
+    for line in jsonl.lines() {
+        let value: Value = serde_json::from_str(line).expect("Valid JSON line");
+        assert_eq!(value["type"], "Loop");
+    }
+
+    let complexity = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_complexity_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+    let flagged: Vec<&str> = complexity
+        .complexity()
+        .all()
+        .iter()
+        .filter(|result| result.irreducible)
+        .map(|result| result.function.as_str())
+        .collect();
+    assert_eq!(flagged, vec!["jump"]);
+    assert!(complexity.loops().is_empty());
+}
+
+#[test]
+fn test_same_named_methods_keep_their_own_loops() {
+    let mut temp_file = NamedTempFile::with_suffix(".java").expect("Failed to create temp file");
+    temp_file
+        .write_all(
+            b"class Once {\n    int run(int n) {\n        return n;\n    }\n}\n\n\
+              class Many {\n    int run(int n) {\n        while (n > 0) {\n            n--;\n        }\n        return n;\n    }\n}\n",
+        )
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_loop_analysis(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    // Methods are looked up by qualified name
+    let once = result
+        .loops()
+        .for_function("Once::run")
+        .expect("Loops for Once::run");
+    let many = result
+        .loops()
+        .for_function("Many::run")
+        .expect("Loops for Many::run");
+    assert_eq!(
+        (once.function.as_str(), many.function.as_str()),
+        ("run", "run")
+    );
+    assert!(once.loops.is_empty());
+    assert_eq!(many.loops.len(), 1);
+}
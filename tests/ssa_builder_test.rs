@@ -0,0 +1,269 @@
+use ntree::analyzers::{DefUseExtractor, SsaBuilder};
+use ntree::{CFGEdgeIR, CFGNodeIR, FunctionCFGIR, NodeDefUse, SourceCode, SsaValue};
+use serde_json::Value;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+/// Build a CFG IR from `(id, label)` nodes and `(from, to, kind)` edges.
+fn build_cfg(func: &str, nodes: &[(&str, &str)], edges: &[(&str, &str, &str)]) -> FunctionCFGIR {
+    let mut cfg = FunctionCFGIR::new(func.to_string(), None);
+    for (id, label) in nodes {
+        cfg.add_node(CFGNodeIR::new(
+            func.to_string(),
+            id.to_string(),
+            label.to_string(),
+            "test_span".to_string(),
+        ));
+    }
+    for (from, to, kind) in edges {
+        cfg.add_edge(CFGEdgeIR::new(
+            func.to_string(),
+            from.to_string(),
+            to.to_string(),
+            kind.to_string(),
+        ));
+    }
+    cfg
+}
+
+fn value(variable: &str, version: u32) -> SsaValue {
+    SsaValue::new(variable.to_string(), version)
+}
+
+fn names(values: &[SsaValue]) -> Vec<String> {
+    values.iter().map(|value| value.name()).collect()
+}
+
+#[test]
+fn test_phi_at_if_else_join() {
+    let cfg = build_cfg(
+        "sign",
+        &[
+            ("N0", "ENTRY"),
+            ("N1", "if (x < 0)"),
+            ("N2", "s = -1;"),
+            ("N3", "s = 1;"),
+            ("N4", "join"),
+            ("N5", "return s * x;"),
+            ("N9999", "EXIT"),
+        ],
+        &[
+            ("N0", "N1", "next"),
+            ("N1", "N2", "true"),
+            ("N1", "N3", "false"),
+            ("N2", "N4", "next"),
+            ("N3", "N4", "next"),
+            ("N4", "N5", "next"),
+            ("N5", "N9999", "exit"),
+        ],
+    );
+
+    let ssa = SsaBuilder::new()
+        .build(&cfg)
+        .expect("Construction should succeed");
+
+    assert_eq!(names(&ssa.node("N2").unwrap().defs), ["s_1"]);
+    assert_eq!(names(&ssa.node("N3").unwrap().defs), ["s_2"]);
+
+    let phis = ssa.phis_at("N4");
+    assert_eq!(phis.len(), 1);
+    assert_eq!(phis[0].target, value("s", 3));
+    let operands: Vec<(&str, String)> = phis[0]
+        .operands
+        .iter()
+        .map(|operand| (operand.from.as_str(), operand.value.name()))
+        .collect();
+    assert_eq!(
+        operands,
+        vec![("N2", "s_1".to_string()), ("N3", "s_2".to_string())]
+    );
+
+    // Parameters keep the version they have on entry
+    assert_eq!(names(&ssa.node("N5").unwrap().uses), ["s_3", "x_0"]);
+    assert_eq!(ssa.definition_site(&value("s", 3)), Some("N4"));
+    assert_eq!(ssa.definition_site(&value("x", 0)), None);
+    assert_eq!(ssa.use_sites(&value("s", 1)), ["N4"]);
+}
+
+#[test]
+fn test_loop_with_explicit_def_use() {
+    // N1 is a loop header, N2 increments i and N4 is unreachable
+    let cfg = build_cfg(
+        "count",
+        &[
+            ("N0", "ENTRY"),
+            ("N1", "header"),
+            ("N2", "step"),
+            ("N3", "after"),
+            ("N4", "dead"),
+            ("N9999", "EXIT"),
+        ],
+        &[
+            ("N0", "N1", "next"),
+            ("N1", "N2", "true"),
+            ("N2", "N1", "back"),
+            ("N1", "N3", "false"),
+            ("N3", "N9999", "next"),
+            ("N4", "N3", "next"),
+        ],
+    );
+    let def_use = |defs: &[&str], uses: &[&str]| NodeDefUse {
+        defs: defs.iter().map(|name| name.to_string()).collect(),
+        uses: uses.iter().map(|name| name.to_string()).collect(),
+    };
+    let nodes = [
+        def_use(&["i"], &[]),
+        def_use(&[], &["i", "n"]),
+        def_use(&["i"], &["i"]),
+        def_use(&[], &["i"]),
+        def_use(&["i"], &[]),
+        def_use(&[], &[]),
+    ];
+
+    let ssa = SsaBuilder::new()
+        .build_with_def_use(&cfg, &nodes)
+        .expect("Construction should succeed");
+
+    let header = ssa.phis_at("N1");
+    assert_eq!(header.len(), 1);
+    assert_eq!(header[0].target, value("i", 2));
+    let operands: Vec<String> = header[0]
+        .operands
+        .iter()
+        .map(|operand| operand.value.name())
+        .collect();
+    assert_eq!(operands, ["i_1", "i_3"]);
+
+    assert_eq!(names(&ssa.node("N2").unwrap().uses), ["i_2"]);
+    assert_eq!(names(&ssa.node("N2").unwrap().defs), ["i_3"]);
+    assert_eq!(names(&ssa.node("N3").unwrap().uses), ["i_2"]);
+
+    // Unreachable definitions neither appear nor need merging
+    assert!(ssa.node("N4").is_none());
+    assert!(ssa.phis_at("N3").is_empty());
+
+    assert!(SsaBuilder::new()
+        .build_with_def_use(&cfg, &nodes[..2])
+        .is_err());
+}
+
+#[test]
+fn test_def_use_from_labels() {
+    let def_use = DefUseExtractor::extract("let mut total: i32 = base + offset;");
+    assert_eq!(def_use.defs, ["total"]);
+    assert_eq!(def_use.uses, ["base", "offset"]);
+
+    let def_use = DefUseExtractor::extract("count += step(delta);");
+    assert_eq!(def_use.defs, ["count"]);
+    assert_eq!(def_use.uses, ["count", "delta"]);
+
+    // Member and element stores read the object rather than define it
+    let def_use = DefUseExtractor::extract("self.items[i] = value;");
+    assert!(def_use.defs.is_empty());
+    assert_eq!(def_use.uses, ["self", "i", "value"]);
+
+    let def_use = DefUseExtractor::extract("for_loop(cond: pairs.has_next, pattern: (key, val))");
+    assert_eq!(def_use.defs, ["key", "val"]);
+    assert_eq!(def_use.uses, ["pairs"]);
+
+    let def_use = DefUseExtractor::extract("for_loop(init: int i = 0, cond: i < n, update: i++)");
+    assert_eq!(def_use.defs, ["i"]);
+    assert_eq!(def_use.uses, ["i", "n"]);
+
+    assert_eq!(
+        DefUseExtractor::extract("if_value_join"),
+        NodeDefUse::default()
+    );
+    assert_eq!(DefUseExtractor::extract("int x;").defs, ["x"]);
+}
+
+#[test]
+fn test_ssa_from_analysis_result() {
+    let mut temp_file = NamedTempFile::with_suffix(".py").expect("Failed to create temp file");
+    temp_file
+        .write_all(
+            b"def total(xs):\n    acc = 0\n    for x in xs:\n        acc += x\n    return acc\n",
+        )
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_ssa_construction(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    let ssa = result.ssa().for_function("total").expect("SSA for total");
+    let node = |label: &str| {
+        ssa.nodes
+            .iter()
+            .find(|node| node.label == label)
+            .unwrap_or_else(|| panic!("missing node {}", label))
+    };
+
+    // The loop header merges the accumulator from before and after each iteration
+    let header = node("for_loop(cond: xs.has_next, pattern: x)");
+    let merged = ssa
+        .phis_at(&header.id)
+        .into_iter()
+        .find(|phi| phi.target.variable == "acc")
+        .expect("Phi for acc");
+    let operands: Vec<String> = merged
+        .operands
+        .iter()
+        .map(|operand| operand.value.name())
+        .collect();
+    assert_eq!(operands, ["acc_1", "acc_3"]);
+    assert_eq!(names(&node("acc += x;").uses), ["acc_2", "x_2"]);
+    assert_eq!(names(&node("return acc").uses), ["acc_2"]);
+
+    let jsonl = result.ssa().to_jsonl();
+    let types: Vec<String> = jsonl
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("Valid JSON line"))
+        .map(|value| value["type"].as_str().unwrap_or_default().to_string())
+        .collect();
+    assert!(types.contains(&"SSANode".to_string()));
+    assert!(types.contains(&"Phi".to_string()));
+
+    let minimal = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .analyze()
+        .expect("Analysis should succeed");
+    assert!(minimal.ssa().is_empty());
+}
+
+#[test]
+fn test_same_named_methods_keep_their_own_ssa() {
+    let mut temp_file = NamedTempFile::with_suffix(".rs").expect("Failed to create temp file");
+    temp_file
+        .write_all(
+            b"impl Left {\n    fn pick(x: i32) -> i32 {\n        x\n    }\n}\n\n\
+              impl Right {\n    fn pick(x: i32) -> i32 {\n        let mut y = x;\n        if y > 1 {\n            y = 1;\n        }\n        y\n    }\n}\n",
+        )
+        .expect("Failed to write to temp file");
+
+    let result = SourceCode::new(temp_file.path())
+        .expect("Valid file")
+        .minimal()
+        .with_ssa_construction(true)
+        .analyze()
+        .expect("Analysis should succeed");
+
+    // Methods are looked up by qualified name
+    let left = result
+        .ssa()
+        .for_function("Left::pick")
+        .expect("SSA for Left::pick");
+    let right = result
+        .ssa()
+        .for_function("Right::pick")
+        .expect("SSA for Right::pick");
+    assert_eq!(
+        (left.function_name.as_str(), right.function_name.as_str()),
+        ("pick", "pick")
+    );
+    assert!(left.phis.is_empty());
+    assert!(right.phis.iter().any(|phi| phi.target.variable == "y"));
+}
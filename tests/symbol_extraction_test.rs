@@ -0,0 +1,364 @@
+use ntree::SourceCode;
+use std::fs;
+use tempfile::TempDir;
+
+/// Write a single source file into a temp dir and return both.
+fn write_source(file_name: &str, content: &str) -> (TempDir, std::path::PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join(file_name);
+    fs::write(&file_path, content).unwrap();
+    (temp_dir, file_path)
+}
+
+#[test]
+fn test_javascript_symbols() {
+    let (_dir, file_path) = write_source(
+        "widgets.js",
+        r#"
+export function render(a) {}
+const add = (x, y) => x + y;
+let legacy = function() {};
+
+class Widget extends Base {
+  constructor() {}
+  get size() { return 1; }
+  set size(v) {}
+  static create() {}
+  draw() {}
+}
+
+class Gadget {
+  constructor() {}
+}
+
+const api = {
+  fetch() {},
+  post: function() {},
+  nested: { remove: () => 1 },
+};
+"#,
+    );
+
+    let analysis = SourceCode::new(&file_path).unwrap().analyze().unwrap();
+    let qualnames: Vec<&str> = analysis
+        .symbol_store()
+        .get_all_symbols()
+        .map(|s| s.qualname.as_str())
+        .collect();
+
+    for expected in [
+        "widgets.render",
+        "widgets.add",
+        "widgets.legacy",
+        "widgets.Widget",
+        "widgets.Widget.constructor",
+        "widgets.Widget.draw",
+        "widgets.Widget.create",
+        "widgets.Gadget.constructor",
+        "widgets.api.fetch",
+        "widgets.api.post",
+        "widgets.api.nested.remove",
+    ] {
+        assert!(qualnames.contains(&expected), "missing {}", expected);
+    }
+
+    assert_eq!(analysis.symbols().kind("class").count().unwrap(), 2);
+    assert_eq!(analysis.symbols().kind("function").count().unwrap(), 3);
+    assert_eq!(analysis.symbols().kind("static_method").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("getter").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("setter").count().unwrap(), 1);
+
+    // Both constructors survive even though they share a name
+    let symbols = analysis.symbols();
+    assert_eq!(symbols.constructors().unwrap().len(), 2);
+}
+
+#[test]
+fn test_typescript_symbols() {
+    let (_dir, file_path) = write_source(
+        "shapes.ts",
+        r#"
+export interface Shape { area(): number; }
+enum Color { Red, Green }
+type Id = string | number;
+abstract class Base { abstract run(): void; protected helper(): void {} }
+export class Circle extends Base implements Shape {
+  constructor(private r: number) { super(); }
+  area(): number { return 1; }
+  run() {}
+}
+namespace Geo { export function dist() {} namespace Inner { class P {} } }
+declare function ext(x: number): void;
+declare class Amb { m(): void; }
+declare namespace Ext { class Widget { draw(): void; } function make(): Widget; }
+declare const VERSION: string;
+function over(a: string): void;
+function over(a: any) {}
+"#,
+    );
+
+    let analysis = SourceCode::new(&file_path).unwrap().analyze().unwrap();
+    assert_eq!(analysis.symbols().kind("interface").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("enum").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("type_alias").count().unwrap(), 1);
+    assert_eq!(
+        analysis.symbols().kind("abstract_class").count().unwrap(),
+        1
+    );
+    assert_eq!(
+        analysis.symbols().kind("abstract_method").count().unwrap(),
+        1
+    );
+    assert_eq!(analysis.symbols().kind("namespace").count().unwrap(), 2);
+    assert_eq!(
+        analysis.symbols().kind("ambient_function").count().unwrap(),
+        2
+    );
+    assert_eq!(analysis.symbols().kind("ambient_class").count().unwrap(), 2);
+    assert_eq!(
+        analysis
+            .symbols()
+            .kind("ambient_namespace")
+            .count()
+            .unwrap(),
+        1
+    );
+
+    // Members of declared classes and namespaces are ambient too
+    assert_eq!(
+        analysis.symbols().kind("ambient_method").count().unwrap(),
+        2
+    );
+    assert_eq!(analysis.symbols().kind("method").count().unwrap(), 3);
+    assert_eq!(
+        analysis.symbols().kind("ambient_variable").count().unwrap(),
+        1
+    );
+
+    // Overload signatures collapse onto the implementation
+    assert_eq!(analysis.symbols().named("over").count().unwrap(), 1);
+
+    let qualnames: Vec<&str> = analysis
+        .symbol_store()
+        .get_all_symbols()
+        .map(|s| s.qualname.as_str())
+        .collect();
+    for expected in [
+        "shapes.Circle.constructor",
+        "shapes.Circle.area",
+        "shapes.Geo.dist",
+        "shapes.Geo.Inner.P",
+        "shapes.Amb.m",
+        "shapes.Shape.area",
+    ] {
+        assert!(qualnames.contains(&expected), "missing {}", expected);
+    }
+}
+
+#[test]
+fn test_java_symbols() {
+    let (_dir, file_path) = write_source(
+        "Outer.java",
+        r#"
+package com.acme;
+
+public class Outer {
+  private int count, total;
+  public Outer() {}
+  public Outer(int c) {}
+  void run(int a, String b) {}
+  void run(java.util.List<String> xs, String... rest) {}
+  static class Inner { void method() {} }
+  interface Callback { void call(); }
+  enum Mode { A, B; void describe() {} }
+  record Point(int x, int y) { Point { } }
+  @interface Marker { String value(); }
+}
+"#,
+    );
+
+    let analysis = SourceCode::new(&file_path).unwrap().analyze().unwrap();
+    let store = analysis.symbol_store();
+
+    let qualnames: Vec<&str> = store
+        .get_all_symbols()
+        .map(|s| s.qualname.as_str())
+        .collect();
+    for expected in [
+        "com.acme.Outer",
+        "com.acme.Outer.count",
+        "com.acme.Outer.total",
+        "com.acme.Outer.Inner.method",
+        "com.acme.Outer.Callback.call",
+        "com.acme.Outer.Mode.describe",
+        "com.acme.Outer.Point",
+    ] {
+        assert!(qualnames.contains(&expected), "missing {}", expected);
+    }
+
+    // Overloads are kept apart by parameter types
+    let runs: Vec<&str> = store
+        .find_symbols_exact("run")
+        .iter()
+        .map(|s| s.id.as_str())
+        .collect();
+    assert_eq!(runs.len(), 2);
+    assert!(runs.iter().any(|id| id.ends_with("Outer.run(int,String)")));
+    assert!(runs
+        .iter()
+        .any(|id| id.ends_with("Outer.run(java.util.List<String>,String...)")));
+
+    assert_eq!(analysis.symbols().kind("constructor").count().unwrap(), 3);
+    assert_eq!(analysis.symbols().kind("interface").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("enum").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("record").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("annotation").count().unwrap(), 1);
+
+    let symbols = analysis.symbols();
+    assert_eq!(symbols.constructors().unwrap().len(), 3);
+}
+
+#[test]
+fn test_c_header_symbols() {
+    let (_dir, file_path) = write_source(
+        "shapes.h",
+        r#"
+#ifndef SHAPES_H
+#define SHAPES_H
+int add(int a, int b);
+extern int counter;
+static int cache_size = 4;
+typedef struct Node { struct Node *next; } Node;
+typedef int (*callback_t)(int);
+union Value { int i; float f; };
+enum Color { RED, GREEN };
+void (*handler)(int);
+#endif
+"#,
+    );
+
+    let analysis = SourceCode::new(&file_path).unwrap().analyze().unwrap();
+
+    assert_eq!(analysis.symbols().in_file("shapes.h").count().unwrap(), 9);
+    assert_eq!(analysis.symbols().kind("prototype").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("typedef").count().unwrap(), 2);
+    assert_eq!(analysis.symbols().kind("struct").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("union").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("enum").count().unwrap(), 1);
+    assert_eq!(analysis.symbols().kind("variable").count().unwrap(), 3);
+
+    let store = analysis.symbol_store();
+    let cache = store.find_symbols_exact("cache_size")[0];
+    assert_eq!(cache.linkage.as_deref(), Some("internal"));
+    let add = store.find_symbols_exact("add")[0];
+    assert_eq!(add.linkage.as_deref(), Some("external"));
+}
+
+#[test]
+fn test_c_source_symbols() {
+    let (_dir, file_path) = write_source(
+        "main.c",
+        r#"
+static int helper(void);
+int global = 1;
+
+static int helper(void) { return global; }
+int *make(void) { return 0; }
+"#,
+    );
+
+    let analysis = SourceCode::new(&file_path).unwrap().analyze().unwrap();
+
+    // The forward declaration folds into the definition
+    assert_eq!(analysis.symbols().kind("prototype").count().unwrap(), 0);
+    assert_eq!(analysis.symbols().kind("function").count().unwrap(), 2);
+
+    let store = analysis.symbol_store();
+    let helper = store.find_symbols_exact("helper")[0];
+    assert_eq!(helper.kind, "function");
+    assert_eq!(helper.linkage.as_deref(), Some("internal"));
+}
+
+#[test]
+fn test_cpp_symbols() {
+    let (_dir, file_path) = write_source(
+        "shape.cpp",
+        r#"
+namespace geo {
+class Shape {
+public:
+    Shape();
+    explicit Shape(int sides);
+    ~Shape();
+    virtual double area() const;
+    bool operator==(const Shape& other) const { return true; }
+private:
+    int sides_;
+};
+
+template <typename T>
+T max_of(T a, T b) { return a; }
+
+using Id = long;
+
+namespace detail { int helper(int x); }
+}
+
+geo::Shape::Shape() {}
+geo::Shape::Shape(int sides) : sides_(sides) {}
+geo::Shape::~Shape() {}
+double geo::Shape::area() const { return 0.0; }
+int geo::detail::helper(int x) { return x; }
+
+namespace {
+int hidden() { return 0; }
+}
+"#,
+    );
+
+    let analysis = SourceCode::new(&file_path).unwrap().analyze().unwrap();
+    let store = analysis.symbol_store();
+
+    let qualnames: Vec<&str> = store
+        .get_all_symbols()
+        .map(|s| s.qualname.as_str())
+        .collect();
+    for expected in [
+        "geo",
+        "geo::detail",
+        "geo::Shape",
+        "geo::Shape::sides_",
+        "geo::Shape::operator==",
+        "geo::Id",
+        "geo::detail::helper",
+    ] {
+        assert!(qualnames.contains(&expected), "missing {}", expected);
+    }
+
+    // Out-of-line definitions replace the in-class declarations
+    let area = store.find_symbols_exact("area");
+    assert_eq!(area.len(), 1);
+    assert_eq!(area[0].kind, "method");
+    assert_eq!(area[0].qualname, "geo::Shape::area");
+    assert!(area[0].span.starts_with("25:"));
+
+    assert_eq!(analysis.symbols().kind("constructor").count().unwrap(), 2);
+    assert_eq!(analysis.symbols().kind("destructor").count().unwrap(), 1);
+    assert_eq!(
+        analysis
+            .symbols()
+            .kind("template_function")
+            .count()
+            .unwrap(),
+        1
+    );
+    assert_eq!(analysis.symbols().kind("type_alias").count().unwrap(), 1);
+
+    let helper = store.find_symbols_exact("helper")[0];
+    assert_eq!(helper.kind, "function");
+    let hidden = store.find_symbols_exact("hidden")[0];
+    assert_eq!(hidden.linkage.as_deref(), Some("internal"));
+
+    let symbols = analysis.symbols();
+    assert_eq!(symbols.constructors().unwrap().len(), 2);
+}